@@ -1,12 +1,16 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonAny, JsonMetric,
+};
 
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
 use crate::{
     adapters::util::{latency_as_nanos, Units},
+    diagnostic::json_error,
     results::adapter_results::AdapterResults,
-    Adaptable, AdapterError, Settings,
+    Adaptable, AdapterDiagnostic, AdapterError, Settings,
 };
 
 pub struct AdapterCSharpDotNet;
@@ -18,6 +22,14 @@ impl Adaptable for AdapterCSharpDotNet {
             .convert(settings)
             .ok()?
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::CSharpDotNet, input, settings, || {
+            Some(json_error(input, |dot_net: DotNet| {
+                dot_net.convert(settings)
+            }))
+        })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -1,13 +1,25 @@
 pub mod dot_net;
 
-use crate::{Adaptable, AdapterResults, Settings};
-use dot_net::AdapterCSharpDotNet;
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
+};
 
 pub struct AdapterCSharp;
 
+/// The adapters that the C# adapter tries, in order.
+pub const C_SHARP_ADAPTERS: &[Adapter] = &[Adapter::CSharpDotNet];
+
 impl Adaptable for AdapterCSharp {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterCSharpDotNet::parse(input, settings)
+        C_SHARP_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(C_SHARP_ADAPTERS, input, settings)
     }
 }
 
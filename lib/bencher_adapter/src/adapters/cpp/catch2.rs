@@ -1,4 +1,7 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric,
+};
 use nom::{
     character::complete::{anychar, space0, space1},
     combinator::{eof, map, map_res},
@@ -13,8 +16,9 @@ use crate::{
         latency_as_nanos, nom_error, parse_benchmark_name_chars, parse_f64, parse_u64, parse_units,
         NomError, Units,
     },
+    diagnostic::line_error,
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, AdapterDiagnostic, Settings,
 };
 
 pub struct AdapterCppCatch2;
@@ -48,6 +52,26 @@ impl Adaptable for AdapterCppCatch2 {
 
         AdapterResults::new_latency(benchmark_metrics)
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        // Each benchmark name line is followed by its mean line and then its standard deviation line.
+        AdapterDiagnostic::explain(Adapter::CppCatch2, input, settings, || {
+            let lines = input.lines().collect::<Vec<_>>();
+            lines.windows(3).enumerate().find_map(|(index, window)| {
+                let [benchmark_name_line, mean_line, std_dev_line] = window else {
+                    return None;
+                };
+                if !matches!(
+                    parse_catch2_benchmark_name(benchmark_name_line),
+                    Ok(("", _))
+                ) {
+                    return None;
+                }
+                line_error(input, index + 1, mean_line, parse_catch2_time)
+                    .or_else(|| line_error(input, index + 2, std_dev_line, parse_catch2_time))
+            })
+        })
+    }
 }
 
 fn parse_catch2<'i>(
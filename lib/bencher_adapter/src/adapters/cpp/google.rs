@@ -1,11 +1,15 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonAny, JsonMetric,
+};
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
 use crate::{
     adapters::util::{latency_as_nanos, Units},
+    diagnostic::json_error,
     results::adapter_results::AdapterResults,
-    Adaptable, AdapterError, Settings,
+    Adaptable, AdapterDiagnostic, AdapterError, Settings,
 };
 
 pub struct AdapterCppGoogle;
@@ -22,6 +26,12 @@ impl Adaptable for AdapterCppGoogle {
             .try_into()
             .ok()?
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::CppGoogle, input, settings, || {
+            Some(json_error(input, |google: Google| google.try_into()))
+        })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
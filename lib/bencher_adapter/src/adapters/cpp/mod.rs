@@ -1,16 +1,26 @@
 pub mod catch2;
 pub mod google;
 
-use crate::{Adaptable, AdapterResults, Settings};
-use catch2::AdapterCppCatch2;
-use google::AdapterCppGoogle;
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
+};
 
 pub struct AdapterCpp;
 
+/// The adapters that the C++ adapter tries, in order.
+pub const CPP_ADAPTERS: &[Adapter] = &[Adapter::CppCatch2, Adapter::CppGoogle];
+
 impl Adaptable for AdapterCpp {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterCppCatch2::parse(input, settings)
-            .or_else(|| AdapterCppGoogle::parse(input, settings))
+        CPP_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(CPP_ADAPTERS, input, settings)
     }
 }
 
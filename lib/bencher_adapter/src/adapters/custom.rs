@@ -0,0 +1,266 @@
+use bencher_json::{BenchmarkName, JsonMetric, MeasureNameId};
+use ordered_float::OrderedFloat;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    adapters::util::{latency_as_nanos, parse_benchmark_name, throughput_as_secs, Units},
+    results::adapter_results::{
+        AdapterMeasure, AdapterResults, LATENCY_NAME_ID, THROUGHPUT_NAME_ID,
+    },
+    Adaptable, AdapterDiagnostic, AdapterError, Settings,
+};
+
+const NAME_GROUP: &str = "name";
+const VALUE_GROUP: &str = "value";
+const LOWER_VALUE_GROUP: &str = "lower_value";
+const UPPER_VALUE_GROUP: &str = "upper_value";
+const UNITS_GROUP: &str = "units";
+const MEASURE_GROUP: &str = "measure";
+const REQUIRED_GROUPS: [&str; 2] = [NAME_GROUP, VALUE_GROUP];
+
+/// A user-defined adapter for benchmark harness output that has no built-in adapter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomAdapterConfig {
+    /// Each line of the benchmark harness output is checked against each pattern in order.
+    pub patterns: Vec<CustomPattern>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomPattern {
+    /// A regular expression with `name` and `value` named capture groups,
+    /// and optional `lower_value`, `upper_value`, `units`, and `measure` named capture groups.
+    pub regex: String,
+    /// The Measure to use when there is no `measure` capture group (default `latency`)
+    pub measure: Option<MeasureNameId>,
+    /// The units to use when there is no `units` capture group.
+    /// Units are only used to convert `latency` to nanoseconds and `throughput` to operations per second.
+    pub units: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct AdapterCustom {
+    patterns: Vec<Pattern>,
+}
+
+#[derive(Debug)]
+struct Pattern {
+    regex: Regex,
+    measure: Option<MeasureNameId>,
+    units: Option<Units>,
+}
+
+impl TryFrom<CustomAdapterConfig> for AdapterCustom {
+    type Error = AdapterError;
+
+    fn try_from(config: CustomAdapterConfig) -> Result<Self, Self::Error> {
+        let patterns = config
+            .patterns
+            .into_iter()
+            .map(Pattern::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { patterns })
+    }
+}
+
+impl Adaptable for AdapterCustom {
+    fn convert(&self, input: &str, _settings: Settings) -> Option<AdapterResults> {
+        let benchmark_metrics = input
+            .lines()
+            .filter_map(|line| {
+                self.patterns
+                    .iter()
+                    .find_map(|pattern| pattern.parse_line(line))
+            })
+            .map(|(benchmark_name, measure, metric)| {
+                (
+                    benchmark_name,
+                    vec![AdapterMeasure::Custom(measure, metric)],
+                )
+            })
+            .collect();
+        AdapterResults::new(benchmark_metrics)
+    }
+
+    // A custom adapter has no patterns to match without its config.
+    fn parse(_input: &str, _settings: Settings) -> Option<AdapterResults> {
+        None
+    }
+
+    // A custom adapter is not one of the built-in adapters, so it has nothing to explain.
+    fn diagnose(_input: &str, _settings: Settings) -> Vec<AdapterDiagnostic> {
+        Vec::new()
+    }
+}
+
+impl TryFrom<CustomPattern> for Pattern {
+    type Error = AdapterError;
+
+    fn try_from(pattern: CustomPattern) -> Result<Self, Self::Error> {
+        let CustomPattern {
+            regex,
+            measure,
+            units,
+        } = pattern;
+        let compiled = Regex::new(&regex).map_err(AdapterError::CustomRegex)?;
+        for group in REQUIRED_GROUPS {
+            if !compiled.capture_names().flatten().any(|name| name == group) {
+                return Err(AdapterError::CustomCaptureGroup { regex, group });
+            }
+        }
+        let units = units.as_deref().map(str::parse).transpose()?;
+        Ok(Self {
+            regex: compiled,
+            measure,
+            units,
+        })
+    }
+}
+
+impl Pattern {
+    fn parse_line(&self, line: &str) -> Option<(BenchmarkName, MeasureNameId, JsonMetric)> {
+        let captures = self.regex.captures(line)?;
+        let benchmark_name = parse_benchmark_name(capture(&captures, NAME_GROUP)?).ok()?;
+        let measure = if let Some(measure) = capture(&captures, MEASURE_GROUP) {
+            measure.parse().ok()?
+        } else {
+            self.measure
+                .clone()
+                .unwrap_or_else(|| LATENCY_NAME_ID.clone())
+        };
+        let units = if let Some(units) = capture(&captures, UNITS_GROUP) {
+            Some(units.parse().ok()?)
+        } else {
+            self.units
+        };
+
+        let to_value = |value: &str| {
+            let value = parse_value(value)?;
+            Some(match units {
+                Some(units) if measure == *LATENCY_NAME_ID => latency_as_nanos(value, units),
+                Some(units) if measure == *THROUGHPUT_NAME_ID => throughput_as_secs(value, units),
+                Some(_) | None => value,
+            })
+        };
+        let value = to_value(capture(&captures, VALUE_GROUP)?)?;
+        let lower_value = if let Some(lower_value) = capture(&captures, LOWER_VALUE_GROUP) {
+            Some(to_value(lower_value)?)
+        } else {
+            None
+        };
+        let upper_value = if let Some(upper_value) = capture(&captures, UPPER_VALUE_GROUP) {
+            Some(to_value(upper_value)?)
+        } else {
+            None
+        };
+
+        Some((
+            benchmark_name,
+            measure,
+            JsonMetric {
+                value,
+                lower_value,
+                upper_value,
+            },
+        ))
+    }
+}
+
+fn capture<'h>(captures: &Captures<'h>, group: &str) -> Option<&'h str> {
+    captures.name(group).map(|capture| capture.as_str().trim())
+}
+
+// Thousands separators are ignored, ie `1,234.5`
+fn parse_value(input: &str) -> Option<OrderedFloat<f64>> {
+    input.replace(',', "").parse::<f64>().ok().map(Into::into)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_custom {
+    use bencher_json::project::measure::{
+        ALLOCATIONS_SLUG_STR, LATENCY_SLUG_STR, THROUGHPUT_SLUG_STR,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{validate_latency, validate_multi_metric},
+        Adaptable, AdapterError, AdapterResults, Settings,
+    };
+
+    use super::{AdapterCustom, CustomAdapterConfig, CustomPattern};
+
+    fn custom_adapter() -> AdapterCustom {
+        let config = std::fs::read_to_string("./tool_output/custom/config.json").unwrap();
+        serde_json::from_str::<CustomAdapterConfig>(&config)
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    fn custom_pattern(regex: &str, units: Option<&str>) -> CustomAdapterConfig {
+        CustomAdapterConfig {
+            patterns: vec![CustomPattern {
+                regex: regex.into(),
+                measure: None,
+                units: units.map(Into::into),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_adapter_custom_config() {
+        let result = AdapterCustom::try_from(custom_pattern("(?<name>\\S+", None));
+        assert!(matches!(result, Err(AdapterError::CustomRegex(_))));
+
+        let result = AdapterCustom::try_from(custom_pattern("(?<name>\\S+) (\\d+)", None));
+        assert!(matches!(
+            result,
+            Err(AdapterError::CustomCaptureGroup { group: "value", .. })
+        ));
+
+        let result = AdapterCustom::try_from(custom_pattern(
+            "(?<name>\\S+) (?<value>\\d+)",
+            Some("furlongs"),
+        ));
+        assert!(matches!(result, Err(AdapterError::BenchmarkUnits(_))));
+    }
+
+    #[test]
+    fn test_adapter_custom_no_config() {
+        assert_eq!(
+            AdapterCustom::parse("bench 1 ns", Settings::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_adapter_custom() {
+        let input = std::fs::read_to_string("./tool_output/custom/output.txt").unwrap();
+        let results = custom_adapter()
+            .convert(&input, Settings::default())
+            .unwrap();
+        validate_adapter_custom(&results);
+    }
+
+    pub fn validate_adapter_custom(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("parse_small").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_multi_metric(
+            metrics,
+            LATENCY_SLUG_STR,
+            1_234.5,
+            Some(1_200.0),
+            Some(1_270.5),
+        );
+        validate_multi_metric(metrics, ALLOCATIONS_SLUG_STR, 42.0, None, None);
+
+        let metrics = results.get("parse_large").unwrap();
+        validate_latency(metrics, 2_500_000.0, Some(2_400_000.0), Some(2_750_000.0));
+
+        let metrics = results.get("serialize").unwrap();
+        validate_multi_metric(metrics, THROUGHPUT_SLUG_STR, 1_500.0, None, None);
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{anychar, space0, space1},
+    combinator::{eof, map, map_res, opt},
+    multi::many_till,
+    sequence::{delimited, tuple},
+    IResult,
+};
+use ordered_float::OrderedFloat;
+use serde::Deserialize;
+
+use crate::{
+    adapters::util::{
+        latency_as_nanos, parse_benchmark_name_chars, parse_f64, parse_units, NomError, Units,
+    },
+    diagnostic::{first_line_error, json_error, looks_like_json, parse_named},
+    results::adapter_results::AdapterResults,
+    Adaptable, AdapterDiagnostic, AdapterError, Settings,
+};
+
+pub struct AdapterElixirBenchee;
+
+impl Adaptable for AdapterElixirBenchee {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        // Benchee has both a console formatter and a JSON formatter (`benchee_json`).
+        if let Ok(benchee) = serde_json::from_str::<Benchee>(input) {
+            return benchee.convert(settings).ok()?;
+        }
+
+        let average = settings.average.unwrap_or_default();
+        let mut benchmark_metrics = Vec::new();
+        for line in input.lines() {
+            if let Ok((remainder, benchmark_metric)) = parse_benchee(average, line) {
+                if remainder.is_empty() {
+                    benchmark_metrics.push(benchmark_metric);
+                }
+            }
+        }
+
+        AdapterResults::new_latency(benchmark_metrics)
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::ElixirBenchee, input, settings, || {
+            if looks_like_json(input) {
+                return Some(json_error(input, |benchee: Benchee| {
+                    benchee.convert(settings)
+                }));
+            }
+
+            let average = settings.average.unwrap_or_default();
+            first_line_error(
+                input,
+                |line| line.contains(" ±"),
+                |line| {
+                    parse_named(
+                        line,
+                        |line| parse_benchee(average, line),
+                        |line| parse_benchee_columns(average, line),
+                    )
+                },
+            )
+        })
+    }
+}
+
+// https://github.com/bencheeorg/benchee_json
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Benchee {
+    pub statistics: HashMap<BenchmarkName, Statistics>,
+}
+
+// All times are in nanoseconds.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Statistics {
+    pub average: f64,
+    pub std_dev: f64,
+    pub median: f64,
+    pub minimum: f64,
+    pub maximum: f64,
+}
+
+impl Benchee {
+    #[allow(clippy::unnecessary_wraps)]
+    fn convert(self, settings: Settings) -> Result<Option<AdapterResults>, AdapterError> {
+        let mut benchmark_metrics = Vec::with_capacity(self.statistics.len());
+        for (benchmark_name, statistics) in self.statistics {
+            let Statistics {
+                average,
+                std_dev,
+                median,
+                minimum,
+                maximum,
+            } = statistics;
+
+            let units = Units::Nano;
+            let (value, lower_value, upper_value) = match settings.average.unwrap_or_default() {
+                JsonAverage::Mean => (average, average - std_dev, average + std_dev),
+                JsonAverage::Median => (median, minimum, maximum),
+            };
+            let json_metric = JsonMetric {
+                value: latency_as_nanos(value, units),
+                lower_value: Some(latency_as_nanos(lower_value, units)),
+                upper_value: Some(latency_as_nanos(upper_value, units)),
+            };
+
+            benchmark_metrics.push((benchmark_name, json_metric));
+        }
+
+        Ok(AdapterResults::new_latency(benchmark_metrics))
+    }
+}
+
+// A console result row looks like:
+// `flat_map           2.34 K      427.78 μs    ±16.02%      406.29 μs      743.01 μs`
+fn parse_benchee(average: JsonAverage, input: &str) -> IResult<&str, (BenchmarkName, JsonMetric)> {
+    map_res(
+        many_till(anychar, |input| parse_benchee_columns(average, input)),
+        |(name_chars, json_metric)| -> Result<(BenchmarkName, JsonMetric), NomError> {
+            let benchmark_name = parse_benchmark_name_chars(&name_chars)?;
+            Ok((benchmark_name, json_metric))
+        },
+    )(input)
+}
+
+fn parse_benchee_columns(average: JsonAverage, input: &str) -> IResult<&str, JsonMetric> {
+    map(
+        tuple((
+            // ips
+            space1,
+            parse_f64,
+            opt(tuple((space1, alt((tag("K"), tag("M"), tag("B")))))),
+            // average
+            space1,
+            parse_benchee_duration,
+            // deviation
+            space1,
+            delimited(tag("±"), parse_f64, tag("%")),
+            // median
+            space1,
+            parse_benchee_duration,
+            // 99th %
+            space1,
+            parse_benchee_duration,
+            space0,
+            eof,
+        )),
+        |(_, _, _, _, mean, _, deviation, _, median, _, _, _, _)| match average {
+            JsonAverage::Mean => {
+                let std_dev = mean * deviation / 100.0;
+                JsonMetric {
+                    value: mean,
+                    lower_value: Some(mean - std_dev),
+                    upper_value: Some(mean + std_dev),
+                }
+            },
+            // The console output does not have a spread for the median.
+            JsonAverage::Median => JsonMetric {
+                value: median,
+                lower_value: None,
+                upper_value: None,
+            },
+        },
+    )(input)
+}
+
+fn parse_benchee_duration(input: &str) -> IResult<&str, OrderedFloat<f64>> {
+    map(
+        tuple((parse_f64, space1, parse_units)),
+        |(duration, _, units)| latency_as_nanos(duration, units),
+    )(input)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_elixir_benchee {
+    use bencher_json::{project::report::JsonAverage, JsonMetric};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, convert_file_path_median, validate_latency},
+        AdapterResults,
+    };
+
+    use super::{parse_benchee, AdapterElixirBenchee};
+
+    fn file_path(suffix: &str) -> String {
+        format!("./tool_output/elixir/benchee/{suffix}")
+    }
+
+    #[test]
+    fn test_parse_benchee() {
+        for (index, (expected, input)) in [
+            (
+                Ok((
+                    "",
+                    (
+                        "flat_map".parse().unwrap(),
+                        JsonMetric {
+                            value: 400_000.0.into(),
+                            lower_value: Some(360_000.0.into()),
+                            upper_value: Some(440_000.0.into()),
+                        },
+                    ),
+                )),
+                "flat_map           2.50 K      400.00 μs    ±10.00%      390.00 μs      700.00 μs",
+            ),
+            (
+                Ok((
+                    "",
+                    (
+                        "map with spaces".parse().unwrap(),
+                        JsonMetric {
+                            value: 1_000.0.into(),
+                            lower_value: Some(500.0.into()),
+                            upper_value: Some(1_500.0.into()),
+                        },
+                    ),
+                )),
+                "map with spaces        1 M        1000 ns    ±50.00%         900 ns        2000 ns",
+            ),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            assert_eq!(
+                expected,
+                parse_benchee(JsonAverage::Mean, input),
+                "#{index}: {input}"
+            );
+        }
+
+        assert!(parse_benchee(
+            JsonAverage::Mean,
+            "Name                  ips        average  deviation         median         99th %"
+        )
+        .is_err());
+        assert!(parse_benchee(
+            JsonAverage::Mean,
+            "map.flatten        1.25 K - 2.00x slower +400.00 μs"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_adapter_elixir_benchee() {
+        let results = convert_file_path::<AdapterElixirBenchee>(&file_path("two.txt"));
+        validate_adapter_elixir_benchee(&results);
+    }
+
+    pub fn validate_adapter_elixir_benchee(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("flat_map").unwrap();
+        validate_latency(metrics, 400_000.0, Some(360_000.0), Some(440_000.0));
+
+        let metrics = results.get("map.flatten").unwrap();
+        validate_latency(metrics, 800_000.0, Some(640_000.0), Some(960_000.0));
+    }
+
+    #[test]
+    fn test_adapter_elixir_benchee_median() {
+        let results = convert_file_path_median::<AdapterElixirBenchee>(&file_path("two.txt"));
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("flat_map").unwrap();
+        validate_latency(metrics, 390_000.0, None, None);
+
+        let metrics = results.get("map.flatten").unwrap();
+        validate_latency(metrics, 750_000.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_elixir_benchee_json() {
+        let results = convert_file_path::<AdapterElixirBenchee>(&file_path("two.json"));
+        validate_adapter_elixir_benchee_json(&results);
+    }
+
+    pub fn validate_adapter_elixir_benchee_json(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("flat_map").unwrap();
+        validate_latency(metrics, 400_000.0, Some(350_000.0), Some(450_000.0));
+
+        let metrics = results.get("map.flatten").unwrap();
+        validate_latency(metrics, 800_000.0, Some(700_000.0), Some(900_000.0));
+    }
+
+    #[test]
+    fn test_adapter_elixir_benchee_json_median() {
+        let results = convert_file_path_median::<AdapterElixirBenchee>(&file_path("two.json"));
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("flat_map").unwrap();
+        validate_latency(metrics, 390_000.0, Some(345_000.0), Some(1_165_000.0));
+
+        let metrics = results.get("map.flatten").unwrap();
+        validate_latency(metrics, 750_000.0, Some(690_000.0), Some(1_800_000.0));
+    }
+}
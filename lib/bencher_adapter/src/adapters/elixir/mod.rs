@@ -0,0 +1,39 @@
+pub mod benchee;
+
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
+};
+
+pub struct AdapterElixir;
+
+/// The adapters that the Elixir adapter tries, in order.
+pub const ELIXIR_ADAPTERS: &[Adapter] = &[Adapter::ElixirBenchee];
+
+impl Adaptable for AdapterElixir {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        ELIXIR_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(ELIXIR_ADAPTERS, input, settings)
+    }
+}
+
+#[cfg(test)]
+mod test_elixir {
+    use super::AdapterElixir;
+    use crate::adapters::{elixir::benchee::test_elixir_benchee, test_util::convert_file_path};
+
+    #[test]
+    fn test_adapter_elixir_benchee() {
+        let results = convert_file_path::<AdapterElixir>("./tool_output/elixir/benchee/two.txt");
+        test_elixir_benchee::validate_adapter_elixir_benchee(&results);
+
+        let results = convert_file_path::<AdapterElixir>("./tool_output/elixir/benchee/two.json");
+        test_elixir_benchee::validate_adapter_elixir_benchee_json(&results);
+    }
+}
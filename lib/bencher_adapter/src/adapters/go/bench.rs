@@ -1,20 +1,24 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric,
+};
 use nom::{
-    branch::alt,
     bytes::complete::{tag, take_till1},
-    character::complete::{anychar, space1},
-    combinator::{eof, map, map_res},
-    multi::many_till,
-    sequence::tuple,
+    character::complete::{space0, space1},
+    combinator::{eof, map_res},
+    multi::many0,
+    sequence::{preceded, tuple},
     IResult,
 };
 
 use crate::{
     adapters::util::{
-        latency_as_nanos, parse_benchmark_name, parse_f64, parse_u64, parse_units, NomError,
+        latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_u64, parse_units,
+        NomError,
     },
-    results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    diagnostic::first_line_error,
+    results::adapter_results::{AdapterMeasure, AdapterResults},
+    Adaptable, AdapterDiagnostic, Settings,
 };
 
 pub struct AdapterGoBench;
@@ -36,11 +40,19 @@ impl Adaptable for AdapterGoBench {
             }
         }
 
-        AdapterResults::new_latency(benchmark_metrics)
+        AdapterResults::new(benchmark_metrics)
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::GoBench, input, settings, || {
+            first_line_error(input, |line| line.starts_with("Benchmark"), parse_go)
+        })
     }
 }
 
-fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, JsonMetric)> {
+type GoBenchMetrics = (BenchmarkName, Vec<AdapterMeasure>);
+
+fn parse_go(input: &str) -> IResult<&str, GoBenchMetrics> {
     map_res(
         tuple((
             take_till1(|c| c == ' ' || c == '\t'),
@@ -48,17 +60,16 @@ fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, JsonMetric)> {
             parse_u64,
             space1,
             parse_go_bench,
-            alt((
-                map(eof, |_| ()),
-                map(
-                    tuple((space1, parse_f64, space1, many_till(anychar, eof))),
-                    |_| (),
-                ),
-            )),
+            many0(preceded(space1, parse_go_metric)),
+            space0,
+            eof,
         )),
-        |(name, _, _iter, _, json_metric, ())| -> Result<(BenchmarkName, JsonMetric), NomError> {
+        |(name, _, _iter, _, json_metric, metrics, _, _)| -> Result<GoBenchMetrics, NomError> {
             let benchmark_name = parse_benchmark_name(name)?;
-            Ok((benchmark_name, json_metric))
+            let mut measures = Vec::with_capacity(metrics.len() + 1);
+            measures.push(AdapterMeasure::Latency(json_metric));
+            measures.extend(metrics);
+            Ok((benchmark_name, measures))
         },
     )(input)
 }
@@ -77,14 +88,45 @@ fn parse_go_bench(input: &str) -> IResult<&str, JsonMetric> {
     )(input)
 }
 
+// The `-benchmem` columns and any custom `b.ReportMetric` columns, named by their units.
+fn parse_go_metric(input: &str) -> IResult<&str, AdapterMeasure> {
+    map_res(
+        tuple((parse_f64, space1, take_till1(|c: char| c.is_whitespace()))),
+        |(value, _, units)| -> Result<AdapterMeasure, NomError> {
+            let json_metric = JsonMetric {
+                value: value.into(),
+                lower_value: None,
+                upper_value: None,
+            };
+            Ok(match units {
+                "B/op" => AdapterMeasure::AllocatedBytes(json_metric),
+                "allocs/op" => AdapterMeasure::Allocations(json_metric),
+                _ => AdapterMeasure::Custom(
+                    units.parse().map_err(|_e| nom_error(units))?,
+                    json_metric,
+                ),
+            })
+        },
+    )(input)
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 pub(crate) mod test_go_bench {
-    use bencher_json::{project::report::JsonAverage, JsonMetric};
+    use bencher_json::{
+        project::{
+            measure::{ALLOCATED_BYTES_SLUG_STR, ALLOCATIONS_SLUG_STR, LATENCY_SLUG_STR},
+            report::JsonAverage,
+        },
+        JsonMetric,
+    };
     use pretty_assertions::assert_eq;
 
     use crate::{
-        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        adapters::test_util::{
+            convert_file_path, opt_convert_file_path, validate_latency, validate_multi_metric,
+        },
+        results::adapter_results::AdapterMeasure,
         AdapterResults, Settings,
     };
 
@@ -95,6 +137,14 @@ pub(crate) mod test_go_bench {
         convert_file_path::<AdapterGoBench>(&file_path)
     }
 
+    fn latency(value: f64) -> AdapterMeasure {
+        AdapterMeasure::Latency(JsonMetric {
+            value: value.into(),
+            lower_value: None,
+            upper_value: None,
+        })
+    }
+
     #[test]
     fn test_parse_go() {
         for (index, (expected, input)) in [
@@ -103,11 +153,7 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib10-8".parse().unwrap(),
-                        JsonMetric {
-                            value: 325.0.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![latency(325.0)],
                     ),
                 )),
                 "BenchmarkFib10-8   		 					5000000		325 ns/op",
@@ -117,11 +163,7 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib20".parse().unwrap(),
-                        JsonMetric {
-                            value: 40_537.123.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![latency(40_537.123)],
                     ),
                 )),
                 "BenchmarkFib20  	 	   					30000		40537.123 ns/op",
@@ -131,11 +173,7 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my_tabled_benchmark_-_10-8".parse().unwrap(),
-                        JsonMetric {
-                            value: 325.0.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![latency(325.0)],
                     ),
                 )),
                 "BenchmarkFib/my_tabled_benchmark_-_10-8    	5000000		325 ns/op",
@@ -145,11 +183,7 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my_tabled_benchmark_-_20".parse().unwrap(),
-                        JsonMetric {
-                            value: 40_537.123.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![latency(40_537.123)],
                     ),
                 )),
                 "BenchmarkFib/my_tabled_benchmark_-_20		30000		40537.123 ns/op",
@@ -159,11 +193,7 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my/tabled/benchmark_-_20".parse().unwrap(),
-                        JsonMetric {
-                            value: 40_537.456.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![latency(40_537.456)],
                     ),
                 )),
                 "BenchmarkFib/my/tabled/benchmark_-_20		30001		40537.456 ns/op",
@@ -173,11 +203,17 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib20WithAuxMetric-8".parse().unwrap(),
-                        JsonMetric {
-                            value: 25_829.0.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![
+                            latency(25_829.0),
+                            AdapterMeasure::Custom(
+                                "auxMetricUnits".parse().unwrap(),
+                                JsonMetric {
+                                    value: 4.0.into(),
+                                    lower_value: None,
+                                    upper_value: None,
+                                },
+                            ),
+                        ],
                     ),
                 )),
                 "BenchmarkFib20WithAuxMetric-8              46714             25829 ns/op                 4.000 auxMetricUnits",
@@ -256,6 +292,36 @@ pub(crate) mod test_go_bench {
         validate_latency(metrics, 26264.0, None, None);
 
         let metrics = results.get("BenchmarkFib20WithAuxMetric-8").unwrap();
-        validate_latency(metrics, 25829.0, None, None);
+        assert_eq!(metrics.inner.len(), 2);
+        validate_multi_metric(metrics, LATENCY_SLUG_STR, 25829.0, None, None);
+        validate_multi_metric(metrics, "auxMetricUnits", 4.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_go_bench_benchmem() {
+        let results = convert_go_bench("benchmem");
+        assert_eq!(results.inner.len(), 4);
+
+        let metrics = results.get("BenchmarkFib10-16").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_multi_metric(metrics, LATENCY_SLUG_STR, 252.3, None, None);
+        validate_multi_metric(metrics, ALLOCATED_BYTES_SLUG_STR, 0.0, None, None);
+        validate_multi_metric(metrics, ALLOCATIONS_SLUG_STR, 0.0, None, None);
+
+        let metrics = results.get("BenchmarkConcat-16").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_multi_metric(metrics, ALLOCATED_BYTES_SLUG_STR, 53_488.0, None, None);
+        validate_multi_metric(metrics, ALLOCATIONS_SLUG_STR, 99.0, None, None);
+
+        let metrics = results.get("BenchmarkEncode-16").unwrap();
+        assert_eq!(metrics.inner.len(), 4);
+        validate_multi_metric(metrics, LATENCY_SLUG_STR, 1339.0, None, None);
+        validate_multi_metric(metrics, "MB/s", 764.66, None, None);
+        validate_multi_metric(metrics, ALLOCATED_BYTES_SLUG_STR, 2048.0, None, None);
+
+        let metrics = results.get("BenchmarkParse-16").unwrap();
+        assert_eq!(metrics.inner.len(), 4);
+        validate_multi_metric(metrics, "tokens/op", 12.5, None, None);
+        validate_multi_metric(metrics, ALLOCATIONS_SLUG_STR, 37.0, None, None);
     }
 }
@@ -1,13 +1,25 @@
 pub mod bench;
 
-use crate::{Adaptable, AdapterResults, Settings};
-use bench::AdapterGoBench;
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
+};
 
 pub struct AdapterGo;
 
+/// The adapters that the Go adapter tries, in order.
+pub const GO_ADAPTERS: &[Adapter] = &[Adapter::GoBench];
+
 impl Adaptable for AdapterGo {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterGoBench::parse(input, settings)
+        GO_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(GO_ADAPTERS, input, settings)
     }
 }
 
@@ -0,0 +1,150 @@
+use bencher_json::{project::report::Adapter, BenchmarkName};
+use serde::Deserialize;
+
+use crate::{
+    adapters::{
+        http::http_metric,
+        util::{latency_as_nanos, Units},
+    },
+    diagnostic::json_error,
+    results::adapter_results::{AdapterMeasure, AdapterResults},
+    Adaptable, AdapterDiagnostic, Settings,
+};
+
+pub struct AdapterHttpK6;
+
+const K6_BENCHMARK_NAME: &str = "k6";
+
+impl Adaptable for AdapterHttpK6 {
+    fn parse(input: &str, _settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<K6>(input).ok()?.convert()
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::HttpK6, input, settings, || {
+            Some(json_error(input, |k6: K6| Ok(k6.convert())))
+        })
+    }
+}
+
+// The JSON output from `k6 run --summary-export summary.json`
+#[derive(Debug, Clone, Deserialize)]
+pub struct K6 {
+    pub metrics: K6Metrics,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct K6Metrics {
+    pub http_req_duration: K6Trend,
+    pub http_reqs: K6Counter,
+    pub http_req_failed: Option<K6Rate>,
+}
+
+/// All times are in milliseconds.
+/// Which percentiles are available depends on `--summary-trend-stats`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct K6Trend {
+    pub med: Option<f64>,
+    #[serde(rename = "p(50)")]
+    pub p50: Option<f64>,
+    #[serde(rename = "p(90)")]
+    pub p90: Option<f64>,
+    #[serde(rename = "p(99)")]
+    pub p99: Option<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct K6Counter {
+    pub count: f64,
+    /// Per second
+    pub rate: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct K6Rate {
+    /// The fraction of values that were true, which for `http_req_failed` are the failed requests.
+    pub value: f64,
+}
+
+impl K6 {
+    fn convert(self) -> Option<AdapterResults> {
+        let K6Metrics {
+            http_req_duration,
+            http_reqs,
+            http_req_failed,
+        } = self.metrics;
+
+        let latency = |time: f64| http_metric(latency_as_nanos(time, Units::Milli));
+        let mut measures = Vec::new();
+        if let Some(p50) = http_req_duration.p50.or(http_req_duration.med) {
+            measures.push(AdapterMeasure::LatencyP50(latency(p50)));
+        }
+        if let Some(p90) = http_req_duration.p90 {
+            measures.push(AdapterMeasure::LatencyP90(latency(p90)));
+        }
+        if let Some(p99) = http_req_duration.p99 {
+            measures.push(AdapterMeasure::LatencyP99(latency(p99)));
+        }
+        measures.push(AdapterMeasure::Throughput(http_metric(http_reqs.rate)));
+        if let Some(K6Rate { value }) = http_req_failed {
+            measures.push(AdapterMeasure::ErrorRate(http_metric(value * 100.0)));
+        }
+
+        let benchmark_name: BenchmarkName = K6_BENCHMARK_NAME.parse().ok()?;
+        AdapterResults::new(vec![(benchmark_name, measures)])
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_http_k6 {
+    use bencher_json::project::measure::{
+        ERROR_RATE_SLUG_STR, LATENCY_P50_SLUG_STR, LATENCY_P90_SLUG_STR, LATENCY_P99_SLUG_STR,
+        THROUGHPUT_SLUG_STR,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, validate_multi_metric},
+        Adaptable, AdapterResults, Settings,
+    };
+
+    use super::AdapterHttpK6;
+
+    fn convert_http_k6(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/http/k6/{suffix}.json");
+        convert_file_path::<AdapterHttpK6>(&file_path)
+    }
+
+    #[test]
+    fn test_adapter_http_k6() {
+        let results = convert_http_k6("summary");
+        validate_adapter_http_k6(&results);
+    }
+
+    #[test]
+    fn test_adapter_http_k6_default_trend_stats() {
+        let input = r#"{"metrics": {
+            "http_req_duration": {"avg": 1.5, "min": 1.0, "med": 1.25, "max": 3.0, "p(90)": 2.0, "p(95)": 2.5},
+            "http_reqs": {"count": 10, "rate": 5.0}
+        }}"#;
+        let results = AdapterHttpK6::parse(input, Settings::default()).unwrap();
+        let metrics = results.get("k6").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_multi_metric(metrics, LATENCY_P50_SLUG_STR, 1_250_000.0, None, None);
+        validate_multi_metric(metrics, LATENCY_P90_SLUG_STR, 2_000_000.0, None, None);
+        validate_multi_metric(metrics, THROUGHPUT_SLUG_STR, 5.0, None, None);
+    }
+
+    pub fn validate_adapter_http_k6(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("k6").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+        validate_multi_metric(metrics, LATENCY_P50_SLUG_STR, 110_200_000.0, None, None);
+        validate_multi_metric(metrics, LATENCY_P90_SLUG_STR, 180_400_000.0, None, None);
+        validate_multi_metric(metrics, LATENCY_P99_SLUG_STR, 401_700_000.0, None, None);
+        validate_multi_metric(metrics, THROUGHPUT_SLUG_STR, 99.96, None, None);
+        validate_multi_metric(metrics, ERROR_RATE_SLUG_STR, 0.4, None, None);
+    }
+}
@@ -0,0 +1,66 @@
+pub mod k6;
+pub mod oha;
+pub mod wrk;
+
+use bencher_json::{project::report::Adapter, JsonMetric};
+use ordered_float::OrderedFloat;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
+};
+
+pub struct AdapterHttp;
+
+/// The adapters that the HTTP adapter tries, in order.
+pub const HTTP_ADAPTERS: &[Adapter] = &[Adapter::HttpK6, Adapter::HttpOha, Adapter::HttpWrk];
+
+impl Adaptable for AdapterHttp {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        HTTP_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(HTTP_ADAPTERS, input, settings)
+    }
+}
+
+// Load generators only report a single value for each measure.
+fn http_metric<T>(value: T) -> JsonMetric
+where
+    T: Into<OrderedFloat<f64>>,
+{
+    JsonMetric {
+        value: value.into(),
+        lower_value: None,
+        upper_value: None,
+    }
+}
+
+#[cfg(test)]
+mod test_http {
+    use super::AdapterHttp;
+    use crate::adapters::{
+        http::{k6::test_http_k6, oha::test_http_oha, wrk::test_http_wrk},
+        test_util::convert_file_path,
+    };
+
+    #[test]
+    fn test_adapter_http_k6() {
+        let results = convert_file_path::<AdapterHttp>("./tool_output/http/k6/summary.json");
+        test_http_k6::validate_adapter_http_k6(&results);
+    }
+
+    #[test]
+    fn test_adapter_http_oha() {
+        let results = convert_file_path::<AdapterHttp>("./tool_output/http/oha/summary.json");
+        test_http_oha::validate_adapter_http_oha(&results);
+    }
+
+    #[test]
+    fn test_adapter_http_wrk() {
+        let results = convert_file_path::<AdapterHttp>("./tool_output/http/wrk/latency.txt");
+        test_http_wrk::validate_adapter_http_wrk(&results);
+    }
+}
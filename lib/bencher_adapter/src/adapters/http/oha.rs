@@ -0,0 +1,151 @@
+use bencher_json::{project::report::Adapter, BenchmarkName};
+use serde::Deserialize;
+
+use crate::{
+    adapters::{
+        http::http_metric,
+        util::{latency_as_nanos, Units},
+    },
+    diagnostic::json_error,
+    results::adapter_results::{AdapterMeasure, AdapterResults},
+    Adaptable, AdapterDiagnostic, Settings,
+};
+
+pub struct AdapterHttpOha;
+
+const OHA_BENCHMARK_NAME: &str = "oha";
+
+impl Adaptable for AdapterHttpOha {
+    fn parse(input: &str, _settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<Oha>(input).ok()?.convert()
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::HttpOha, input, settings, || {
+            Some(json_error(input, |oha: Oha| Ok(oha.convert())))
+        })
+    }
+}
+
+// The JSON output from `oha --json`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Oha {
+    pub summary: OhaSummary,
+    pub latency_percentiles: OhaLatencyPercentiles,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OhaSummary {
+    /// The fraction of requests that did not have an error
+    pub success_rate: Option<f64>,
+    pub requests_per_sec: Option<f64>,
+}
+
+/// All times are in seconds.
+/// The percentiles are `null` if there were no successful requests.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OhaLatencyPercentiles {
+    pub p50: Option<f64>,
+    pub p90: Option<f64>,
+    pub p99: Option<f64>,
+}
+
+impl Oha {
+    fn convert(self) -> Option<AdapterResults> {
+        let Self {
+            summary:
+                OhaSummary {
+                    success_rate,
+                    requests_per_sec,
+                },
+            latency_percentiles: OhaLatencyPercentiles { p50, p90, p99 },
+        } = self;
+
+        let latency = |time: f64| http_metric(latency_as_nanos(time, Units::Sec));
+        let mut measures = Vec::new();
+        if let Some(p50) = p50 {
+            measures.push(AdapterMeasure::LatencyP50(latency(p50)));
+        }
+        if let Some(p90) = p90 {
+            measures.push(AdapterMeasure::LatencyP90(latency(p90)));
+        }
+        if let Some(p99) = p99 {
+            measures.push(AdapterMeasure::LatencyP99(latency(p99)));
+        }
+        if let Some(requests_per_sec) = requests_per_sec {
+            measures.push(AdapterMeasure::Throughput(http_metric(requests_per_sec)));
+        }
+        if let Some(success_rate) = success_rate {
+            measures.push(AdapterMeasure::ErrorRate(http_metric(
+                (1.0 - success_rate) * 100.0,
+            )));
+        }
+        if measures.is_empty() {
+            return None;
+        }
+
+        let benchmark_name: BenchmarkName = OHA_BENCHMARK_NAME.parse().ok()?;
+        AdapterResults::new(vec![(benchmark_name, measures)])
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_http_oha {
+    use bencher_json::project::measure::{
+        ERROR_RATE_SLUG_STR, LATENCY_P50_SLUG_STR, LATENCY_P90_SLUG_STR, LATENCY_P99_SLUG_STR,
+        THROUGHPUT_SLUG_STR,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, validate_multi_metric},
+        Adaptable, AdapterResults, Settings,
+    };
+
+    use super::AdapterHttpOha;
+
+    fn convert_http_oha(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/http/oha/{suffix}.json");
+        convert_file_path::<AdapterHttpOha>(&file_path)
+    }
+
+    #[test]
+    fn test_adapter_http_oha() {
+        let results = convert_http_oha("summary");
+        validate_adapter_http_oha(&results);
+    }
+
+    #[test]
+    fn test_adapter_http_oha_no_success() {
+        let input = r#"{
+            "summary": {"successRate": 0.0, "requestsPerSec": 100.0},
+            "latencyPercentiles": {"p50": null, "p90": null, "p99": null}
+        }"#;
+        let results = AdapterHttpOha::parse(input, Settings::default()).unwrap();
+        let metrics = results.get("oha").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_multi_metric(metrics, THROUGHPUT_SLUG_STR, 100.0, None, None);
+        validate_multi_metric(metrics, ERROR_RATE_SLUG_STR, 100.0, None, None);
+    }
+
+    pub fn validate_adapter_http_oha(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("oha").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+        validate_multi_metric(metrics, LATENCY_P50_SLUG_STR, 8_900_000.0, None, None);
+        validate_multi_metric(metrics, LATENCY_P90_SLUG_STR, 13_500_000.0, None, None);
+        validate_multi_metric(metrics, LATENCY_P99_SLUG_STR, 21_300_000.0, None, None);
+        validate_multi_metric(metrics, THROUGHPUT_SLUG_STR, 1_054.3, None, None);
+        validate_multi_metric(
+            metrics,
+            ERROR_RATE_SLUG_STR,
+            1.000_000_000_000_000_9,
+            None,
+            None,
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use bencher_json::{project::report::Adapter, BenchmarkName};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{space0, space1},
+    combinator::{eof, map},
+    sequence::tuple,
+    IResult,
+};
+use ordered_float::OrderedFloat;
+
+use crate::{
+    adapters::{
+        http::http_metric,
+        util::{latency_as_nanos, parse_benchmark_name, parse_f64, parse_u64, parse_units},
+    },
+    diagnostic::first_line_error,
+    results::adapter_results::{AdapterMeasure, AdapterResults},
+    Adaptable, AdapterDiagnostic, Settings,
+};
+
+pub struct AdapterHttpWrk;
+
+const WRK_BENCHMARK_NAME: &str = "wrk";
+const WRK_RUNNING: &str = "Running ";
+const WRK_URL_SEPARATOR: &str = " @ ";
+const WRK_REQUESTS: &str = " requests in ";
+const WRK_SOCKET_ERRORS: &str = "Socket errors:";
+const WRK_NON_2XX_3XX: &str = "Non-2xx or 3xx responses:";
+const WRK_REQUESTS_PER_SEC: &str = "Requests/sec:";
+
+impl Adaptable for AdapterHttpWrk {
+    fn parse(input: &str, _settings: Settings) -> Option<AdapterResults> {
+        let mut url = None;
+        let mut percentiles = Vec::new();
+        let mut requests = None;
+        let mut errors = 0;
+        let mut requests_per_sec = None;
+        for line in input.lines() {
+            let line = line.trim();
+            if let Some(running) = line.strip_prefix(WRK_RUNNING) {
+                url = running
+                    .split_once(WRK_URL_SEPARATOR)
+                    .map(|(_, url)| url.trim());
+            } else if let Ok((_, percentile)) = parse_wrk_percentile(line) {
+                percentiles.push(percentile);
+            } else if let Some((count, _)) = line.split_once(WRK_REQUESTS) {
+                requests = count.trim().parse::<u64>().ok();
+            } else if let Some(socket_errors) = line.strip_prefix(WRK_SOCKET_ERRORS) {
+                // connect 0, read 0, write 0, timeout 48
+                errors += socket_errors
+                    .split(',')
+                    .filter_map(|error| error.split_whitespace().last()?.parse::<u64>().ok())
+                    .sum::<u64>();
+            } else if let Some(non_2xx_3xx) = line.strip_prefix(WRK_NON_2XX_3XX) {
+                errors += non_2xx_3xx.trim().parse::<u64>().unwrap_or_default();
+            } else if line.starts_with(WRK_REQUESTS_PER_SEC) {
+                requests_per_sec = parse_wrk_requests_per_sec(line).ok().map(|(_, rps)| rps);
+            }
+        }
+
+        // The requests per second are always reported at the end of the summary.
+        let requests_per_sec = requests_per_sec?;
+        let mut measures = Vec::new();
+        for (percentile, latency) in percentiles {
+            let measure = match percentile {
+                50 => AdapterMeasure::LatencyP50,
+                90 => AdapterMeasure::LatencyP90,
+                99 => AdapterMeasure::LatencyP99,
+                _ => continue,
+            };
+            measures.push(measure(http_metric(latency)));
+        }
+        measures.push(AdapterMeasure::Throughput(http_metric(requests_per_sec)));
+        if let Some(requests) = requests.filter(|requests| *requests > 0) {
+            #[allow(clippy::cast_precision_loss)]
+            let error_rate = errors as f64 / requests as f64 * 100.0;
+            measures.push(AdapterMeasure::ErrorRate(http_metric(error_rate)));
+        }
+
+        let benchmark_name: BenchmarkName = url
+            .and_then(|url| parse_benchmark_name(url).ok())
+            .or_else(|| WRK_BENCHMARK_NAME.parse().ok())?;
+        AdapterResults::new(vec![(benchmark_name, measures)])
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::HttpWrk, input, settings, || {
+            first_line_error(
+                input,
+                |line| {
+                    line.trim_start().starts_with(WRK_REQUESTS_PER_SEC)
+                        || tuple((space0, parse_u64, tag("%")))(line).is_ok()
+                },
+                |line| {
+                    if line.trim_start().starts_with(WRK_REQUESTS_PER_SEC) {
+                        map(parse_wrk_requests_per_sec, |_| ())(line)
+                    } else {
+                        map(parse_wrk_percentile, |_| ())(line)
+                    }
+                },
+            )
+        })
+    }
+}
+
+// A latency distribution line looks like: `99%    5.80ms`
+// The latency distribution is only reported with `--latency`.
+fn parse_wrk_percentile(input: &str) -> IResult<&str, (u64, OrderedFloat<f64>)> {
+    map(
+        tuple((
+            space0,
+            parse_u64,
+            tag("%"),
+            space1,
+            parse_f64,
+            space0,
+            parse_units,
+            eof,
+        )),
+        |(_, percentile, _, _, latency, _, units, _)| {
+            (percentile, latency_as_nanos(latency, units))
+        },
+    )(input)
+}
+
+// The requests per second line looks like: `Requests/sec: 748868.53`
+fn parse_wrk_requests_per_sec(input: &str) -> IResult<&str, f64> {
+    map(
+        tuple((
+            space0,
+            tag(WRK_REQUESTS_PER_SEC),
+            space0,
+            parse_f64,
+            space0,
+            eof,
+        )),
+        |(_, _, _, requests_per_sec, _, _)| requests_per_sec,
+    )(input)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_http_wrk {
+    use bencher_json::project::measure::{
+        ERROR_RATE_SLUG_STR, LATENCY_P50_SLUG_STR, LATENCY_P90_SLUG_STR, LATENCY_P99_SLUG_STR,
+        THROUGHPUT_SLUG_STR,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, validate_multi_metric},
+        Adaptable, AdapterResults, Settings,
+    };
+
+    use super::{parse_wrk_percentile, parse_wrk_requests_per_sec, AdapterHttpWrk};
+
+    fn convert_http_wrk(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/http/wrk/{suffix}.txt");
+        convert_file_path::<AdapterHttpWrk>(&file_path)
+    }
+
+    #[test]
+    fn test_parse_wrk_percentile() {
+        assert_eq!(
+            parse_wrk_percentile("50%  250.00us"),
+            Ok(("", (50, 250_000.0.into())))
+        );
+        assert_eq!(
+            parse_wrk_percentile("     99%    5.80ms"),
+            Ok(("", (99, 5_800_000.0.into())))
+        );
+        assert!(parse_wrk_percentile("Latency   635.91us").is_err());
+        assert!(parse_wrk_percentile("90%  700.00us  extra").is_err());
+    }
+
+    #[test]
+    fn test_parse_wrk_requests_per_sec() {
+        assert_eq!(
+            parse_wrk_requests_per_sec("Requests/sec: 748868.53"),
+            Ok(("", 748_868.53))
+        );
+        assert!(parse_wrk_requests_per_sec("Requests/sec: many").is_err());
+    }
+
+    #[test]
+    fn test_adapter_http_wrk() {
+        let results = convert_http_wrk("latency");
+        validate_adapter_http_wrk(&results);
+    }
+
+    #[test]
+    fn test_adapter_http_wrk_no_latency() {
+        let input = "Running 10s test @ http://localhost:3000
+  2 threads and 10 connections
+  Thread Stats   Avg      Stdev     Max   +/- Stdev
+    Latency     1.13ms  317.40us   8.18ms   89.02%
+    Req/Sec     4.47k   245.66     4.94k    71.50%
+  89010 requests in 10.00s, 12.48MB read
+Requests/sec:   8899.69
+Transfer/sec:      1.25MB";
+        let results = AdapterHttpWrk::parse(input, Settings::default()).unwrap();
+        let metrics = results.get("http://localhost:3000").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_multi_metric(metrics, THROUGHPUT_SLUG_STR, 8_899.69, None, None);
+        validate_multi_metric(metrics, ERROR_RATE_SLUG_STR, 0.0, None, None);
+    }
+
+    pub fn validate_adapter_http_wrk(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("http://127.0.0.1:8080/index.html").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+        validate_multi_metric(metrics, LATENCY_P50_SLUG_STR, 250_000.0, None, None);
+        validate_multi_metric(metrics, LATENCY_P90_SLUG_STR, 700_000.0, None, None);
+        validate_multi_metric(metrics, LATENCY_P99_SLUG_STR, 5_800_000.0, None, None);
+        validate_multi_metric(metrics, THROUGHPUT_SLUG_STR, 748_868.53, None, None);
+        validate_multi_metric(
+            metrics,
+            ERROR_RATE_SLUG_STR,
+            0.000_267_086_205_678_546_5,
+            None,
+            None,
+        );
+    }
+}
@@ -1,12 +1,16 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonAny, JsonMetric,
+};
 
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
 use crate::{
     adapters::util::{latency_as_nanos, throughput_as_secs},
+    diagnostic::json_error,
     results::adapter_results::{AdapterMeasure, AdapterResults},
-    Adaptable, AdapterError, Settings,
+    Adaptable, AdapterDiagnostic, AdapterError, Settings,
 };
 
 pub struct AdapterJavaJmh;
@@ -20,6 +24,12 @@ impl Adaptable for AdapterJavaJmh {
 
         serde_json::from_str::<Jmh>(input).ok()?.try_into().ok()?
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::JavaJmh, input, settings, || {
+            Some(json_error(input, |jmh: Jmh| jmh.try_into()))
+        })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -100,7 +110,7 @@ impl TryFrom<Jmh> for Option<AdapterResults> {
                 return Err(AdapterError::BenchmarkUnits(score_unit));
             };
 
-            benchmark_metrics.push((benchmark_name, measure));
+            benchmark_metrics.push((benchmark_name, vec![measure]));
         }
 
         Ok(AdapterResults::new(benchmark_metrics))
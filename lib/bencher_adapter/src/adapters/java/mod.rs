@@ -1,13 +1,25 @@
 pub mod jmh;
 
-use crate::{Adaptable, AdapterResults, Settings};
-use jmh::AdapterJavaJmh;
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
+};
 
 pub struct AdapterJava;
 
+/// The adapters that the Java adapter tries, in order.
+pub const JAVA_ADAPTERS: &[Adapter] = &[Adapter::JavaJmh];
+
 impl Adaptable for AdapterJava {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterJavaJmh::parse(input, settings)
+        JAVA_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(JAVA_ADAPTERS, input, settings)
     }
 }
 
@@ -1,4 +1,7 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric,
+};
 
 use nom::{
     bytes::complete::tag,
@@ -14,8 +17,9 @@ use crate::{
         nom_error, parse_benchmark_name_chars, parse_f64, parse_u64, throughput_as_secs, NomError,
         Units,
     },
+    diagnostic::first_line_error,
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, AdapterDiagnostic, Settings,
 };
 
 pub struct AdapterJsBenchmark;
@@ -39,6 +43,12 @@ impl Adaptable for AdapterJsBenchmark {
 
         AdapterResults::new_throughput(benchmark_metrics)
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::JsBenchmark, input, settings, || {
+            first_line_error(input, |line| line.contains(" ops/sec"), parse_benchmark)
+        })
+    }
 }
 
 fn parse_benchmark(input: &str) -> IResult<&str, (BenchmarkName, JsonMetric)> {
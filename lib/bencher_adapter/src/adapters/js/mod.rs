@@ -1,21 +1,33 @@
 pub mod benchmark;
 pub mod time;
+pub mod vitest;
 
-use crate::{Adaptable, AdapterResults, Settings};
-use benchmark::AdapterJsBenchmark;
-use time::AdapterJsTime;
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
+};
 
 pub struct AdapterJs;
 
+/// The adapters that the JavaScript adapter tries, in order.
+pub const JS_ADAPTERS: &[Adapter] = &[Adapter::JsBenchmark, Adapter::JsTime, Adapter::JsVitest];
+
 impl Adaptable for AdapterJs {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterJsBenchmark::parse(input, settings).or_else(|| AdapterJsTime::parse(input, settings))
+        JS_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(JS_ADAPTERS, input, settings)
     }
 }
 
 #[cfg(test)]
 mod test_js {
-    use super::{time::test_js_time, AdapterJs};
+    use super::{time::test_js_time, vitest::test_js_vitest, AdapterJs};
     use crate::adapters::{js::benchmark::test_js_benchmark, test_util::convert_file_path};
 
     #[test]
@@ -29,4 +41,10 @@ mod test_js {
         let results = convert_file_path::<AdapterJs>("./tool_output/js/time/four.txt");
         test_js_time::validate_adapter_js_time(&results);
     }
+
+    #[test]
+    fn test_adapter_js_vitest() {
+        let results = convert_file_path::<AdapterJs>("./tool_output/js/vitest/two.txt");
+        test_js_vitest::validate_adapter_js_vitest(&results);
+    }
 }
@@ -1,4 +1,7 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric,
+};
 
 use nom::{
     bytes::complete::tag,
@@ -13,8 +16,9 @@ use crate::{
     adapters::util::{
         latency_as_nanos, nom_error, parse_benchmark_name_chars, parse_u64, parse_units, NomError,
     },
+    diagnostic::first_line_error,
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, AdapterDiagnostic, Settings,
 };
 
 pub struct AdapterJsTime;
@@ -37,6 +41,12 @@ impl Adaptable for AdapterJsTime {
 
         AdapterResults::new_latency(benchmark_metrics)
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::JsTime, input, settings, || {
+            first_line_error(input, |line| line.ends_with(" - timer ended"), parse_time)
+        })
+    }
 }
 
 fn parse_time(input: &str) -> IResult<&str, (BenchmarkName, JsonMetric)> {
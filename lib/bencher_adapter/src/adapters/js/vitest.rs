@@ -0,0 +1,400 @@
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    JsonMetric,
+};
+use nom::{combinator::eof, sequence::tuple};
+use serde::Deserialize;
+
+use crate::{
+    adapters::util::{
+        latency_as_nanos, parse_benchmark_name, parse_f64, throughput_as_secs, Units,
+    },
+    diagnostic::{json_error, looks_like_json, parse_error},
+    results::adapter_results::{AdapterMeasure, AdapterResults},
+    Adaptable, AdapterDiagnostic, Settings,
+};
+
+pub struct AdapterJsVitest;
+
+const VITEST_NAME_SEPARATOR: &str = " > ";
+const VITEST_PASS: &str = "✓ ";
+const VITEST_BENCHMARK: &str = "· ";
+const VITEST_NAME_HEADER: &str = "name";
+const VITEST_REQUIRED_COLUMNS: [&str; 3] = ["hz", "mean", "rme"];
+const VITEST_RANKS: [&str; 2] = ["fastest", "slowest"];
+
+impl Adaptable for AdapterJsVitest {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        let average = settings.average.unwrap_or_default();
+        if let Ok(vitest) = serde_json::from_str::<Vitest>(input) {
+            vitest.convert(average)
+        } else {
+            parse_vitest_table(input, average)
+        }
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::JsVitest, input, settings, || {
+            if looks_like_json(input) {
+                let average = settings.average.unwrap_or_default();
+                return Some(json_error(input, |vitest: Vitest| {
+                    Ok(vitest.convert(average))
+                }));
+            }
+
+            let mut columns = Vec::new();
+            input.lines().enumerate().find_map(|(index, line)| {
+                let trimmed = line.trim_start();
+                if let Some(header) = trimmed.strip_prefix(VITEST_NAME_HEADER) {
+                    columns = parse_vitest_header(header);
+                    return None;
+                }
+                let row = trimmed.strip_prefix(VITEST_BENCHMARK)?;
+                if columns.is_empty() {
+                    return None;
+                }
+                let offset = parse_vitest_row(row, &columns).err()?;
+                Some(parse_error(
+                    input,
+                    index,
+                    line,
+                    line.len() - row.len() + offset,
+                ))
+            })
+        })
+    }
+}
+
+// The JSON output from `vitest bench --outputJson`
+#[derive(Debug, Clone, Deserialize)]
+pub struct Vitest {
+    pub files: Vec<VitestFile>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VitestFile {
+    pub groups: Vec<VitestGroup>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VitestGroup {
+    pub full_name: String,
+    pub benchmarks: Vec<VitestBenchmark>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VitestBenchmark {
+    pub name: String,
+    #[serde(flatten)]
+    pub stats: VitestStats,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct VitestStats {
+    /// Operations per second
+    pub hz: f64,
+    /// All times are in milliseconds
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    /// The median is not available in the table output,
+    /// so the mean is used instead.
+    pub median: Option<f64>,
+    /// The relative margin of error, as a percentage
+    pub rme: f64,
+}
+
+impl Vitest {
+    fn convert(self, average: JsonAverage) -> Option<AdapterResults> {
+        let mut benchmark_metrics = Vec::new();
+        for group in self.files.into_iter().flat_map(|file| file.groups) {
+            let VitestGroup {
+                full_name,
+                benchmarks,
+            } = group;
+            for benchmark in benchmarks {
+                let VitestBenchmark { name, stats } = benchmark;
+                let Ok(benchmark_name) =
+                    parse_benchmark_name(&[full_name.as_str(), &name].join(VITEST_NAME_SEPARATOR))
+                else {
+                    continue;
+                };
+                benchmark_metrics.push((benchmark_name, stats.into_measures(average)));
+            }
+        }
+
+        AdapterResults::new(benchmark_metrics)
+    }
+}
+
+impl VitestStats {
+    fn into_measures(self, average: JsonAverage) -> Vec<AdapterMeasure> {
+        let Self {
+            hz,
+            min,
+            max,
+            mean,
+            median,
+            rme,
+        } = self;
+
+        let throughput = throughput_as_secs(hz, Units::Sec);
+        let throughput_error = throughput * rme / 100.0;
+        let throughput = JsonMetric {
+            value: throughput,
+            lower_value: Some(throughput - throughput_error),
+            upper_value: Some(throughput + throughput_error),
+        };
+
+        let units = Units::Milli;
+        let latency = match (average, median) {
+            (JsonAverage::Median, Some(median)) => JsonMetric {
+                value: latency_as_nanos(median, units),
+                lower_value: Some(latency_as_nanos(min, units)),
+                upper_value: Some(latency_as_nanos(max, units)),
+            },
+            (JsonAverage::Mean, _) | (JsonAverage::Median, None) => {
+                let value = latency_as_nanos(mean, units);
+                let error = value * rme / 100.0;
+                JsonMetric {
+                    value,
+                    lower_value: Some(value - error),
+                    upper_value: Some(value + error),
+                }
+            },
+        };
+
+        vec![
+            AdapterMeasure::Latency(latency),
+            AdapterMeasure::Throughput(throughput),
+        ]
+    }
+}
+
+// The table output from `vitest bench`
+fn parse_vitest_table(input: &str, average: JsonAverage) -> Option<AdapterResults> {
+    let mut benchmark_metrics = Vec::new();
+    // The indentation and name of each file and describe block containing the current table.
+    let mut groups = Vec::<(usize, &str)>::new();
+    let mut columns = Vec::new();
+    for line in input.lines() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        if let Some(group) = trimmed.strip_prefix(VITEST_PASS) {
+            // The group name is followed by the number of benchmarks and the duration.
+            let name = group
+                .rsplit_once(" (")
+                .map_or(group, |(name, _)| name)
+                .trim();
+            groups.retain(|(group_indent, _)| *group_indent < indent);
+            groups.push((indent, name));
+        } else if let Some(header) = trimmed.strip_prefix(VITEST_NAME_HEADER) {
+            columns = parse_vitest_header(header);
+        } else if let Some(row) = trimmed.strip_prefix(VITEST_BENCHMARK) {
+            if columns.is_empty() {
+                continue;
+            }
+            let Ok((name, stats)) = parse_vitest_row(row, &columns) else {
+                continue;
+            };
+            let full_name = groups
+                .iter()
+                .map(|(_, group)| *group)
+                .chain(std::iter::once(name.as_str()))
+                .collect::<Vec<_>>()
+                .join(VITEST_NAME_SEPARATOR);
+            let Ok(benchmark_name) = parse_benchmark_name(&full_name) else {
+                continue;
+            };
+            benchmark_metrics.push((benchmark_name, stats.into_measures(average)));
+        }
+    }
+
+    AdapterResults::new(benchmark_metrics)
+}
+
+// The columns are empty if any of the required columns are missing.
+fn parse_vitest_header(header: &str) -> Vec<&str> {
+    let columns = header.split_whitespace().collect::<Vec<_>>();
+    if VITEST_REQUIRED_COLUMNS
+        .iter()
+        .all(|required| columns.contains(required))
+    {
+        columns
+    } else {
+        Vec::new()
+    }
+}
+
+// The benchmark name is followed by one value for each column in the header,
+// and then optionally whether it was the fastest or slowest benchmark.
+// If the row can not be parsed, then the error is the byte offset in the row of the invalid value.
+fn parse_vitest_row(row: &str, columns: &[&str]) -> Result<(String, VitestStats), usize> {
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+    for token in row.split_inclusive(char::is_whitespace) {
+        if !token.trim_end().is_empty() {
+            tokens.push((offset, token.trim_end()));
+        }
+        offset += token.len();
+    }
+    if tokens
+        .last()
+        .is_some_and(|(_, token)| VITEST_RANKS.contains(token))
+    {
+        tokens.pop();
+    }
+    let Some(name_len) = tokens.len().checked_sub(columns.len()) else {
+        return Err(0);
+    };
+    let (name, values) = tokens.split_at(name_len);
+    if name.is_empty() {
+        return Err(0);
+    }
+
+    let mut stats = VitestStats::default();
+    for (&column, &(offset, value)) in columns.iter().zip(values) {
+        let number = || parse_vitest_number(value).ok_or(offset);
+        match column {
+            "hz" => stats.hz = number()?,
+            "min" => stats.min = number()?,
+            "max" => stats.max = number()?,
+            "mean" => stats.mean = number()?,
+            "rme" => {
+                let rme = value
+                    .strip_prefix('±')
+                    .and_then(|rme| rme.strip_suffix('%'));
+                stats.rme = rme.and_then(parse_vitest_number).ok_or(offset)?;
+            },
+            _ => {},
+        }
+    }
+    let name = name.iter().map(|(_, token)| *token).collect::<Vec<_>>();
+    Ok((name.join(" "), stats))
+}
+
+fn parse_vitest_number(input: &str) -> Option<f64> {
+    let (_, (number, _)) = tuple((parse_f64, eof))(input).ok()?;
+    Some(number)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_js_vitest {
+    use bencher_json::project::measure::{LATENCY_SLUG_STR, THROUGHPUT_SLUG_STR};
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, convert_file_path_median, validate_multi_metric},
+        Adaptable, AdapterResults, Settings,
+    };
+
+    use super::{parse_vitest_row, AdapterJsVitest};
+
+    fn convert_js_vitest(file_name: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/js/vitest/{file_name}");
+        convert_file_path::<AdapterJsVitest>(&file_path)
+    }
+
+    #[test]
+    fn test_parse_vitest_row() {
+        let columns = ["hz", "min", "max", "mean", "rme", "samples"];
+        let (name, stats) = parse_vitest_row(
+            "quick sort  1,234.5  0.5  1.5  0.81  ±1.25%  617  fastest",
+            &columns,
+        )
+        .unwrap();
+        assert_eq!(name, "quick sort");
+        assert_eq!(OrderedFloat(stats.hz), OrderedFloat(1_234.5));
+        assert_eq!(OrderedFloat(stats.mean), OrderedFloat(0.81));
+        assert_eq!(OrderedFloat(stats.rme), OrderedFloat(1.25));
+        assert_eq!(stats.median, None);
+
+        assert_eq!(
+            parse_vitest_row("0.5  1.5  0.81  ±1.25%  617", &columns).unwrap_err(),
+            0
+        );
+        assert_eq!(
+            parse_vitest_row("sort  fast  0.5  1.5  0.81  ±1.25%  617", &columns).unwrap_err(),
+            6
+        );
+    }
+
+    #[test]
+    fn test_adapter_js_vitest_table() {
+        let results = convert_js_vitest("two.txt");
+        validate_adapter_js_vitest(&results);
+    }
+
+    #[test]
+    fn test_adapter_js_vitest_table_median() {
+        let contents = std::fs::read_to_string("./tool_output/js/vitest/two.txt").unwrap();
+        let settings = Settings {
+            average: Some(bencher_json::project::report::JsonAverage::Median),
+        };
+        let results = AdapterJsVitest::parse(&contents, settings).unwrap();
+        validate_adapter_js_vitest(&results);
+    }
+
+    #[test]
+    fn test_adapter_js_vitest_json() {
+        let results = convert_js_vitest("two.json");
+        validate_adapter_js_vitest(&results);
+    }
+
+    #[test]
+    fn test_adapter_js_vitest_json_median() {
+        let results =
+            convert_file_path_median::<AdapterJsVitest>("./tool_output/js/vitest/two.json");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("bench/sort.bench.ts > sort > normal").unwrap();
+        validate_multi_metric(
+            metrics,
+            LATENCY_SLUG_STR,
+            149_800.0,
+            Some(134_700.0),
+            Some(437_400.0),
+        );
+    }
+
+    pub fn validate_adapter_js_vitest(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("bench/sort.bench.ts > sort > normal").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_multi_metric(
+            metrics,
+            LATENCY_SLUG_STR,
+            153_200.0,
+            Some(152_449.32),
+            Some(153_950.68),
+        );
+        validate_multi_metric(
+            metrics,
+            THROUGHPUT_SLUG_STR,
+            6_526.82,
+            Some(6_494.838_581_999_999),
+            Some(6_558.801_418),
+        );
+
+        let metrics = results.get("bench/sort.bench.ts > sort > reverse").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_multi_metric(
+            metrics,
+            LATENCY_SLUG_STR,
+            369_600.0,
+            Some(368_121.6),
+            Some(371_078.4),
+        );
+        validate_multi_metric(
+            metrics,
+            THROUGHPUT_SLUG_STR,
+            2_705.28,
+            Some(2_694.458_88),
+            Some(2_716.101_120_000_000_3),
+        );
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{results::adapter_results::AdapterResults, Adaptable, Settings};
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::json_error, results::adapter_results::AdapterResults, Adaptable, AdapterDiagnostic,
+    Settings,
+};
 
 pub struct AdapterJson;
 
@@ -6,6 +11,14 @@ impl Adaptable for AdapterJson {
     fn parse(input: &str, _settings: Settings) -> Option<AdapterResults> {
         serde_json::from_str(input).ok()
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::Json, input, settings, || {
+            Some(json_error(input, |results: AdapterResults| {
+                Ok(Some(results))
+            }))
+        })
+    }
 }
 
 #[cfg(test)]
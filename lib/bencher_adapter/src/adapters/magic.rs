@@ -1,23 +1,39 @@
+use bencher_json::project::report::Adapter;
+
 use crate::{
-    results::adapter_results::AdapterResults, Adaptable, AdapterCSharp, AdapterCpp, AdapterGo,
-    AdapterJava, AdapterJs, AdapterJson, AdapterPython, AdapterRuby, AdapterRust, AdapterShell,
-    Settings,
+    diagnostic::diagnose_adapters, results::adapter_results::AdapterResults, Adaptable,
+    AdapterDiagnostic, Settings,
 };
 
 pub struct AdapterMagic;
 
+/// The adapters that the magic adapter tries, in order.
+pub const MAGIC_ADAPTERS: &[Adapter] = &[
+    Adapter::Json,
+    Adapter::CSharp,
+    Adapter::Cpp,
+    Adapter::Elixir,
+    Adapter::Go,
+    Adapter::Http,
+    Adapter::Java,
+    Adapter::Js,
+    Adapter::Python,
+    Adapter::Ruby,
+    Adapter::Rust,
+    Adapter::Shell,
+    Adapter::Swift,
+    Adapter::Zig,
+];
+
 impl Adaptable for AdapterMagic {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterJson::parse(input, settings)
-            .or_else(|| AdapterCSharp::parse(input, settings))
-            .or_else(|| AdapterCpp::parse(input, settings))
-            .or_else(|| AdapterGo::parse(input, settings))
-            .or_else(|| AdapterJava::parse(input, settings))
-            .or_else(|| AdapterJs::parse(input, settings))
-            .or_else(|| AdapterPython::parse(input, settings))
-            .or_else(|| AdapterRuby::parse(input, settings))
-            .or_else(|| AdapterRust::parse(input, settings))
-            .or_else(|| AdapterShell::parse(input, settings))
+        MAGIC_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(MAGIC_ADAPTERS, input, settings)
     }
 }
 
@@ -27,18 +43,22 @@ mod test_magic {
     use crate::adapters::{
         c_sharp::{dot_net::test_c_sharp_dot_net, AdapterCSharp},
         cpp::{catch2::test_cpp_catch2, google::test_cpp_google},
+        elixir::benchee::test_elixir_benchee,
         go::bench::test_go_bench,
+        http::{k6::test_http_k6, oha::test_http_oha, wrk::test_http_wrk},
         java::jmh::test_java_jmh,
-        js::{benchmark::test_js_benchmark, time::test_js_time},
+        js::{benchmark::test_js_benchmark, time::test_js_time, vitest::test_js_vitest},
         json::test_json,
-        python::{asv::test_python_asv, pytest::test_python_pytest},
+        python::{asv::test_python_asv, pyperf::test_python_pyperf, pytest::test_python_pytest},
         ruby::benchmark::test_ruby_benchmark,
         rust::{
-            bench::test_rust_bench, criterion::test_rust_criterion, iai::test_rust_iai,
-            iai_callgrind::test_rust_iai_callgrind,
+            bench::test_rust_bench, criterion::test_rust_criterion, divan::test_rust_divan,
+            iai::test_rust_iai, iai_callgrind::test_rust_iai_callgrind,
         },
-        shell::hyperfine::test_shell_hyperfine,
+        shell::{hyperfine::test_shell_hyperfine, perf_stat::test_shell_perf_stat},
+        swift::benchmark::test_swift_benchmark,
         test_util::convert_file_path,
+        zig::zbench::test_zig_zbench,
     };
 
     #[test]
@@ -71,6 +91,24 @@ mod test_magic {
         test_go_bench::validate_adapter_go_bench(&results);
     }
 
+    #[test]
+    fn test_adapter_magic_http_k6() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/http/k6/summary.json");
+        test_http_k6::validate_adapter_http_k6(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_http_wrk() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/http/wrk/latency.txt");
+        test_http_wrk::validate_adapter_http_wrk(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_http_oha() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/http/oha/summary.json");
+        test_http_oha::validate_adapter_http_oha(&results);
+    }
+
     #[test]
     fn test_adapter_magic_java_jmh() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/java/jmh/six.json");
@@ -89,6 +127,15 @@ mod test_magic {
         test_js_time::validate_adapter_js_time(&results);
     }
 
+    #[test]
+    fn test_adapter_magic_js_vitest() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/js/vitest/two.txt");
+        test_js_vitest::validate_adapter_js_vitest(&results);
+
+        let results = convert_file_path::<AdapterMagic>("./tool_output/js/vitest/two.json");
+        test_js_vitest::validate_adapter_js_vitest(&results);
+    }
+
     #[test]
     fn test_adapter_python_asv() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/python/asv/six.txt");
@@ -101,6 +148,12 @@ mod test_magic {
         test_python_pytest::validate_adapter_python_pytest(&results);
     }
 
+    #[test]
+    fn test_adapter_python_pyperf() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/python/pyperf/two.json");
+        test_python_pyperf::validate_adapter_python_pyperf(&results);
+    }
+
     #[test]
     fn test_adapter_ruby_benchmark() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/ruby/benchmark/five.txt");
@@ -131,9 +184,45 @@ mod test_magic {
         test_rust_iai_callgrind::validate_adapter_rust_iai_callgrind(&results);
     }
 
+    #[test]
+    fn test_adapter_magic_rust_divan() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/rust/divan/many.txt");
+        test_rust_divan::validate_adapter_rust_divan(&results);
+    }
+
     #[test]
     fn test_adapter_magic_shell_hyperfine() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/shell/hyperfine/two.json");
         test_shell_hyperfine::validate_adapter_shell_hyperfine(&results);
     }
+
+    #[test]
+    fn test_adapter_magic_shell_perf_stat() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/shell/perf_stat/two.csv");
+        test_shell_perf_stat::validate_adapter_shell_perf_stat(&results);
+
+        let results = convert_file_path::<AdapterMagic>("./tool_output/shell/perf_stat/two.json");
+        test_shell_perf_stat::validate_adapter_shell_perf_stat(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_swift_benchmark() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/swift/benchmark/three.json");
+        test_swift_benchmark::validate_adapter_swift_benchmark(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_zig_zbench() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/zig/zbench/three.txt");
+        test_zig_zbench::validate_adapter_zig_zbench(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_elixir_benchee() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/elixir/benchee/two.txt");
+        test_elixir_benchee::validate_adapter_elixir_benchee(&results);
+
+        let results = convert_file_path::<AdapterMagic>("./tool_output/elixir/benchee/two.json");
+        test_elixir_benchee::validate_adapter_elixir_benchee_json(&results);
+    }
 }
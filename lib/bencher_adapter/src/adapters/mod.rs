@@ -2,7 +2,10 @@ use nom::IResult;
 
 pub mod c_sharp;
 pub mod cpp;
+pub mod custom;
+pub mod elixir;
 pub mod go;
+pub mod http;
 pub mod java;
 pub mod js;
 pub mod json;
@@ -11,7 +14,9 @@ pub mod python;
 pub mod ruby;
 pub mod rust;
 pub mod shell;
+pub mod swift;
 mod util;
+pub mod zig;
 
 #[allow(dead_code, clippy::print_stdout, clippy::unnecessary_wraps)]
 fn print_ln(input: &str) -> IResult<&str, ()> {
@@ -96,6 +101,16 @@ pub(crate) mod test_util {
         upper_value: Option<f64>,
     ) {
         assert_eq!(metrics.inner.len(), 1);
+        validate_multi_metric(metrics, key, value, lower_value, upper_value);
+    }
+
+    pub fn validate_multi_metric(
+        metrics: &AdapterMetrics,
+        key: &str,
+        value: f64,
+        lower_value: Option<f64>,
+        upper_value: Option<f64>,
+    ) {
         let metric = metrics.get(key).unwrap();
         assert_eq!(metric.value, OrderedFloat::from(value));
         assert_eq!(metric.lower_value, lower_value.map(OrderedFloat::from));
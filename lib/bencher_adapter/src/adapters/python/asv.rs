@@ -1,4 +1,7 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric,
+};
 use nom::{
     bytes::complete::{tag, take_until1, take_while1},
     character::complete::{space0, space1},
@@ -11,8 +14,9 @@ use crate::{
     adapters::util::{
         latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_units, NomError,
     },
+    diagnostic::first_line_error,
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, AdapterDiagnostic, Settings,
 };
 
 pub struct AdapterPythonAsv;
@@ -36,6 +40,16 @@ impl Adaptable for AdapterPythonAsv {
 
         AdapterResults::new_latency(benchmark_metrics)
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::PythonAsv, input, settings, || {
+            first_line_error(
+                input,
+                |line| line.starts_with('[') && line.contains('±'),
+                parse_asv,
+            )
+        })
+    }
 }
 
 fn parse_asv(input: &str) -> IResult<&str, (BenchmarkName, JsonMetric)> {
@@ -1,16 +1,31 @@
 pub mod asv;
+pub mod pyperf;
 pub mod pytest;
 
-use crate::{Adaptable, AdapterResults, Settings};
-use asv::AdapterPythonAsv;
-use pytest::AdapterPythonPytest;
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
+};
 
 pub struct AdapterPython;
 
+/// The adapters that the Python adapter tries, in order.
+pub const PYTHON_ADAPTERS: &[Adapter] = &[
+    Adapter::PythonAsv,
+    Adapter::PythonPytest,
+    Adapter::PythonPyperf,
+];
+
 impl Adaptable for AdapterPython {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterPythonAsv::parse(input, settings)
-            .or_else(|| AdapterPythonPytest::parse(input, settings))
+        PYTHON_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(PYTHON_ADAPTERS, input, settings)
     }
 }
 
@@ -18,8 +33,8 @@ impl Adaptable for AdapterPython {
 mod test_go {
     use super::AdapterPython;
     use crate::adapters::{
-        python::asv::test_python_asv, python::pytest::test_python_pytest,
-        test_util::convert_file_path,
+        python::asv::test_python_asv, python::pyperf::test_python_pyperf,
+        python::pytest::test_python_pytest, test_util::convert_file_path,
     };
 
     #[test]
@@ -33,4 +48,10 @@ mod test_go {
         let results = convert_file_path::<AdapterPython>("./tool_output/python/pytest/four.json");
         test_python_pytest::validate_adapter_python_pytest(&results);
     }
+
+    #[test]
+    fn test_adapter_python_pyperf() {
+        let results = convert_file_path::<AdapterPython>("./tool_output/python/pyperf/two.json");
+        test_python_pyperf::validate_adapter_python_pyperf(&results);
+    }
 }
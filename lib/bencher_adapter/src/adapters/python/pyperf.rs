@@ -0,0 +1,190 @@
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric,
+};
+use serde::Deserialize;
+
+use crate::{
+    adapters::util::{latency_as_nanos, mean_std_dev, median_abs_dev, Units},
+    diagnostic::json_error,
+    results::adapter_results::AdapterResults,
+    Adaptable, AdapterDiagnostic, AdapterError, Settings,
+};
+
+pub struct AdapterPythonPyperf;
+
+const PYPERF_SECOND_UNIT: &str = "second";
+
+impl Adaptable for AdapterPythonPyperf {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<Pyperf>(input)
+            .ok()?
+            .convert(settings)
+            .ok()?
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::PythonPyperf, input, settings, || {
+            Some(json_error(input, |pyperf: Pyperf| pyperf.convert(settings)))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Pyperf {
+    pub version: String,
+    /// Metadata common to all of the benchmarks in the suite.
+    #[serde(default)]
+    pub metadata: Metadata,
+    pub benchmarks: Vec<Benchmark>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Metadata {
+    pub name: Option<BenchmarkName>,
+    pub unit: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Benchmark {
+    #[serde(default)]
+    pub metadata: Metadata,
+    pub runs: Vec<Run>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Run {
+    /// Calibration runs only have warmups and no values.
+    #[serde(default)]
+    pub values: Vec<f64>,
+}
+
+impl Pyperf {
+    #[allow(clippy::unnecessary_wraps)]
+    fn convert(self, settings: Settings) -> Result<Option<AdapterResults>, AdapterError> {
+        let Self {
+            metadata: suite_metadata,
+            benchmarks,
+            ..
+        } = self;
+        let mut benchmark_metrics = Vec::with_capacity(benchmarks.len());
+        for benchmark in benchmarks {
+            let Benchmark { metadata, runs } = benchmark;
+            let Some(benchmark_name) = metadata.name.or_else(|| suite_metadata.name.clone()) else {
+                continue;
+            };
+            // Only timing benchmarks are supported, not memory tracking or other custom units.
+            let unit = metadata.unit.as_ref().or(suite_metadata.unit.as_ref());
+            if unit.is_some_and(|unit| unit != PYPERF_SECOND_UNIT) {
+                continue;
+            }
+
+            let values = runs
+                .into_iter()
+                .flat_map(|run| run.values)
+                .collect::<Vec<_>>();
+            let Some((average, spread)) = (match settings.average.unwrap_or_default() {
+                JsonAverage::Mean => mean_std_dev(&values),
+                JsonAverage::Median => median_abs_dev(&values),
+            }) else {
+                continue;
+            };
+
+            // JSON output is always in seconds
+            let units = Units::Sec;
+            let value = latency_as_nanos(average, units);
+            let (lower_value, upper_value) = spread.map_or((None, None), |spread| {
+                (
+                    Some(latency_as_nanos(average - spread, units)),
+                    Some(latency_as_nanos(average + spread, units)),
+                )
+            });
+            let json_metric = JsonMetric {
+                value,
+                lower_value,
+                upper_value,
+            };
+
+            benchmark_metrics.push((benchmark_name, json_metric));
+        }
+
+        Ok(AdapterResults::new_latency(benchmark_metrics))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_python_pyperf {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, convert_file_path_median, validate_latency},
+        AdapterResults,
+    };
+
+    use crate::adapters::util::{mean_std_dev, median_abs_dev};
+
+    use super::AdapterPythonPyperf;
+
+    fn file_path(suffix: &str) -> String {
+        format!("./tool_output/python/pyperf/{suffix}.json")
+    }
+
+    fn convert_python_pyperf(suffix: &str) -> AdapterResults {
+        convert_file_path::<AdapterPythonPyperf>(&file_path(suffix))
+    }
+
+    #[test]
+    fn test_python_pyperf_stats() {
+        assert_eq!(mean_std_dev(&[]), None);
+        assert_eq!(mean_std_dev(&[2.0]), Some((2.0, None)));
+        assert_eq!(mean_std_dev(&[1.0, 3.0]), Some((2.0, Some(f64::sqrt(2.0)))));
+        assert_eq!(median_abs_dev(&[]), None);
+        assert_eq!(
+            median_abs_dev(&[3.0, 1.0, 2.0, 6.0]),
+            Some((2.5, Some(1.0)))
+        );
+    }
+
+    #[test]
+    fn test_adapter_python_pyperf() {
+        let results = convert_python_pyperf("two");
+        validate_adapter_python_pyperf(&results);
+    }
+
+    #[test]
+    fn test_adapter_python_pyperf_median() {
+        let results = convert_file_path_median::<AdapterPythonPyperf>(&file_path("two"));
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("json_dumps").unwrap();
+        validate_latency(metrics, 12_050.0, Some(11_900.0), Some(12_200.0));
+
+        let metrics = results.get("json_loads").unwrap();
+        validate_latency(metrics, 250_000.0, Some(248_000.0), Some(252_000.0));
+    }
+
+    pub fn validate_adapter_python_pyperf(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("json_dumps").unwrap();
+        validate_latency(
+            metrics,
+            12_166.666_666_666_666,
+            Some(11_734.617_286_772_81),
+            Some(12_598.716_046_560_524),
+        );
+
+        let metrics = results.get("json_loads").unwrap();
+        validate_latency(
+            metrics,
+            250_000.0,
+            Some(246_734.013_676_289_12),
+            Some(253_265.986_323_710_88),
+        );
+    }
+}
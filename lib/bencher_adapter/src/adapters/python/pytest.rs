@@ -1,12 +1,16 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonAny, JsonMetric,
+};
 
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
 use crate::{
     adapters::util::{latency_as_nanos, Units},
+    diagnostic::json_error,
     results::adapter_results::AdapterResults,
-    Adaptable, AdapterError, Settings,
+    Adaptable, AdapterDiagnostic, AdapterError, Settings,
 };
 
 pub struct AdapterPythonPytest;
@@ -18,6 +22,12 @@ impl Adaptable for AdapterPythonPytest {
             .convert(settings)
             .ok()?
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::PythonPytest, input, settings, || {
+            Some(json_error(input, |pytest: Pytest| pytest.convert(settings)))
+        })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
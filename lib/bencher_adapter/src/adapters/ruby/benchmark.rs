@@ -1,4 +1,7 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric,
+};
 use nom::{
     bytes::complete::tag,
     character::complete::{anychar, space1},
@@ -11,7 +14,7 @@ use nom::{
 use crate::{
     adapters::util::{latency_as_nanos, parse_benchmark_name_chars, parse_f64, NomError, Units},
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, AdapterDiagnostic, Settings,
 };
 
 pub struct AdapterRubyBenchmark;
@@ -43,6 +46,10 @@ impl Adaptable for AdapterRubyBenchmark {
 
         AdapterResults::new_latency(benchmark_metrics)
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::RubyBenchmark, input, settings, || None)
+    }
 }
 
 fn parse_header(input: &str) -> IResult<&str, ()> {
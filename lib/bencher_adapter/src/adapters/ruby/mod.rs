@@ -1,13 +1,25 @@
 pub mod benchmark;
 
-use crate::{Adaptable, AdapterResults, Settings};
-use benchmark::AdapterRubyBenchmark;
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
+};
 
 pub struct AdapterRuby;
 
+/// The adapters that the Ruby adapter tries, in order.
+pub const RUBY_ADAPTERS: &[Adapter] = &[Adapter::RubyBenchmark];
+
 impl Adaptable for AdapterRuby {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterRubyBenchmark::parse(input, settings)
+        RUBY_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(RUBY_ADAPTERS, input, settings)
     }
 }
 
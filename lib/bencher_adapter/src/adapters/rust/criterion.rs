@@ -1,8 +1,12 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric,
+};
 use nom::{
+    branch::alt,
     bytes::complete::tag,
     character::complete::{anychar, space1},
-    combinator::{eof, map, map_res},
+    combinator::{eof, map, map_res, success},
     multi::many_till,
     sequence::{delimited, tuple},
     IResult,
@@ -13,8 +17,9 @@ use crate::{
     adapters::util::{
         latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_units, NomError,
     },
-    results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    diagnostic::{first_line_error, parse_named},
+    results::adapter_results::{AdapterMeasure, AdapterResults},
+    Adaptable, AdapterDiagnostic, Settings,
 };
 
 pub struct AdapterRustCriterion;
@@ -30,16 +35,52 @@ impl Adaptable for AdapterRustCriterion {
 
         let mut prior_line = None;
         for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_criterion(prior_line, line) {
+            if let Ok((remainder, (benchmark_name, json_metric))) =
+                parse_criterion(prior_line, line)
+            {
+                if remainder.is_empty() {
+                    benchmark_metrics
+                        .push((benchmark_name, vec![AdapterMeasure::Latency(json_metric)]));
+                }
+            } else if let Ok((remainder, json_metric)) = parse_criterion_throughput(line) {
+                // The throughput line always follows the time line for the same benchmark.
                 if remainder.is_empty() {
-                    benchmark_metrics.push(benchmark_metric);
+                    if let Some((_, measures)) = benchmark_metrics.last_mut() {
+                        measures.push(AdapterMeasure::Throughput(json_metric));
+                    }
                 }
             }
 
             prior_line = Some(line);
         }
 
-        AdapterResults::new_latency(benchmark_metrics)
+        AdapterResults::new(benchmark_metrics)
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        // The benchmark name may be on the line before its time,
+        // so only the time and throughput columns are checked.
+        AdapterDiagnostic::explain(Adapter::RustCriterion, input, settings, || {
+            first_line_error(
+                input,
+                |line| line.contains(" time:") || line.contains(" thrpt:"),
+                |line| {
+                    if line.contains(" thrpt:") {
+                        parse_named(
+                            line,
+                            many_till(anychar, parse_criterion_throughput),
+                            parse_criterion_throughput,
+                        )
+                    } else {
+                        parse_named(
+                            line,
+                            many_till(anychar, parse_criterion_time),
+                            parse_criterion_time,
+                        )
+                    }
+                },
+            )
+        })
     }
 }
 
@@ -100,14 +141,69 @@ fn parse_criterion_duration(input: &str) -> IResult<&str, OrderedFloat<f64>> {
     )(input)
 }
 
+fn parse_criterion_throughput(input: &str) -> IResult<&str, JsonMetric> {
+    map(
+        tuple((
+            tuple((space1, tag("thrpt:"), space1)),
+            delimited(
+                tag("["),
+                tuple((
+                    parse_criterion_rate,
+                    space1,
+                    parse_criterion_rate,
+                    space1,
+                    parse_criterion_rate,
+                )),
+                tag("]"),
+            ),
+            eof,
+        )),
+        |(_, (lower_value, _, value, _, upper_value), _)| JsonMetric {
+            value,
+            lower_value: Some(lower_value),
+            upper_value: Some(upper_value),
+        },
+    )(input)
+}
+
+// Criterion scales elements and decimal bytes by powers of 1000 and binary bytes by powers of 1024.
+fn parse_criterion_rate(input: &str) -> IResult<&str, OrderedFloat<f64>> {
+    map(
+        tuple((
+            parse_f64,
+            space1,
+            alt((
+                map(tag("Ki"), |_| 1_024.0),
+                map(tag("Mi"), |_| 1_048_576.0),
+                map(tag("Gi"), |_| 1_073_741_824.0),
+                map(tag("K"), |_| 1_000.0),
+                map(tag("M"), |_| 1_000_000.0),
+                map(tag("G"), |_| 1_000_000_000.0),
+                success(1.0),
+            )),
+            alt((tag("elem"), tag("B"))),
+            tag("/s"),
+        )),
+        |(rate, _, scale, _, _)| (rate * scale).into(),
+    )(input)
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 pub(crate) mod test_rust_criterion {
-    use bencher_json::{project::report::JsonAverage, JsonMetric};
+    use bencher_json::{
+        project::{
+            measure::{LATENCY_SLUG_STR, THROUGHPUT_SLUG_STR},
+            report::JsonAverage,
+        },
+        JsonMetric,
+    };
     use pretty_assertions::assert_eq;
 
     use crate::{
-        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        adapters::test_util::{
+            convert_file_path, opt_convert_file_path, validate_latency, validate_multi_metric,
+        },
         Adaptable, AdapterResults, Settings,
     };
 
@@ -229,6 +325,49 @@ pub(crate) mod test_rust_criterion {
         validate_latency(metrics, 20.930, Some(18.195), Some(24.240));
     }
 
+    #[test]
+    fn test_adapter_rust_criterion_throughput() {
+        let results = convert_rust_criterion("throughput");
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("parse/small").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_multi_metric(
+            metrics,
+            LATENCY_SLUG_STR,
+            2_400.0,
+            Some(2_375.0),
+            Some(2_425.0),
+        );
+        validate_multi_metric(
+            metrics,
+            THROUGHPUT_SLUG_STR,
+            416.67 * 1_000.0,
+            Some(412.37 * 1_000.0),
+            Some(421.05 * 1_000.0),
+        );
+
+        let metrics = results.get("parse/large").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_multi_metric(
+            metrics,
+            LATENCY_SLUG_STR,
+            256_000.0,
+            Some(250_000.0),
+            Some(262_000.0),
+        );
+        validate_multi_metric(
+            metrics,
+            THROUGHPUT_SLUG_STR,
+            3.8147 * 1_073_741_824.0,
+            Some(3.7273 * 1_073_741_824.0),
+            Some(3.9063 * 1_073_741_824.0),
+        );
+
+        let metrics = results.get("serialize").unwrap();
+        validate_latency(metrics, 101.0, Some(100.0), Some(102.0));
+    }
+
     #[test]
     fn test_adapter_rust_criterion_failed() {
         let contents = std::fs::read_to_string("./tool_output/rust/criterion/failed.txt").unwrap();
@@ -0,0 +1,384 @@
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{space0, space1},
+    combinator::{eof, map},
+    sequence::tuple,
+    IResult,
+};
+use ordered_float::OrderedFloat;
+
+use crate::{
+    adapters::util::{latency_as_nanos, parse_benchmark_name, parse_f64, parse_units},
+    diagnostic::parse_error,
+    results::adapter_results::{AdapterMeasure, AdapterResults},
+    Adaptable, AdapterDiagnostic, Settings,
+};
+
+pub struct AdapterRustDivan;
+
+const DIVAN_COLUMN_SEPARATOR: char = '│';
+const DIVAN_FASTEST_HEADER: &str = "fastest";
+const DIVAN_BRANCH: &str = "├─ ";
+const DIVAN_LAST_BRANCH: &str = "╰─ ";
+const DIVAN_INDENT_WIDTH: usize = 3;
+const DIVAN_ALLOC_COUNTER: &str = "alloc:";
+const DIVAN_PATH_SEPARATOR: &str = "::";
+
+// The allocation counter rows that follow an `alloc:` label.
+#[derive(Debug, Clone, Copy)]
+enum AllocRow {
+    Count,
+    Bytes,
+}
+
+impl Adaptable for AdapterRustDivan {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        let average = settings.average.unwrap_or_default();
+
+        let mut benchmark_metrics = Vec::<(BenchmarkName, Vec<AdapterMeasure>)>::new();
+        // The char index of the first column separator, from the table header.
+        let mut name_width = None;
+        // The names from the root of the tree down to the current group or benchmark.
+        let mut path = Vec::new();
+        let mut alloc_row = None;
+        for line in input.lines() {
+            // A table header looks like: `example  fastest │ slowest │ median │ mean │ samples │ iters`
+            if let Some((root, width)) = parse_divan_header(line) {
+                name_width = Some(width);
+                path = vec![root];
+                alloc_row = None;
+                continue;
+            }
+            let Some(width) = name_width else {
+                continue;
+            };
+            let Some((name_column, columns)) = split_divan_row(line, width) else {
+                // Anything that is not a table row ends the table.
+                name_width = None;
+                continue;
+            };
+
+            if let Some((depth, name_column)) = parse_divan_branch(&name_column) {
+                alloc_row = None;
+                path.truncate(depth);
+                let Some((name, fastest)) = parse_divan_name(name_column) else {
+                    continue;
+                };
+                path.push(name);
+                // Groups do not have any timings of their own.
+                let Some(fastest) = fastest else {
+                    continue;
+                };
+                let Some(json_metric) = parse_divan_latency(fastest, &columns, average) else {
+                    continue;
+                };
+                let Ok(benchmark_name) = parse_benchmark_name(&path.join(DIVAN_PATH_SEPARATOR))
+                else {
+                    continue;
+                };
+                benchmark_metrics
+                    .push((benchmark_name, vec![AdapterMeasure::Latency(json_metric)]));
+                continue;
+            }
+
+            // Any other rows are counters for the most recent benchmark.
+            let counter = name_column
+                .trim_matches(|c: char| c == DIVAN_COLUMN_SEPARATOR || c.is_whitespace());
+            let Some((_, measures)) = benchmark_metrics.last_mut() else {
+                continue;
+            };
+            alloc_row = match alloc_row {
+                _ if counter == DIVAN_ALLOC_COUNTER => Some(AllocRow::Count),
+                Some(AllocRow::Count) => {
+                    if let Some(value) =
+                        divan_average(&columns, average).and_then(parse_divan_count)
+                    {
+                        measures.push(AdapterMeasure::Allocations(divan_counter(value)));
+                    }
+                    Some(AllocRow::Bytes)
+                },
+                Some(AllocRow::Bytes) => {
+                    if let Some(value) =
+                        divan_average(&columns, average).and_then(parse_divan_bytes)
+                    {
+                        measures.push(AdapterMeasure::AllocatedBytes(divan_counter(value)));
+                    }
+                    None
+                },
+                None => None,
+            };
+        }
+
+        AdapterResults::new(benchmark_metrics)
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::RustDivan, input, settings, || {
+            let mut name_width = None;
+            input.lines().enumerate().find_map(|(index, line)| {
+                if let Some((_, width)) = parse_divan_header(line) {
+                    name_width = Some(width);
+                    return None;
+                }
+                let width = name_width?;
+                let Some((name_column, columns)) = split_divan_row(line, width) else {
+                    // Anything that is not a table row ends the table.
+                    name_width = None;
+                    return None;
+                };
+                let offset = divan_row_error(line, &name_column, &columns)?;
+                Some(parse_error(input, index, line, offset))
+            })
+        })
+    }
+}
+
+fn parse_divan_header(line: &str) -> Option<(String, usize)> {
+    let width = line.chars().position(|c| c == DIVAN_COLUMN_SEPARATOR)?;
+    let name_column = line.chars().take(width).collect::<String>();
+    let root = name_column
+        .trim_end()
+        .strip_suffix(DIVAN_FASTEST_HEADER)?
+        .trim();
+    let columns = line
+        .split(DIVAN_COLUMN_SEPARATOR)
+        .skip(1)
+        .map(str::trim)
+        .collect::<Vec<_>>();
+    (!root.is_empty() && columns.starts_with(&["slowest", "median", "mean"]))
+        .then(|| (root.to_owned(), width))
+}
+
+// Tree branches in the name column also use the column separator,
+// so the name column is split off by its width from the table header.
+fn split_divan_row(line: &str, width: usize) -> Option<(String, Vec<&str>)> {
+    let (index, separator) = line.char_indices().nth(width)?;
+    if separator != DIVAN_COLUMN_SEPARATOR {
+        return None;
+    }
+    let name_column = line.get(..index)?.to_owned();
+    let columns = line
+        .get(index + separator.len_utf8()..)?
+        .split(DIVAN_COLUMN_SEPARATOR)
+        .map(str::trim)
+        .collect();
+    Some((name_column, columns))
+}
+
+// Returns the byte offset in the line of the first timing that can not be parsed,
+// if the row is for a benchmark.
+fn divan_row_error(line: &str, name_column: &str, columns: &[&str]) -> Option<usize> {
+    let (_, name) = parse_divan_branch(name_column)?;
+    // Groups do not have any timings of their own.
+    if columns.iter().all(|column| column.is_empty()) {
+        return None;
+    }
+    if !matches!(parse_divan_name(name), Some((_, Some(_)))) {
+        return Some(name_column.len() - name.trim_start().len());
+    }
+
+    // The slowest, median, and mean columns
+    let mut offset = name_column.len();
+    for column in line
+        .get(offset..)?
+        .split(DIVAN_COLUMN_SEPARATOR)
+        .skip(1)
+        .take(3)
+    {
+        offset += DIVAN_COLUMN_SEPARATOR.len_utf8();
+        if parse_divan_duration(column.trim()).is_none() {
+            return Some(offset + column.len() - column.trim_start().len());
+        }
+        offset += column.len();
+    }
+    None
+}
+
+// Returns the depth of the branch in the tree, where the root is zero,
+// along with the rest of the name column.
+fn parse_divan_branch(name_column: &str) -> Option<(usize, &str)> {
+    let (index, branch) = [DIVAN_BRANCH, DIVAN_LAST_BRANCH]
+        .into_iter()
+        .find_map(|branch| Some((name_column.find(branch)?, branch)))?;
+    let indent = name_column.get(..index)?.chars().count();
+    let rest = name_column.get(index + branch.len()..)?;
+    Some((indent.checked_div(DIVAN_INDENT_WIDTH)? + 1, rest))
+}
+
+// The name column for a benchmark ends with its fastest time,
+// while the name column for a group is only its name.
+fn parse_divan_name(name_column: &str) -> Option<(String, Option<&str>)> {
+    let name_column = name_column.trim();
+    if let Some(name) = name_column.rsplitn(3, char::is_whitespace).nth(2) {
+        let fastest = name_column.get(name.len()..)?.trim();
+        if parse_divan_duration(fastest).is_some() {
+            return Some((name.trim().to_owned(), Some(fastest)));
+        }
+    }
+    (!name_column.is_empty()).then(|| (name_column.to_owned(), None))
+}
+
+fn parse_divan_latency(
+    fastest: &str,
+    columns: &[&str],
+    average: JsonAverage,
+) -> Option<JsonMetric> {
+    let lower_value = parse_divan_duration(fastest)?;
+    let upper_value = parse_divan_duration(columns.first()?)?;
+    let value = parse_divan_duration(divan_average(columns, average)?)?;
+    Some(JsonMetric {
+        value,
+        lower_value: Some(lower_value),
+        upper_value: Some(upper_value),
+    })
+}
+
+// The columns after the name column are: slowest, median, mean, samples, and iters
+fn divan_average<'c>(columns: &[&'c str], average: JsonAverage) -> Option<&'c str> {
+    match average {
+        JsonAverage::Mean => columns.get(2),
+        JsonAverage::Median => columns.get(1),
+    }
+    .copied()
+}
+
+fn divan_counter(value: OrderedFloat<f64>) -> JsonMetric {
+    JsonMetric {
+        value,
+        lower_value: None,
+        upper_value: None,
+    }
+}
+
+fn parse_divan_duration(input: &str) -> Option<OrderedFloat<f64>> {
+    let (_, duration) = map(
+        tuple((parse_f64, space1, parse_units, eof)),
+        |(duration, _, units, _)| latency_as_nanos(duration, units),
+    )(input)
+    .ok()?;
+    Some(duration)
+}
+
+fn parse_divan_count(input: &str) -> Option<OrderedFloat<f64>> {
+    let (_, (count, _)) = tuple((parse_f64, eof))(input).ok()?;
+    Some(count.into())
+}
+
+fn parse_divan_bytes(input: &str) -> Option<OrderedFloat<f64>> {
+    let (_, (bytes, _, scale, _)) =
+        tuple((parse_f64, space0, parse_divan_bytes_units, eof))(input).ok()?;
+    Some((bytes * scale).into())
+}
+
+fn parse_divan_bytes_units(input: &str) -> IResult<&str, f64> {
+    alt((
+        map(tag("KiB"), |_| 1024.0),
+        map(tag("MiB"), |_| 1024.0 * 1024.0),
+        map(tag("GiB"), |_| 1024.0 * 1024.0 * 1024.0),
+        map(tag("KB"), |_| 1_000.0),
+        map(tag("MB"), |_| 1_000_000.0),
+        map(tag("GB"), |_| 1_000_000_000.0),
+        map(tag("B"), |_| 1.0),
+    ))(input)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_rust_divan {
+    use bencher_json::project::measure::{
+        ALLOCATED_BYTES_SLUG_STR, ALLOCATIONS_SLUG_STR, LATENCY_SLUG_STR,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{
+            convert_file_path, convert_file_path_median, validate_latency, validate_multi_metric,
+        },
+        AdapterResults,
+    };
+
+    use super::{parse_divan_bytes, parse_divan_name, AdapterRustDivan};
+
+    fn convert_rust_divan(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/rust/divan/{suffix}.txt");
+        convert_file_path::<AdapterRustDivan>(&file_path)
+    }
+
+    #[test]
+    fn test_parse_divan_name() {
+        assert_eq!(
+            parse_divan_name("fibonacci                   "),
+            Some(("fibonacci".into(), None))
+        );
+        assert_eq!(
+            parse_divan_name("10         93.79 ns      "),
+            Some(("10".into(), Some("93.79 ns")))
+        );
+        assert_eq!(
+            parse_divan_name("vec_alloc     47.45 ns"),
+            Some(("vec_alloc".into(), Some("47.45 ns")))
+        );
+        assert_eq!(parse_divan_name("   "), None);
+    }
+
+    #[test]
+    fn test_parse_divan_bytes() {
+        assert_eq!(parse_divan_bytes("64 B"), Some(64.0.into()));
+        assert_eq!(parse_divan_bytes("4.096 KB"), Some(4_096.0.into()));
+        assert_eq!(parse_divan_bytes("2 KiB"), Some(2_048.0.into()));
+        assert_eq!(parse_divan_bytes("64"), None);
+    }
+
+    #[test]
+    fn test_adapter_rust_divan() {
+        let results = convert_rust_divan("many");
+        validate_adapter_rust_divan(&results);
+    }
+
+    #[test]
+    fn test_adapter_rust_divan_median() {
+        let results =
+            convert_file_path_median::<AdapterRustDivan>("./tool_output/rust/divan/many.txt");
+        assert_eq!(results.inner.len(), 5);
+
+        let metrics = results.get("example::fibonacci::10").unwrap();
+        validate_latency(metrics, 95.47, Some(93.79), Some(104.8));
+
+        let metrics = results.get("example::vec_alloc").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_multi_metric(metrics, LATENCY_SLUG_STR, 50.05, Some(47.45), Some(93.16));
+    }
+
+    pub fn validate_adapter_rust_divan(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 5);
+
+        let metrics = results.get("example::fibonacci::0").unwrap();
+        validate_latency(metrics, 0.303, Some(0.291), Some(0.338));
+
+        let metrics = results.get("example::fibonacci::10").unwrap();
+        validate_latency(metrics, 95.93, Some(93.79), Some(104.8));
+
+        let metrics = results.get("example::fibonacci::20").unwrap();
+        validate_latency(metrics, 11_900.0, Some(11_540.0), Some(13_790.0));
+
+        let metrics = results.get("example::str_len").unwrap();
+        validate_latency(metrics, 2.014, Some(1.958), Some(2.354));
+
+        let metrics = results.get("example::vec_alloc").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_multi_metric(metrics, LATENCY_SLUG_STR, 52.6, Some(47.45), Some(93.16));
+        validate_multi_metric(metrics, ALLOCATIONS_SLUG_STR, 1.0, None, None);
+        validate_multi_metric(metrics, ALLOCATED_BYTES_SLUG_STR, 4_096.0, None, None);
+
+        assert_eq!(
+            None,
+            results.get("example::fibonacci"),
+            "Groups should not have results"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use bencher_json::{
+    project::report::Adapter,
     project::{
         measure::{
             ESTIMATED_CYCLES_NAME_STR, INSTRUCTIONS_NAME_STR, L1_ACCESSES_NAME_STR,
@@ -19,13 +20,21 @@ use nom::{
 
 use crate::{
     adapters::util::{parse_f64, parse_u64},
+    diagnostic::line_error,
     results::adapter_results::{AdapterResults, IaiMeasure},
-    Adaptable, Settings,
+    Adaptable, AdapterDiagnostic, Settings,
 };
 
 pub struct AdapterRustIai;
 
 const IAI_METRICS_LINE_COUNT: usize = 6;
+const IAI_MEASURES: [&str; 5] = [
+    INSTRUCTIONS_NAME_STR,
+    L1_ACCESSES_NAME_STR,
+    L2_ACCESSES_NAME_STR,
+    RAM_ACCESSES_NAME_STR,
+    ESTIMATED_CYCLES_NAME_STR,
+];
 
 impl Adaptable for AdapterRustIai {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
@@ -51,6 +60,15 @@ impl Adaptable for AdapterRustIai {
 
         AdapterResults::new_iai(benchmark_metrics)
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::RustIai, input, settings, || {
+            input.lines().enumerate().find_map(|(index, line)| {
+                let measure = iai_measure(line)?;
+                line_error(input, index, line, |line| parse_iai_metric(line, measure))
+            })
+        })
+    }
 }
 
 fn parse_iai_lines(
@@ -137,6 +155,15 @@ fn parse_iai_metric<'a>(input: &'a str, measure: &'static str) -> IResult<&'a st
     )(input)
 }
 
+// Each metric line starts with the name of its measure.
+fn iai_measure(line: &str) -> Option<&'static str> {
+    IAI_MEASURES.into_iter().find(|measure| {
+        line.trim_start()
+            .strip_prefix(measure)
+            .is_some_and(|rest| rest.starts_with(':'))
+    })
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 pub(crate) mod test_rust_iai {
@@ -1,4 +1,5 @@
 use bencher_json::{
+    project::report::Adapter,
     project::{
         measure::{ESTIMATED_CYCLES_NAME_STR, INSTRUCTIONS_NAME_STR},
         report::JsonAverage,
@@ -16,8 +17,9 @@ use nom::{
 
 use crate::{
     adapters::util::{parse_f64, parse_u64},
+    diagnostic::line_error,
     results::adapter_results::{AdapterResults, IaiCallgrindMeasure},
-    Adaptable, Settings,
+    Adaptable, AdapterDiagnostic, Settings,
 };
 
 pub struct AdapterRustIaiCallgrind;
@@ -27,6 +29,14 @@ const L1_HITS_NAME_STR: &str = "L1 Hits";
 const L2_HITS_NAME_STR: &str = "L2 Hits";
 const RAM_HITS_NAME_STR: &str = "RAM Hits";
 const TOTAL_READ_WRITE_NAME_STR: &str = "Total read+write";
+const IAI_CALLGRIND_MEASURES: [&str; 6] = [
+    INSTRUCTIONS_NAME_STR,
+    L1_HITS_NAME_STR,
+    L2_HITS_NAME_STR,
+    RAM_HITS_NAME_STR,
+    TOTAL_READ_WRITE_NAME_STR,
+    ESTIMATED_CYCLES_NAME_STR,
+];
 
 impl Adaptable for AdapterRustIaiCallgrind {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
@@ -52,6 +62,17 @@ impl Adaptable for AdapterRustIaiCallgrind {
 
         AdapterResults::new_iai_callgrind(benchmark_metrics)
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::RustIaiCallgrind, input, settings, || {
+            input.lines().enumerate().find_map(|(index, line)| {
+                let measure = iai_callgrind_measure(line)?;
+                line_error(input, index, line, |line| {
+                    parse_iai_callgrind_metric(line, measure)
+                })
+            })
+        })
+    }
 }
 
 fn parse_iai_lines(
@@ -159,6 +180,15 @@ fn parse_iai_callgrind_metric<'a>(
     )(input)
 }
 
+// Each metric line starts with the name of its measure.
+fn iai_callgrind_measure(line: &str) -> Option<&'static str> {
+    IAI_CALLGRIND_MEASURES.into_iter().find(|measure| {
+        line.trim_start()
+            .strip_prefix(measure)
+            .is_some_and(|rest| rest.starts_with(':'))
+    })
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 pub(crate) mod test_rust_iai_callgrind {
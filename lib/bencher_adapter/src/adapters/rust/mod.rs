@@ -1,22 +1,35 @@
 pub mod bench;
 pub mod criterion;
+pub mod divan;
 pub mod iai;
 pub mod iai_callgrind;
 
-use self::{
-    criterion::AdapterRustCriterion, iai::AdapterRustIai, iai_callgrind::AdapterRustIaiCallgrind,
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
 };
-use crate::{Adaptable, AdapterResults, Settings};
-use bench::AdapterRustBench;
 
 pub struct AdapterRust;
 
+/// The adapters that the Rust adapter tries, in order.
+pub const RUST_ADAPTERS: &[Adapter] = &[
+    Adapter::RustBench,
+    Adapter::RustCriterion,
+    Adapter::RustIai,
+    Adapter::RustIaiCallgrind,
+    Adapter::RustDivan,
+];
+
 impl Adaptable for AdapterRust {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterRustBench::parse(input, settings)
-            .or_else(|| AdapterRustCriterion::parse(input, settings))
-            .or_else(|| AdapterRustIai::parse(input, settings))
-            .or_else(|| AdapterRustIaiCallgrind::parse(input, settings))
+        RUST_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(RUST_ADAPTERS, input, settings)
     }
 }
 
@@ -25,8 +38,8 @@ mod test_rust {
     use super::AdapterRust;
     use crate::adapters::{
         rust::{
-            bench::test_rust_bench, criterion::test_rust_criterion, iai::test_rust_iai,
-            iai_callgrind::test_rust_iai_callgrind,
+            bench::test_rust_bench, criterion::test_rust_criterion, divan::test_rust_divan,
+            iai::test_rust_iai, iai_callgrind::test_rust_iai_callgrind,
         },
         test_util::convert_file_path,
     };
@@ -54,4 +67,10 @@ mod test_rust {
         let results = convert_file_path::<AdapterRust>("./tool_output/rust/iai_callgrind/two.txt");
         test_rust_iai_callgrind::validate_adapter_rust_iai_callgrind(&results);
     }
+
+    #[test]
+    fn test_adapter_rust_divan() {
+        let results = convert_file_path::<AdapterRust>("./tool_output/rust/divan/many.txt");
+        test_rust_divan::validate_adapter_rust_divan(&results);
+    }
 }
@@ -1,11 +1,15 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonAny, JsonMetric,
+};
 use ordered_float::OrderedFloat;
 use serde::Deserialize;
 
 use crate::{
     adapters::util::{latency_as_nanos, Units},
-    results::adapter_results::AdapterResults,
-    Adaptable, AdapterError, Settings,
+    diagnostic::json_error,
+    results::adapter_results::{AdapterMeasure, AdapterResults},
+    Adaptable, AdapterDiagnostic, AdapterError, Settings,
 };
 
 pub struct AdapterShellHyperfine;
@@ -17,6 +21,14 @@ impl Adaptable for AdapterShellHyperfine {
             .convert(settings)
             .ok()?
     }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::ShellHyperfine, input, settings, || {
+            Some(json_error(input, |hyperfine: Hyperfine| {
+                hyperfine.convert(settings)
+            }))
+        })
+    }
 }
 
 // https://github.com/sharkdp/hyperfine/blob/ef4049f8f897d4adc4c47a07e60e39d9760fb9ed/src/benchmark/benchmark_result.rs#L11
@@ -53,6 +65,8 @@ impl Hyperfine {
                 mean,
                 stddev,
                 median,
+                user,
+                system,
                 min,
                 max,
                 ..
@@ -71,29 +85,52 @@ impl Hyperfine {
                     Some(latency_as_nanos(upper, units)),
                 )
             });
-            let json_metric = JsonMetric {
+            let latency = JsonMetric {
                 value,
                 lower_value,
                 upper_value,
             };
+            // Hyperfine only reports the mean user and system time
+            let user_time = JsonMetric {
+                value: latency_as_nanos(user, units),
+                lower_value: None,
+                upper_value: None,
+            };
+            let system_time = JsonMetric {
+                value: latency_as_nanos(system, units),
+                lower_value: None,
+                upper_value: None,
+            };
 
-            benchmark_metrics.push((command, json_metric));
+            benchmark_metrics.push((
+                command,
+                vec![
+                    AdapterMeasure::Latency(latency),
+                    AdapterMeasure::UserTime(user_time),
+                    AdapterMeasure::SystemTime(system_time),
+                ],
+            ));
         }
 
-        Ok(AdapterResults::new_latency(benchmark_metrics))
+        Ok(AdapterResults::new(benchmark_metrics))
     }
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 pub(crate) mod test_shell_hyperfine {
-    use bencher_json::project::report::JsonAverage;
+    use bencher_json::project::{
+        measure::{LATENCY_SLUG_STR, SYSTEM_TIME_SLUG_STR, USER_TIME_SLUG_STR},
+        report::JsonAverage,
+    };
     use pretty_assertions::assert_eq;
 
     use crate::{
         adapters::test_util::{
-            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+            convert_file_path, convert_file_path_median, opt_convert_file_path,
+            validate_multi_metric,
         },
+        results::adapter_metrics::AdapterMetrics,
         AdapterResults, Settings,
     };
 
@@ -113,6 +150,25 @@ pub(crate) mod test_shell_hyperfine {
         format!("./tool_output/shell/hyperfine/{suffix}.json")
     }
 
+    fn validate_hyperfine(
+        metrics: &AdapterMetrics,
+        latency: (f64, f64, f64),
+        user_time: f64,
+        system_time: f64,
+    ) {
+        assert_eq!(metrics.inner.len(), 3);
+        let (value, lower_value, upper_value) = latency;
+        validate_multi_metric(
+            metrics,
+            LATENCY_SLUG_STR,
+            value,
+            Some(lower_value),
+            Some(upper_value),
+        );
+        validate_multi_metric(metrics, USER_TIME_SLUG_STR, user_time, None, None);
+        validate_multi_metric(metrics, SYSTEM_TIME_SLUG_STR, system_time, None, None);
+    }
+
     #[test]
     fn test_adapter_shell_hyperfine_two() {
         let two = "two";
@@ -138,19 +194,27 @@ pub(crate) mod test_shell_hyperfine {
         assert_eq!(results.inner.len(), 2);
 
         let metrics = results.get("sleep 0.1").unwrap();
-        validate_latency(
+        validate_hyperfine(
             metrics,
-            107_534_464.423_703_72,
-            Some(104_316_587.308_651_45),
-            Some(110_752_341.538_755_98),
+            (
+                107_534_464.423_703_72,
+                104_316_587.308_651_45,
+                110_752_341.538_755_98,
+            ),
+            717_496.296_296_296_4,
+            1_350_884.444_444_444_5,
         );
 
         let metrics = results.get("sleep 0.2").unwrap();
-        validate_latency(
+        validate_hyperfine(
             metrics,
-            208_513_999.104_615_43,
-            Some(204_785_557.656_151_62),
-            Some(212_242_440.553_079_25),
+            (
+                208_513_999.104_615_43,
+                204_785_557.656_151_62,
+                212_242_440.553_079_25,
+            ),
+            1_013_969.230_769_231,
+            2_086_132.307_692_307_5,
         );
     }
 
@@ -158,19 +222,19 @@ pub(crate) mod test_shell_hyperfine {
         assert_eq!(results.inner.len(), 2);
 
         let metrics = results.get("sleep 0.1").unwrap();
-        validate_latency(
+        validate_hyperfine(
             metrics,
-            106_525_351.72,
-            Some(102_474_685.72),
-            Some(115_336_892.72),
+            (106_525_351.72, 102_474_685.72, 115_336_892.72),
+            717_496.296_296_296_4,
+            1_350_884.444_444_444_5,
         );
 
         let metrics = results.get("sleep 0.2").unwrap();
-        validate_latency(
+        validate_hyperfine(
             metrics,
-            208_661_518.72,
-            Some(201_824_142.72),
-            Some(214_128_684.72),
+            (208_661_518.72, 201_824_142.72, 214_128_684.72),
+            1_013_969.230_769_231,
+            2_086_132.307_692_307_5,
         );
     }
 
@@ -180,11 +244,15 @@ pub(crate) mod test_shell_hyperfine {
         assert_eq!(results.inner.len(), 1);
 
         let metrics = results.get("sleep 0.01").unwrap();
-        validate_latency(
+        validate_hyperfine(
             metrics,
-            13_317_239.025_420_565,
-            Some(12_317_546.734_914_13),
-            Some(14_316_931.315_926_999),
+            (
+                13_317_239.025_420_565,
+                12_317_546.734_914_13,
+                14_316_931.315_926_999,
+            ),
+            343_610.467_289_719_73,
+            601_688.598_130_841_9,
         );
     }
 
@@ -194,11 +262,15 @@ pub(crate) mod test_shell_hyperfine {
         assert_eq!(results.inner.len(), 1);
 
         let metrics = results.get("sleep 0.01").unwrap();
-        validate_latency(
+        validate_hyperfine(
             metrics,
-            13_251_329.96,
-            Some(10_165_892.459_999_999),
-            Some(21_347_058.459_999_997),
+            (
+                13_251_329.96,
+                10_165_892.459_999_999,
+                21_347_058.459_999_997,
+            ),
+            343_610.467_289_719_73,
+            601_688.598_130_841_9,
         );
     }
 }
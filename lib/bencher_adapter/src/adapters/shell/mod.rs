@@ -1,24 +1,46 @@
 pub mod hyperfine;
+pub mod perf_stat;
 
-use crate::{Adaptable, AdapterResults, Settings};
-use hyperfine::AdapterShellHyperfine;
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
+};
 
 pub struct AdapterShell;
 
+/// The adapters that the Shell adapter tries, in order.
+pub const SHELL_ADAPTERS: &[Adapter] = &[Adapter::ShellHyperfine, Adapter::ShellPerfStat];
+
 impl Adaptable for AdapterShell {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterShellHyperfine::parse(input, settings)
+        SHELL_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(SHELL_ADAPTERS, input, settings)
     }
 }
 
 #[cfg(test)]
 mod test_shell {
     use super::AdapterShell;
-    use crate::adapters::{shell::hyperfine::test_shell_hyperfine, test_util::convert_file_path};
+    use crate::adapters::{
+        shell::{hyperfine::test_shell_hyperfine, perf_stat::test_shell_perf_stat},
+        test_util::convert_file_path,
+    };
 
     #[test]
     fn test_adapter_shell_hyperfine() {
         let results = convert_file_path::<AdapterShell>("./tool_output/shell/hyperfine/two.json");
         test_shell_hyperfine::validate_adapter_shell_hyperfine(&results);
     }
+
+    #[test]
+    fn test_adapter_shell_perf_stat() {
+        let results = convert_file_path::<AdapterShell>("./tool_output/shell/perf_stat/two.csv");
+        test_shell_perf_stat::validate_adapter_shell_perf_stat(&results);
+    }
 }
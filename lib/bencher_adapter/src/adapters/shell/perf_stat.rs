@@ -0,0 +1,394 @@
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric, MeasureNameId, Slug,
+};
+use serde::Deserialize;
+
+use crate::{
+    adapters::util::{parse_benchmark_name, Units},
+    diagnostic::{parse_error, DiagnosticError, DiagnosticLocation},
+    results::adapter_results::{AdapterMeasure, AdapterResults},
+    Adaptable, AdapterDiagnostic, Settings,
+};
+
+pub struct AdapterShellPerfStat;
+
+const PERF_STAT_DEFAULT_NAME: &str = "perf stat";
+const PERF_STAT_COMMENT: char = '#';
+const PERF_STAT_CSV_SEPARATOR: char = ',';
+// The value, unit, and event
+const PERF_STAT_CSV_FIELDS: usize = 3;
+const PERF_STAT_MODIFIER_SEPARATOR: char = ':';
+const PERF_STAT_VARIANCE_SUFFIX: char = '%';
+
+impl Adaptable for AdapterShellPerfStat {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        // `perf stat` only reports the mean, even when repeated with `-r`.
+        match settings.average {
+            Some(JsonAverage::Mean) | None => {},
+            Some(JsonAverage::Median) => return None,
+        }
+
+        let mut benchmark_metrics = Vec::new();
+        // The most recent line that is not a counter labels the next group of counters.
+        let mut label = None;
+        let mut current: Option<(BenchmarkName, Vec<AdapterMeasure>)> = None;
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(PERF_STAT_COMMENT) {
+                continue;
+            }
+
+            let Some(counter) = PerfStatCounter::parse(line) else {
+                if let Some(benchmark) = current.take() {
+                    benchmark_metrics.push(benchmark);
+                }
+                label = Some(line);
+                continue;
+            };
+            let (_, metrics) = current.get_or_insert_with(|| {
+                let benchmark_name = label
+                    .take()
+                    .and_then(|label| parse_benchmark_name(label).ok())
+                    .unwrap_or_else(default_benchmark_name);
+                (benchmark_name, Vec::new())
+            });
+            if let Some(metric) = counter.into_metric() {
+                metrics.push(metric);
+            }
+        }
+        if let Some(benchmark) = current {
+            benchmark_metrics.push(benchmark);
+        }
+        benchmark_metrics.retain(|(_, metrics)| !metrics.is_empty());
+
+        AdapterResults::new(benchmark_metrics)
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::ShellPerfStat, input, settings, || {
+            input.lines().enumerate().find_map(|(index, line)| {
+                let counter = line.trim();
+                let offset = line.len() - line.trim_start().len();
+                if counter.starts_with('{') {
+                    let e = serde_json::from_str::<PerfStatJson>(counter).err()?;
+                    let location = DiagnosticLocation::new(input, index + 1, offset + e.column());
+                    Some((DiagnosticError::Json(e.to_string()), location))
+                } else if counter.split(PERF_STAT_CSV_SEPARATOR).count() >= PERF_STAT_CSV_FIELDS {
+                    PerfStatCounter::parse_csv(counter)
+                        .is_none()
+                        .then(|| parse_error(input, index, line, offset))
+                } else {
+                    None
+                }
+            })
+        })
+    }
+}
+
+#[allow(clippy::expect_used)]
+fn default_benchmark_name() -> BenchmarkName {
+    PERF_STAT_DEFAULT_NAME
+        .parse()
+        .expect("Failed to parse default benchmark name.")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PerfStatCounter {
+    event: String,
+    /// Counters without units (ie `cycles`) have an empty unit.
+    unit: String,
+    /// Counters that were not counted or not supported do not have a value.
+    value: Option<f64>,
+    /// The standard deviation as a percentage of the mean, when repeated with `-r`.
+    variance: Option<f64>,
+}
+
+// A counter from `perf stat -j`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PerfStatJson {
+    counter_value: PerfStatJsonValue,
+    #[serde(default)]
+    unit: String,
+    event: String,
+    variance: Option<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum PerfStatJsonValue {
+    Number(f64),
+    String(String),
+}
+
+impl PerfStatCounter {
+    fn parse(line: &str) -> Option<Self> {
+        Self::parse_json(line).or_else(|| Self::parse_csv(line))
+    }
+
+    fn parse_json(line: &str) -> Option<Self> {
+        let PerfStatJson {
+            counter_value,
+            unit,
+            event,
+            variance,
+        } = serde_json::from_str(line).ok()?;
+        let value = match counter_value {
+            PerfStatJsonValue::Number(value) => Some(value),
+            PerfStatJsonValue::String(value) => parse_perf_stat_value(&value)?,
+        };
+        Some(Self {
+            event,
+            unit,
+            value,
+            variance,
+        })
+    }
+
+    // A counter from `perf stat -x,`
+    // The fields are: value, unit, event, variance (only with `-r`), run time, percent running, ...
+    fn parse_csv(line: &str) -> Option<Self> {
+        let mut fields = line.split(PERF_STAT_CSV_SEPARATOR);
+        let value = parse_perf_stat_value(fields.next()?)?;
+        let unit = fields.next()?.trim();
+        let event = fields.next()?.trim();
+        if event.is_empty() {
+            return None;
+        }
+        let variance = fields
+            .next()
+            .and_then(|variance| variance.trim().strip_suffix(PERF_STAT_VARIANCE_SUFFIX))
+            .and_then(|variance| variance.parse().ok());
+        Some(Self {
+            event: event.to_owned(),
+            unit: unit.to_owned(),
+            value,
+            variance,
+        })
+    }
+
+    fn into_metric(self) -> Option<AdapterMeasure> {
+        let Self {
+            event,
+            unit,
+            value,
+            variance,
+        } = self;
+        let (value, measure) = if let Some(units) = perf_stat_time_units(&unit) {
+            (value? * units.as_nanos(), perf_stat_measure(&event, "")?)
+        } else {
+            (value?, perf_stat_measure(&event, &unit)?)
+        };
+        let (lower_value, upper_value) = variance.map_or((None, None), |variance| {
+            let error = value * variance / 100.0;
+            (Some((value - error).into()), Some((value + error).into()))
+        });
+        Some(AdapterMeasure::Custom(
+            measure,
+            JsonMetric {
+                value: value.into(),
+                lower_value,
+                upper_value,
+            },
+        ))
+    }
+}
+
+// Returns `None` if the input is not a counter value at all,
+// and `Some(None)` if the counter was not counted or is not supported (ie `<not counted>`).
+#[allow(clippy::option_option)]
+fn parse_perf_stat_value(input: &str) -> Option<Option<f64>> {
+    let input = input.trim();
+    if input.starts_with('<') && input.ends_with('>') {
+        Some(None)
+    } else {
+        input.parse().ok().map(Some)
+    }
+}
+
+// Time based events (ie `task-clock`) are converted to nanoseconds.
+fn perf_stat_time_units(unit: &str) -> Option<Units> {
+    Some(match unit {
+        "nsec" | "ns" => Units::Nano,
+        "usec" | "us" => Units::Micro,
+        "msec" | "ms" => Units::Milli,
+        "sec" | "s" => Units::Sec,
+        _ => return None,
+    })
+}
+
+// The event modifiers (ie `cycles:u`) are ignored,
+// so the same event is always the same measure.
+// Any other units (ie `Joules`) are added to the measure,
+// so the same event in different units is never a single measure.
+fn perf_stat_measure(event: &str, unit: &str) -> Option<MeasureNameId> {
+    let event = event
+        .split(PERF_STAT_MODIFIER_SEPARATOR)
+        .next()
+        .unwrap_or(event);
+    if Slug::new(event).as_ref().is_empty() {
+        return None;
+    }
+    let slug = if unit.is_empty() {
+        Slug::new(event)
+    } else {
+        Slug::new(format!("{event} {unit}"))
+    };
+    Some(slug.into())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_shell_perf_stat {
+    use bencher_json::project::measure::{
+        BRANCH_MISSES_SLUG_STR, CACHE_MISSES_SLUG_STR, CYCLES_SLUG_STR, INSTRUCTIONS_SLUG_STR,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, validate_multi_metric},
+        Adaptable, AdapterResults, Settings,
+    };
+
+    use super::{perf_stat_measure, AdapterShellPerfStat, PerfStatCounter};
+
+    fn convert_shell_perf_stat(file_name: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/shell/perf_stat/{file_name}");
+        convert_file_path::<AdapterShellPerfStat>(&file_path)
+    }
+
+    #[test]
+    fn test_parse_perf_stat_counter() {
+        assert_eq!(
+            PerfStatCounter::parse("10034567,,cycles:u,2510000,100.00,3.998,GHz"),
+            Some(PerfStatCounter {
+                event: "cycles:u".into(),
+                unit: String::new(),
+                value: Some(10_034_567.0),
+                variance: None,
+            })
+        );
+        assert_eq!(
+            PerfStatCounter::parse("251.20,msec,task-clock,0.50%,251200000,100.00,0.998,CPUs"),
+            Some(PerfStatCounter {
+                event: "task-clock".into(),
+                unit: "msec".into(),
+                value: Some(251.2),
+                variance: Some(0.5),
+            })
+        );
+        assert_eq!(
+            PerfStatCounter::parse("<not counted>,,cpu-migrations:u,0,0.00,,"),
+            Some(PerfStatCounter {
+                event: "cpu-migrations:u".into(),
+                unit: String::new(),
+                value: None,
+                variance: None,
+            })
+        );
+        assert_eq!(
+            PerfStatCounter::parse(
+                r#"{"counter-value" : "12.5", "unit" : "", "event" : "cycles"}"#
+            ),
+            Some(PerfStatCounter {
+                event: "cycles".into(),
+                unit: String::new(),
+                value: Some(12.5),
+                variance: None,
+            })
+        );
+        assert_eq!(PerfStatCounter::parse("fib 20"), None);
+        assert_eq!(PerfStatCounter::parse("fib, 20, again"), None);
+    }
+
+    #[test]
+    fn test_perf_stat_measure() {
+        assert_eq!(
+            perf_stat_measure("cycles:u", "").unwrap().as_ref(),
+            CYCLES_SLUG_STR
+        );
+        assert_eq!(
+            perf_stat_measure("cpu_core/cycles/", "").unwrap().as_ref(),
+            "cpu-core-cycles"
+        );
+        assert_eq!(
+            perf_stat_measure("power/energy-pkg/", "Joules")
+                .unwrap()
+                .as_ref(),
+            "power-energy-pkg-joules"
+        );
+        assert_eq!(perf_stat_measure(":u", ""), None);
+        assert_eq!(perf_stat_measure(":u", "Joules"), None);
+    }
+
+    #[test]
+    fn test_adapter_shell_perf_stat_csv() {
+        let results = convert_shell_perf_stat("two.csv");
+        validate_adapter_shell_perf_stat(&results);
+    }
+
+    #[test]
+    fn test_adapter_shell_perf_stat_json() {
+        let results = convert_shell_perf_stat("two.json");
+        validate_adapter_shell_perf_stat(&results);
+    }
+
+    #[test]
+    fn test_adapter_shell_perf_stat_unlabeled() {
+        let input = "10034567,,cycles:u,2510000,100.00,3.998,GHz";
+        let results = AdapterShellPerfStat::parse(input, Settings::default()).unwrap();
+        let metrics = results.get("perf stat").unwrap();
+        validate_multi_metric(metrics, CYCLES_SLUG_STR, 10_034_567.0, None, None);
+    }
+
+    pub fn validate_adapter_shell_perf_stat(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("fib 20").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+        validate_multi_metric(metrics, "task-clock", 2_510_000.0, None, None);
+        validate_multi_metric(metrics, "context-switches", 0.0, None, None);
+        validate_multi_metric(metrics, CYCLES_SLUG_STR, 10_034_567.0, None, None);
+        validate_multi_metric(metrics, INSTRUCTIONS_SLUG_STR, 25_021_000.0, None, None);
+        validate_multi_metric(metrics, BRANCH_MISSES_SLUG_STR, 12_034.0, None, None);
+
+        let metrics = results.get("fib 30").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+        validate_multi_metric(
+            metrics,
+            "task-clock",
+            251_200_000.0,
+            Some(249_944_000.0),
+            Some(252_456_000.0),
+        );
+        validate_multi_metric(
+            metrics,
+            CYCLES_SLUG_STR,
+            1_004_321_000.0,
+            Some(994_277_790.0),
+            Some(1_014_364_210.0),
+        );
+        validate_multi_metric(
+            metrics,
+            INSTRUCTIONS_SLUG_STR,
+            2_502_100_000.0,
+            Some(2_499_597_900.0),
+            Some(2_504_602_100.0),
+        );
+        validate_multi_metric(
+            metrics,
+            BRANCH_MISSES_SLUG_STR,
+            1_203_400.0,
+            Some(1_179_332.0),
+            Some(1_227_468.0),
+        );
+        validate_multi_metric(
+            metrics,
+            CACHE_MISSES_SLUG_STR,
+            5_600.0,
+            Some(5_040.0),
+            Some(6_160.0),
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric,
+};
+use serde::Deserialize;
+
+use crate::{
+    adapters::util::{latency_as_nanos, Units},
+    diagnostic::json_error,
+    results::adapter_results::AdapterResults,
+    Adaptable, AdapterDiagnostic, AdapterError, Settings,
+};
+
+pub struct AdapterSwiftBenchmark;
+
+impl Adaptable for AdapterSwiftBenchmark {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        // The `time` column is the median by default.
+        match settings.average {
+            Some(JsonAverage::Median) | None => {},
+            Some(JsonAverage::Mean) => return None,
+        }
+
+        serde_json::from_str::<SwiftBenchmark>(input)
+            .ok()?
+            .try_into()
+            .ok()?
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::SwiftBenchmark, input, settings, || {
+            Some(json_error(input, |swift_benchmark: SwiftBenchmark| {
+                swift_benchmark.try_into()
+            }))
+        })
+    }
+}
+
+// Only the Google swift-benchmark JSON output is supported,
+// not the ordo-one package-benchmark output.
+// https://github.com/google/swift-benchmark#command-line-options
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SwiftBenchmark {
+    pub benchmarks: Vec<Benchmark>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Benchmark {
+    pub name: String,
+    #[serde(default)]
+    pub suite_name: Option<String>,
+    /// The `time` column, which is the median in nanoseconds by default.
+    pub time: f64,
+    /// The `std` column, which is the standard deviation as a percentage of `time`.
+    #[serde(default)]
+    pub std: Option<f64>,
+}
+
+impl TryFrom<SwiftBenchmark> for Option<AdapterResults> {
+    type Error = AdapterError;
+
+    fn try_from(swift_benchmark: SwiftBenchmark) -> Result<Self, Self::Error> {
+        let mut benchmark_metrics = Vec::with_capacity(swift_benchmark.benchmarks.len());
+        for benchmark in swift_benchmark.benchmarks {
+            let Benchmark {
+                name,
+                suite_name,
+                time,
+                std,
+            } = benchmark;
+            let benchmark_name: BenchmarkName = match suite_name {
+                Some(suite_name) if !suite_name.is_empty() => format!("{suite_name}.{name}"),
+                Some(_) | None => name,
+            }
+            .parse()?;
+
+            let units = Units::Nano;
+            let spread = std.map(|std| time * std / 100.0);
+            let json_metric = JsonMetric {
+                value: latency_as_nanos(time, units),
+                lower_value: spread.map(|spread| latency_as_nanos(time - spread, units)),
+                upper_value: spread.map(|spread| latency_as_nanos(time + spread, units)),
+            };
+
+            benchmark_metrics.push((benchmark_name, json_metric));
+        }
+
+        Ok(AdapterResults::new_latency(benchmark_metrics))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_swift_benchmark {
+    use bencher_json::project::report::JsonAverage;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{
+            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+        },
+        AdapterResults, Settings,
+    };
+
+    use super::AdapterSwiftBenchmark;
+
+    fn convert_swift_benchmark(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/swift/benchmark/{suffix}.json");
+        convert_file_path::<AdapterSwiftBenchmark>(&file_path)
+    }
+
+    #[test]
+    fn test_adapter_swift_benchmark() {
+        let results = convert_swift_benchmark("three");
+        validate_adapter_swift_benchmark(&results);
+
+        let file_path = "./tool_output/swift/benchmark/three.json";
+        let results = convert_file_path_median::<AdapterSwiftBenchmark>(file_path);
+        validate_adapter_swift_benchmark(&results);
+
+        let results = opt_convert_file_path::<AdapterSwiftBenchmark>(
+            file_path,
+            Settings {
+                average: Some(JsonAverage::Mean),
+            },
+        );
+        assert!(results.is_none());
+    }
+
+    pub fn validate_adapter_swift_benchmark(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("MyBenchmark.add string no capacity").unwrap();
+        validate_latency(metrics, 37_000.0, Some(33_300.0), Some(40_700.0));
+
+        let metrics = results
+            .get("MyBenchmark.add string reserved capacity")
+            .unwrap();
+        validate_latency(metrics, 25_000.0, Some(24_500.0), Some(25_500.0));
+
+        let metrics = results.get("fibonacci").unwrap();
+        validate_latency(metrics, 500.0, None, None);
+    }
+}
@@ -0,0 +1,36 @@
+pub mod benchmark;
+
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
+};
+
+pub struct AdapterSwift;
+
+/// The adapters that the Swift adapter tries, in order.
+pub const SWIFT_ADAPTERS: &[Adapter] = &[Adapter::SwiftBenchmark];
+
+impl Adaptable for AdapterSwift {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        SWIFT_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(SWIFT_ADAPTERS, input, settings)
+    }
+}
+
+#[cfg(test)]
+mod test_swift {
+    use super::AdapterSwift;
+    use crate::adapters::{swift::benchmark::test_swift_benchmark, test_util::convert_file_path};
+
+    #[test]
+    fn test_adapter_swift_benchmark() {
+        let results = convert_file_path::<AdapterSwift>("./tool_output/swift/benchmark/three.json");
+        test_swift_benchmark::validate_adapter_swift_benchmark(&results);
+    }
+}
@@ -240,3 +240,45 @@ pub fn parse_benchmark_name(name: &str) -> Result<BenchmarkName, NomError> {
         Err(nom_error(name))
     }
 }
+
+// The mean and the sample standard deviation, if there is more than one value.
+#[allow(clippy::cast_precision_loss)]
+pub fn mean_std_dev(values: &[f64]) -> Option<(f64, Option<f64>)> {
+    if values.is_empty() {
+        return None;
+    }
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let std_dev = (values.len() > 1).then(|| {
+        let variance = values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / (values.len() - 1) as f64;
+        variance.sqrt()
+    });
+    Some((mean, std_dev))
+}
+
+// The median and the median absolute deviation, just like `pyperf stats`.
+pub fn median_abs_dev(values: &[f64]) -> Option<(f64, Option<f64>)> {
+    let center = median(values.to_vec())?;
+    let abs_dev = values
+        .iter()
+        .map(|value| (value - center).abs())
+        .collect::<Vec<_>>();
+    Some((center, median(abs_dev)))
+}
+
+#[allow(clippy::indexing_slicing, clippy::integer_division)]
+pub fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable_by(f64::total_cmp);
+    let size = values.len();
+    if (size % 2) == 0 {
+        Some((values[size / 2 - 1] + values[size / 2]) / 2.0)
+    } else {
+        Some(values[size / 2])
+    }
+}
@@ -0,0 +1,36 @@
+pub mod zbench;
+
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    diagnostic::diagnose_adapters, Adaptable, AdapterDiagnostic, AdapterResults, Settings,
+};
+
+pub struct AdapterZig;
+
+/// The adapters that the Zig adapter tries, in order.
+pub const ZIG_ADAPTERS: &[Adapter] = &[Adapter::ZigZbench];
+
+impl Adaptable for AdapterZig {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        ZIG_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        diagnose_adapters(ZIG_ADAPTERS, input, settings)
+    }
+}
+
+#[cfg(test)]
+mod test_zig {
+    use super::AdapterZig;
+    use crate::adapters::{test_util::convert_file_path, zig::zbench::test_zig_zbench};
+
+    #[test]
+    fn test_adapter_zig_zbench() {
+        let results = convert_file_path::<AdapterZig>("./tool_output/zig/zbench/three.txt");
+        test_zig_zbench::validate_adapter_zig_zbench(&results);
+    }
+}
@@ -0,0 +1,222 @@
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonMetric,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{anychar, space0, space1},
+    combinator::{eof, map, map_res},
+    multi::many_till,
+    sequence::tuple,
+    IResult,
+};
+use ordered_float::OrderedFloat;
+
+use crate::{
+    adapters::util::{
+        latency_as_nanos, parse_benchmark_name_chars, parse_f64, parse_u64, parse_units, NomError,
+    },
+    diagnostic::{first_line_error, parse_named},
+    results::adapter_results::AdapterResults,
+    Adaptable, AdapterDiagnostic, Settings,
+};
+
+pub struct AdapterZigZbench;
+
+impl Adaptable for AdapterZigZbench {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        match settings.average {
+            Some(JsonAverage::Mean) | None => {},
+            Some(JsonAverage::Median) => return None,
+        }
+
+        let mut benchmark_metrics = Vec::new();
+        for line in input.lines() {
+            if let Ok((remainder, benchmark_metric)) = parse_zbench(line) {
+                if remainder.is_empty() {
+                    benchmark_metrics.push(benchmark_metric);
+                }
+            }
+        }
+
+        AdapterResults::new_latency(benchmark_metrics)
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterDiagnostic::explain(Adapter::ZigZbench, input, settings, || {
+            first_line_error(
+                input,
+                |line| line.contains(" ± ") && line.contains(" ... "),
+                |line| parse_named(line, parse_zbench, parse_zbench_columns),
+            )
+        })
+    }
+}
+
+// A result row looks like:
+// `My Benchmark  1000  1.523ms  1.523us ± 0.275us  (1.375us ... 5.833us)  1.542us  2.791us  3.125us`
+fn parse_zbench(input: &str) -> IResult<&str, (BenchmarkName, JsonMetric)> {
+    map_res(
+        many_till(anychar, parse_zbench_columns),
+        |(name_chars, json_metric)| -> Result<(BenchmarkName, JsonMetric), NomError> {
+            let benchmark_name = parse_benchmark_name_chars(&name_chars)?;
+            Ok((benchmark_name, json_metric))
+        },
+    )(input)
+}
+
+fn parse_zbench_columns(input: &str) -> IResult<&str, JsonMetric> {
+    map(
+        tuple((
+            // runs and total time
+            tuple((space1, parse_u64, space1, parse_zbench_duration)),
+            // time/run (avg ± σ)
+            tuple((
+                space1,
+                parse_zbench_duration,
+                space1,
+                tag("±"),
+                space1,
+                parse_zbench_duration,
+            )),
+            // (min ... max)
+            tuple((
+                space1,
+                tag("("),
+                parse_zbench_duration,
+                space1,
+                tag("..."),
+                space1,
+                parse_zbench_duration,
+                tag(")"),
+            )),
+            // p75, p99, and p995
+            tuple((
+                space1,
+                parse_zbench_duration,
+                space1,
+                parse_zbench_duration,
+                space1,
+                parse_zbench_duration,
+            )),
+            space0,
+            eof,
+        )),
+        |(_, (_, average, _, _, _, std_dev), _, _, _, _)| JsonMetric {
+            value: average,
+            lower_value: Some(average - std_dev),
+            upper_value: Some(average + std_dev),
+        },
+    )(input)
+}
+
+fn parse_zbench_duration(input: &str) -> IResult<&str, OrderedFloat<f64>> {
+    map(tuple((parse_f64, parse_units)), |(duration, units)| {
+        latency_as_nanos(duration, units)
+    })(input)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_zig_zbench {
+    use bencher_json::{project::report::JsonAverage, JsonMetric};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        AdapterResults, Settings,
+    };
+
+    use super::{parse_zbench, AdapterZigZbench};
+
+    fn convert_zig_zbench(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/zig/zbench/{suffix}.txt");
+        convert_file_path::<AdapterZigZbench>(&file_path)
+    }
+
+    #[test]
+    fn test_parse_zbench() {
+        for (index, (expected, input)) in [
+            (
+                Ok((
+                    "",
+                    (
+                        "Bubble Sort Benchmark".parse().unwrap(),
+                        JsonMetric {
+                            value: 1_500.0.into(),
+                            lower_value: Some(1_250.0.into()),
+                            upper_value: Some(1_750.0.into()),
+                        },
+                    ),
+                )),
+                "Bubble Sort Benchmark  1000     1.5ms          1.5us ± 0.25us          (1.375us ... 5.833us)        1.542us    2.791us    3.125us   ",
+            ),
+            (
+                Ok((
+                    "",
+                    (
+                        "fib".parse().unwrap(),
+                        JsonMetric {
+                            value: 250.0.into(),
+                            lower_value: Some(240.0.into()),
+                            upper_value: Some(260.0.into()),
+                        },
+                    ),
+                )),
+                "fib  100000  25ms  250ns ± 10ns  (200ns ... 1.2us)  255ns  300ns  320ns",
+            ),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            assert_eq!(expected, parse_zbench(input), "#{index}: {input}");
+        }
+
+        assert!(parse_zbench(
+            "benchmark              runs     total time     time/run (avg ± σ)     (min ... max)                p75        p99        p995"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_adapter_zig_zbench_average() {
+        let file_path = "./tool_output/zig/zbench/three.txt";
+        let results = opt_convert_file_path::<AdapterZigZbench>(
+            file_path,
+            Settings {
+                average: Some(JsonAverage::Mean),
+            },
+        )
+        .unwrap();
+        validate_adapter_zig_zbench(&results);
+
+        assert_eq!(
+            None,
+            opt_convert_file_path::<AdapterZigZbench>(
+                file_path,
+                Settings {
+                    average: Some(JsonAverage::Median)
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_adapter_zig_zbench() {
+        let results = convert_zig_zbench("three");
+        validate_adapter_zig_zbench(&results);
+    }
+
+    pub fn validate_adapter_zig_zbench(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("Bubble Sort Benchmark").unwrap();
+        validate_latency(metrics, 1_500.0, Some(1_250.0), Some(1_750.0));
+
+        let metrics = results.get("Insertion Sort Benchmark").unwrap();
+        validate_latency(metrics, 800.0, Some(750.0), Some(850.0));
+
+        let metrics = results.get("Hash Map Insert").unwrap();
+        validate_latency(metrics, 2_000_000.0, Some(1_900_000.0), Some(2_100_000.0));
+    }
+}
@@ -0,0 +1,681 @@
+use std::{cmp::Reverse, fmt};
+
+use bencher_json::project::report::{Adapter, JsonAverage};
+use nom::{error::ErrorKind, IResult};
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+use crate::{Adaptable, AdapterError, AdapterResults, Settings};
+
+/// Parse the input with the given adapter.
+/// If the input can not be parsed, then explain why for each adapter that was tried.
+pub fn diagnose(
+    adapter: Adapter,
+    input: &str,
+    settings: Settings,
+) -> Result<AdapterResults, AdapterDiagnostics> {
+    adapter
+        .convert(input, settings)
+        .ok_or_else(|| AdapterDiagnostics {
+            inner: adapter.explain(input, settings),
+        })
+}
+
+/// Explain why each of the adapters that a family adapter tries could not parse the input.
+pub(crate) fn diagnose_adapters(
+    adapters: &[Adapter],
+    input: &str,
+    settings: Settings,
+) -> Vec<AdapterDiagnostic> {
+    adapters
+        .iter()
+        .flat_map(|adapter| adapter.explain(input, settings))
+        .collect()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AdapterDiagnostics {
+    pub inner: Vec<AdapterDiagnostic>,
+}
+
+impl fmt::Display for AdapterDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, diagnostic) in self.inner.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{diagnostic}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AdapterDiagnostics {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdapterDiagnostic {
+    pub adapter: Adapter,
+    pub error: DiagnosticError,
+    pub location: Option<DiagnosticLocation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DiagnosticError {
+    #[error("No input to parse")]
+    Empty,
+    #[error("Invalid JSON: {0}")]
+    Json(String),
+    #[error("Valid JSON, but it could not be converted into benchmark results (check the benchmark names and units)")]
+    Convert,
+    #[error("Invalid benchmark name or value: {0}")]
+    Valid(String),
+    #[error("Unsupported benchmark units: {0}")]
+    BenchmarkUnits(String),
+    #[error("Failed to parse benchmark result: {0}")]
+    Parse(String),
+    #[error("The {} average is not supported", average_str(*.0))]
+    Average(JsonAverage),
+    #[error("No benchmark results found")]
+    NoResults,
+}
+
+/// The one-based line and column along with the zero-based byte offset of an error in the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticLocation {
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+}
+
+/// The error and where it is in the input, if known
+pub(crate) type Explanation = (DiagnosticError, Option<DiagnosticLocation>);
+
+impl AdapterDiagnostic {
+    /// Explain why a single adapter could not parse the input.
+    /// The adapter specific explanation is only used if the input is not empty
+    /// and the average is supported by the adapter.
+    /// If it has nothing to explain, then no benchmark results were found.
+    pub(crate) fn explain<E>(
+        adapter: Adapter,
+        input: &str,
+        settings: Settings,
+        explain: E,
+    ) -> Vec<Self>
+    where
+        E: FnOnce() -> Option<Explanation>,
+    {
+        if adapter.convert(input, settings).is_some() {
+            return Vec::new();
+        }
+
+        let (error, location) = if input.trim().is_empty() {
+            (DiagnosticError::Empty, None)
+        } else if let Some(average) = settings
+            .average
+            .filter(|_| adapter.convert(input, Settings::default()).is_some())
+        {
+            (DiagnosticError::Average(average), None)
+        } else {
+            explain().unwrap_or((DiagnosticError::NoResults, None))
+        };
+
+        vec![Self {
+            adapter,
+            error,
+            location,
+        }]
+    }
+}
+
+impl fmt::Display for AdapterDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.adapter, self.error)?;
+        if let Some(DiagnosticLocation {
+            line,
+            column,
+            offset,
+        }) = self.location
+        {
+            write!(f, " (line {line}, column {column}, offset {offset})")?;
+        }
+        Ok(())
+    }
+}
+
+impl DiagnosticLocation {
+    // `serde_json` reports a line of zero if the error is not in the input itself.
+    pub(crate) fn new(input: &str, line: usize, column: usize) -> Option<Self> {
+        if line == 0 {
+            return None;
+        }
+        let offset = input
+            .split_inclusive('\n')
+            .take(line - 1)
+            .map(str::len)
+            .sum::<usize>()
+            + column.saturating_sub(1);
+        Some(Self {
+            line,
+            column,
+            offset,
+        })
+    }
+}
+
+impl From<AdapterError> for DiagnosticError {
+    fn from(error: AdapterError) -> Self {
+        match error {
+            AdapterError::Valid(e) => Self::Valid(e.to_string()),
+            AdapterError::BenchmarkUnits(units) => Self::BenchmarkUnits(units),
+            AdapterError::CustomRegex(_)
+            | AdapterError::CustomCaptureGroup { .. }
+            | AdapterError::Convert { .. } => Self::Convert,
+        }
+    }
+}
+
+/// The first line that looks like a benchmark result but that the parser rejects,
+/// with the column where the parser stopped.
+pub(crate) fn first_line_error<T, L, P>(
+    input: &str,
+    looks_like_result: L,
+    mut parser: P,
+) -> Option<Explanation>
+where
+    L: Fn(&str) -> bool,
+    P: FnMut(&str) -> IResult<&str, T>,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| looks_like_result(line))
+        .find_map(|(index, line)| line_error(input, index, line, &mut parser))
+}
+
+/// The error for a single line that looks like a benchmark result, at the zero-based line index,
+/// if the parser rejects it.
+pub(crate) fn line_error<T, P>(
+    input: &str,
+    index: usize,
+    line: &str,
+    mut parser: P,
+) -> Option<Explanation>
+where
+    P: FnMut(&str) -> IResult<&str, T>,
+{
+    let remainder = match parser(line) {
+        Ok(("", _)) => return None,
+        Ok((remainder, _)) => remainder,
+        // If `map_res` fails at the very start of the line,
+        // then the whole line was parsed but the benchmark name or value is invalid.
+        Err(nom::Err::Error(e) | nom::Err::Failure(e))
+            if e.code == ErrorKind::MapRes && e.input.len() == line.len() =>
+        {
+            let location = DiagnosticLocation::new(input, index + 1, 1);
+            return Some((DiagnosticError::Valid(line.trim().into()), location));
+        },
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => e.input,
+        Err(nom::Err::Incomplete(_)) => line,
+    };
+    Some(parse_error(
+        input,
+        index,
+        line,
+        line.len() - remainder.len(),
+    ))
+}
+
+/// A line that looks like a benchmark result but can not be parsed,
+/// at the zero-based line index and byte offset within that line.
+pub(crate) fn parse_error(input: &str, index: usize, line: &str, offset: usize) -> Explanation {
+    (
+        DiagnosticError::Parse(line.trim().into()),
+        DiagnosticLocation::new(input, index + 1, offset + 1),
+    )
+}
+
+/// Parse a benchmark result that starts with a free form name.
+/// The name is parsed with `many_till` up to the columns that follow it,
+/// so if the columns can not be parsed then the error is always at the end of the line.
+/// Instead, the error is from wherever the columns parser got the furthest.
+pub(crate) fn parse_named<'i, T, C, P, Q>(
+    line: &'i str,
+    mut parser: P,
+    mut columns: Q,
+) -> IResult<&'i str, T>
+where
+    P: FnMut(&'i str) -> IResult<&'i str, T>,
+    Q: FnMut(&'i str) -> IResult<&'i str, C>,
+{
+    match parser(line) {
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) if e.code != ErrorKind::MapRes => {
+            let furthest = line
+                .char_indices()
+                .filter_map(|(index, _)| {
+                    let start = line.get(index..)?;
+                    match columns(start) {
+                        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+                            Some((start.len() - e.input.len(), e.input))
+                        },
+                        Ok(_) | Err(nom::Err::Incomplete(_)) => None,
+                    }
+                })
+                .min_by_key(|(parsed, _)| Reverse(*parsed))
+                .map_or(e.input, |(_, remainder)| remainder);
+            Err(nom::Err::Error(nom::error::Error::new(furthest, e.code)))
+        },
+        result => result,
+    }
+}
+
+/// Explain why the input could not be deserialized as JSON,
+/// or else why the deserialized JSON could not be converted.
+pub(crate) fn json_error<T, C>(input: &str, convert: C) -> Explanation
+where
+    T: DeserializeOwned,
+    C: FnOnce(T) -> Result<Option<AdapterResults>, AdapterError>,
+{
+    match serde_json::from_str::<T>(input) {
+        Ok(json) => (
+            convert(json)
+                .err()
+                .map_or(DiagnosticError::Convert, Into::into),
+            None,
+        ),
+        Err(e) => (
+            DiagnosticError::Json(e.to_string()),
+            DiagnosticLocation::new(input, e.line(), e.column()),
+        ),
+    }
+}
+
+pub(crate) fn looks_like_json(input: &str) -> bool {
+    input.trim_start().starts_with(['{', '['])
+}
+
+fn average_str(average: JsonAverage) -> &'static str {
+    match average {
+        JsonAverage::Mean => "mean",
+        JsonAverage::Median => "median",
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_diagnostic {
+    use bencher_json::project::report::{Adapter, JsonAverage};
+    use pretty_assertions::assert_eq;
+
+    use super::{diagnose, DiagnosticError, DiagnosticLocation};
+    use crate::{Adaptable, Settings};
+
+    #[test]
+    fn test_diagnose_magic() {
+        let input = std::fs::read_to_string("./tool_output/rust/bench/many.txt").unwrap();
+        let results = diagnose(Adapter::Magic, &input, Settings::default()).unwrap();
+        assert_eq!(
+            results,
+            Adapter::Magic.convert(&input, Settings::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_diagnose_magic_no_results() {
+        // Each adapter that the magic adapter tries, in order, and whether it parses JSON
+        let expected = [
+            (Adapter::Json, true),
+            (Adapter::CSharpDotNet, true),
+            (Adapter::CppCatch2, false),
+            (Adapter::CppGoogle, true),
+            (Adapter::ElixirBenchee, false),
+            (Adapter::GoBench, false),
+            (Adapter::HttpK6, true),
+            (Adapter::HttpOha, true),
+            (Adapter::HttpWrk, false),
+            (Adapter::JavaJmh, true),
+            (Adapter::JsBenchmark, false),
+            (Adapter::JsTime, false),
+            (Adapter::JsVitest, false),
+            (Adapter::PythonAsv, false),
+            (Adapter::PythonPytest, true),
+            (Adapter::PythonPyperf, true),
+            (Adapter::RubyBenchmark, false),
+            (Adapter::RustBench, false),
+            (Adapter::RustCriterion, false),
+            (Adapter::RustIai, false),
+            (Adapter::RustIaiCallgrind, false),
+            (Adapter::RustDivan, false),
+            (Adapter::ShellHyperfine, true),
+            (Adapter::ShellPerfStat, false),
+            (Adapter::SwiftBenchmark, true),
+            (Adapter::ZigZbench, false),
+        ];
+        let input = "no benchmarks here";
+        let diagnostics = diagnose(Adapter::Magic, input, Settings::default()).unwrap_err();
+        assert_eq!(diagnostics.inner.len(), expected.len());
+        for (diagnostic, (adapter, json)) in diagnostics.inner.into_iter().zip(expected) {
+            assert_eq!(diagnostic.adapter, adapter);
+            if json {
+                assert!(matches!(diagnostic.error, DiagnosticError::Json(_)));
+                assert_eq!(
+                    diagnostic.location,
+                    Some(DiagnosticLocation {
+                        line: 1,
+                        column: 2,
+                        offset: 1,
+                    })
+                );
+            } else {
+                assert_eq!(diagnostic.error, DiagnosticError::NoResults);
+                assert_eq!(diagnostic.location, None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_diagnose_empty() {
+        let diagnostics = diagnose(Adapter::RustBench, " \n", Settings::default()).unwrap_err();
+        assert_eq!(diagnostics.inner.len(), 1);
+        let diagnostic = diagnostics.inner.first().unwrap();
+        assert_eq!(diagnostic.error, DiagnosticError::Empty);
+    }
+
+    #[test]
+    fn test_diagnose_json() {
+        let input = "{\n  \"results\": [\n    {\"command\": \"sleep 0.1\", \"mean\": }\n  ]\n}";
+        let diagnostics =
+            diagnose(Adapter::ShellHyperfine, input, Settings::default()).unwrap_err();
+        assert_eq!(diagnostics.inner.len(), 1);
+        let diagnostic = diagnostics.inner.first().unwrap();
+        assert!(matches!(diagnostic.error, DiagnosticError::Json(_)));
+        assert_eq!(
+            diagnostic.location,
+            Some(DiagnosticLocation {
+                line: 3,
+                column: 38,
+                offset: 54,
+            })
+        );
+        assert_eq!(
+            diagnostic.to_string(),
+            "shell_hyperfine: Invalid JSON: expected value at line 3 column 38 (line 3, column 38, offset 54)"
+        );
+    }
+
+    #[test]
+    fn test_diagnose_average() {
+        let input = std::fs::read_to_string("./tool_output/cpp/google/two.txt").unwrap();
+        let settings = Settings::new(Some(JsonAverage::Median));
+        let diagnostics = diagnose(Adapter::CppGoogle, &input, settings).unwrap_err();
+        assert_eq!(diagnostics.inner.len(), 1);
+        let diagnostic = diagnostics.inner.first().unwrap();
+        assert_eq!(
+            diagnostic.error,
+            DiagnosticError::Average(JsonAverage::Median)
+        );
+        assert_eq!(
+            diagnostics.to_string(),
+            "cpp_google: The median average is not supported"
+        );
+    }
+
+    #[test]
+    fn test_diagnose_line() {
+        let input = "running 1 test\ntest tests::bench_add ... bench:       3,140 ns/it (+/- 12)\n\ntest result: ok. 0 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out\n";
+        let diagnostics = diagnose(Adapter::RustBench, input, Settings::default()).unwrap_err();
+        assert_eq!(diagnostics.inner.len(), 1);
+        let diagnostic = diagnostics.inner.first().unwrap();
+        assert_eq!(
+            diagnostic.error,
+            DiagnosticError::Parse(
+                "test tests::bench_add ... bench:       3,140 ns/it (+/- 12)".into()
+            )
+        );
+        assert_eq!(
+            diagnostic.location,
+            Some(DiagnosticLocation {
+                line: 2,
+                column: 48,
+                offset: 62,
+            })
+        );
+    }
+
+    #[test]
+    fn test_diagnose_line_name() {
+        let name = "a".repeat(1025);
+        let line = format!("test {name} ... bench:       3,140 ns/iter (+/- 12)");
+        let input = format!("running 1 test\n{line}\n");
+        let diagnostics = diagnose(Adapter::RustBench, &input, Settings::default()).unwrap_err();
+        assert_eq!(diagnostics.inner.len(), 1);
+        let diagnostic = diagnostics.inner.first().unwrap();
+        assert_eq!(diagnostic.error, DiagnosticError::Valid(line));
+        assert_eq!(
+            diagnostic.location,
+            Some(DiagnosticLocation {
+                line: 2,
+                column: 1,
+                offset: 15,
+            })
+        );
+    }
+
+    #[test]
+    fn test_diagnose_valid() {
+        let input = r#"{"benchmarks": [{"name": "", "time": 1.0}]}"#;
+        let diagnostics =
+            diagnose(Adapter::SwiftBenchmark, input, Settings::default()).unwrap_err();
+        assert_eq!(diagnostics.inner.len(), 1);
+        let diagnostic = diagnostics.inner.first().unwrap();
+        assert_eq!(
+            diagnostic.error,
+            DiagnosticError::Valid("Failed to validate benchmark name: ".into())
+        );
+        assert_eq!(diagnostic.location, None);
+    }
+
+    #[test]
+    fn test_diagnose_units() {
+        let input = std::fs::read_to_string("./tool_output/java/jmh/latency.json")
+            .unwrap()
+            .replace("\"s/op\"", "\"furlongs/fortnight\"");
+        let diagnostics = diagnose(Adapter::JavaJmh, &input, Settings::default()).unwrap_err();
+        assert_eq!(diagnostics.inner.len(), 1);
+        let diagnostic = diagnostics.inner.first().unwrap();
+        assert_eq!(
+            diagnostic.error,
+            DiagnosticError::BenchmarkUnits("furlongs/fortnight".into())
+        );
+        assert_eq!(
+            diagnostics.to_string(),
+            "java_jmh: Unsupported benchmark units: furlongs/fortnight"
+        );
+    }
+
+    fn diagnose_location(adapter: Adapter, input: &str) -> (DiagnosticError, DiagnosticLocation) {
+        let diagnostics = diagnose(adapter, input, Settings::default()).unwrap_err();
+        assert_eq!(diagnostics.inner.len(), 1);
+        let diagnostic = diagnostics.inner.into_iter().next().unwrap();
+        assert_eq!(diagnostic.adapter, adapter);
+        (diagnostic.error, diagnostic.location.unwrap())
+    }
+
+    #[test]
+    fn test_diagnose_cpp_catch2() {
+        let input = "Fibonacci 10                                              100         76353          0 ns \n                                                         0 ns          0 ns          0 ns \n                                                         0 ns          0 xs          0 ns \n";
+        let (error, location) = diagnose_location(Adapter::CppCatch2, input);
+        assert_eq!(
+            error,
+            DiagnosticError::Parse("0 ns          0 xs          0 ns".into())
+        );
+        assert_eq!(
+            location,
+            DiagnosticLocation {
+                line: 3,
+                column: 74,
+                offset: 255,
+            }
+        );
+    }
+
+    #[test]
+    fn test_diagnose_rust_criterion() {
+        let input = "file                    time:   [320.62 ps 323.90 xs 327.55 ps]\n";
+        let (_, location) = diagnose_location(Adapter::RustCriterion, input);
+        assert_eq!(
+            location,
+            DiagnosticLocation {
+                line: 1,
+                column: 51,
+                offset: 50,
+            }
+        );
+
+        // The benchmark name is on the line before its time.
+        let input = "tracing_file\n                        time:   [16.652 µs 18.019 µs 19.562]\n";
+        let (_, location) = diagnose_location(Adapter::RustCriterion, input);
+        assert_eq!(
+            location,
+            DiagnosticLocation {
+                line: 2,
+                column: 62,
+                offset: 74,
+            }
+        );
+    }
+
+    #[test]
+    fn test_diagnose_rust_iai() {
+        let input = "bench_fibonacci_short\n  Instructions:                1735\n  L1 Accesses:                 many\n";
+        let (error, location) = diagnose_location(Adapter::RustIai, input);
+        assert_eq!(
+            error,
+            DiagnosticError::Parse("L1 Accesses:                 many".into())
+        );
+        assert_eq!(
+            location,
+            DiagnosticLocation {
+                line: 3,
+                column: 32,
+                offset: 89,
+            }
+        );
+    }
+
+    #[test]
+    fn test_diagnose_rust_iai_callgrind() {
+        let input = "rust_iai_callgrind::bench_fibonacci short:10\n  Instructions:                1734|1700            (+2.0000%) [+1.02000]\n";
+        let (_, location) = diagnose_location(Adapter::RustIaiCallgrind, input);
+        assert_eq!(
+            location,
+            DiagnosticLocation {
+                line: 2,
+                column: 73,
+                offset: 117,
+            }
+        );
+    }
+
+    #[test]
+    fn test_diagnose_rust_divan() {
+        let input = "example          fastest       │ slowest       │ median        │ mean          │ samples │ iters\n╰─ fibonacci     0.291 ns      │ 0.338 ns      │ 0.302 xs      │ 0.303 xs      │ 100     │ 409600\n";
+        let (_, location) = diagnose_location(Adapter::RustDivan, input);
+        assert_eq!(
+            location,
+            DiagnosticLocation {
+                line: 2,
+                column: 58,
+                offset: 164,
+            }
+        );
+    }
+
+    #[test]
+    fn test_diagnose_shell_perf_stat() {
+        let input = "fib 20\nmany,msec,task-clock:u,2510000,100.00,0.912,CPUs utilized\n";
+        let (error, location) = diagnose_location(Adapter::ShellPerfStat, input);
+        assert_eq!(
+            error,
+            DiagnosticError::Parse(
+                "many,msec,task-clock:u,2510000,100.00,0.912,CPUs utilized".into()
+            )
+        );
+        assert_eq!(
+            location,
+            DiagnosticLocation {
+                line: 2,
+                column: 1,
+                offset: 7,
+            }
+        );
+
+        let input = "fib 20\n{\"counter-value\" : \"2.510000\", \"unit\" : \"msec\", \"event\" : task-clock}\n";
+        let (error, location) = diagnose_location(Adapter::ShellPerfStat, input);
+        assert!(matches!(error, DiagnosticError::Json(_)));
+        assert_eq!(
+            location,
+            DiagnosticLocation {
+                line: 2,
+                column: 60,
+                offset: 66,
+            }
+        );
+    }
+
+    #[test]
+    fn test_diagnose_http_wrk() {
+        let input = "Running 30s test @ http://127.0.0.1:8080/index.html\nRequests/sec: many\n";
+        let (error, location) = diagnose_location(Adapter::HttpWrk, input);
+        assert_eq!(error, DiagnosticError::Parse("Requests/sec: many".into()));
+        assert_eq!(
+            location,
+            DiagnosticLocation {
+                line: 2,
+                column: 15,
+                offset: 66,
+            }
+        );
+    }
+
+    #[test]
+    fn test_diagnose_zig_zbench() {
+        let input = "Bubble Sort Benchmark  1000     1.5ms          1.5us ± 250ns          (1.375us ... 5.833us)        1.542us    2.791us    3.125xs\n";
+        let (_, location) = diagnose_location(Adapter::ZigZbench, input);
+        assert_eq!(
+            location,
+            DiagnosticLocation {
+                line: 1,
+                column: 128,
+                offset: 127,
+            }
+        );
+    }
+
+    #[test]
+    fn test_diagnose_elixir_benchee() {
+        let input = "Name                  ips        average  deviation         median         99th %\nflat_map           2.50 K      400.00 μs    ±10.00%      390.00 xs      700.00 μs\n";
+        let (_, location) = diagnose_location(Adapter::ElixirBenchee, input);
+        assert_eq!(
+            location,
+            DiagnosticLocation {
+                line: 2,
+                column: 67,
+                offset: 148,
+            }
+        );
+    }
+
+    #[test]
+    fn test_diagnose_js_vitest() {
+        let input = "     name              hz     min     max    mean     p75     p99    p995    p999     rme  samples\n   · normal      6,526.82  0.1347  0.43x4  0.1532  0.1505  0.2796  0.3185  0.4152  ±0.49%     3264   fastest\n";
+        let (_, location) = diagnose_location(Adapter::JsVitest, input);
+        assert_eq!(
+            location,
+            DiagnosticLocation {
+                line: 2,
+                column: 37,
+                offset: 135,
+            }
+        );
+    }
+}
@@ -6,6 +6,13 @@ pub enum AdapterError {
     Valid(#[from] bencher_json::ValidError),
     #[error("Failed to parse benchmark units: {0}")]
     BenchmarkUnits(String),
-    #[error("Failed to convert results: {0}")]
-    Convert(String),
+    #[error("Failed to compile custom adapter regex: {0}")]
+    CustomRegex(regex::Error),
+    #[error("Custom adapter regex ({regex}) is missing the `{group}` named capture group")]
+    CustomCaptureGroup { regex: String, group: &'static str },
+    #[error("Failed to convert results:\n{diagnostics}\nResults: {results}")]
+    Convert {
+        results: String,
+        diagnostics: crate::AdapterDiagnostics,
+    },
 }
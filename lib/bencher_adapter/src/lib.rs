@@ -1,71 +1,117 @@
 pub mod adapters;
+pub mod diagnostic;
 pub mod error;
 pub mod results;
 
+pub use adapters::custom::{AdapterCustom, CustomAdapterConfig, CustomPattern};
 use adapters::{
     c_sharp::{dot_net::AdapterCSharpDotNet, AdapterCSharp},
     cpp::{catch2::AdapterCppCatch2, google::AdapterCppGoogle, AdapterCpp},
+    elixir::{benchee::AdapterElixirBenchee, AdapterElixir},
     go::{bench::AdapterGoBench, AdapterGo},
+    http::{k6::AdapterHttpK6, oha::AdapterHttpOha, wrk::AdapterHttpWrk, AdapterHttp},
     java::{jmh::AdapterJavaJmh, AdapterJava},
-    js::{benchmark::AdapterJsBenchmark, time::AdapterJsTime, AdapterJs},
+    js::{benchmark::AdapterJsBenchmark, time::AdapterJsTime, vitest::AdapterJsVitest, AdapterJs},
     json::AdapterJson,
     magic::AdapterMagic,
-    python::{asv::AdapterPythonAsv, pytest::AdapterPythonPytest, AdapterPython},
+    python::{
+        asv::AdapterPythonAsv, pyperf::AdapterPythonPyperf, pytest::AdapterPythonPytest,
+        AdapterPython,
+    },
     ruby::{benchmark::AdapterRubyBenchmark, AdapterRuby},
     rust::{
-        bench::AdapterRustBench, criterion::AdapterRustCriterion, iai::AdapterRustIai,
-        iai_callgrind::AdapterRustIaiCallgrind, AdapterRust,
+        bench::AdapterRustBench, criterion::AdapterRustCriterion, divan::AdapterRustDivan,
+        iai::AdapterRustIai, iai_callgrind::AdapterRustIaiCallgrind, AdapterRust,
     },
-    shell::{hyperfine::AdapterShellHyperfine, AdapterShell},
+    shell::{hyperfine::AdapterShellHyperfine, perf_stat::AdapterShellPerfStat, AdapterShell},
+    swift::{benchmark::AdapterSwiftBenchmark, AdapterSwift},
+    zig::{zbench::AdapterZigZbench, AdapterZig},
 };
 use bencher_json::project::report::{Adapter, JsonAverage};
 pub use bencher_json::{BenchmarkName, JsonMetric};
+pub use diagnostic::{diagnose, AdapterDiagnostic, AdapterDiagnostics};
 pub use error::AdapterError;
 pub use results::{adapter_results::AdapterResults, AdapterResultsArray};
 
+// Both converting and explaining use the same adapter for each variant.
+macro_rules! adaptable {
+    ($adapter:expr, $method:ident($input:expr, $settings:expr)) => {
+        match $adapter {
+            Adapter::Magic => AdapterMagic::$method($input, $settings),
+            Adapter::Json => AdapterJson::$method($input, $settings),
+            Adapter::CSharp => AdapterCSharp::$method($input, $settings),
+            Adapter::CSharpDotNet => AdapterCSharpDotNet::$method($input, $settings),
+            Adapter::Cpp => AdapterCpp::$method($input, $settings),
+            Adapter::CppCatch2 => AdapterCppCatch2::$method($input, $settings),
+            Adapter::CppGoogle => AdapterCppGoogle::$method($input, $settings),
+            Adapter::Go => AdapterGo::$method($input, $settings),
+            Adapter::GoBench => AdapterGoBench::$method($input, $settings),
+            Adapter::Java => AdapterJava::$method($input, $settings),
+            Adapter::JavaJmh => AdapterJavaJmh::$method($input, $settings),
+            Adapter::Js => AdapterJs::$method($input, $settings),
+            Adapter::JsBenchmark => AdapterJsBenchmark::$method($input, $settings),
+            Adapter::JsTime => AdapterJsTime::$method($input, $settings),
+            Adapter::JsVitest => AdapterJsVitest::$method($input, $settings),
+            Adapter::Python => AdapterPython::$method($input, $settings),
+            Adapter::PythonAsv => AdapterPythonAsv::$method($input, $settings),
+            Adapter::PythonPytest => AdapterPythonPytest::$method($input, $settings),
+            Adapter::PythonPyperf => AdapterPythonPyperf::$method($input, $settings),
+            Adapter::Ruby => AdapterRuby::$method($input, $settings),
+            Adapter::RubyBenchmark => AdapterRubyBenchmark::$method($input, $settings),
+            Adapter::Rust => AdapterRust::$method($input, $settings),
+            Adapter::RustBench => AdapterRustBench::$method($input, $settings),
+            Adapter::RustCriterion => AdapterRustCriterion::$method($input, $settings),
+            Adapter::RustIai => AdapterRustIai::$method($input, $settings),
+            Adapter::RustIaiCallgrind => AdapterRustIaiCallgrind::$method($input, $settings),
+            Adapter::RustDivan => AdapterRustDivan::$method($input, $settings),
+            Adapter::Shell => AdapterShell::$method($input, $settings),
+            Adapter::ShellHyperfine => AdapterShellHyperfine::$method($input, $settings),
+            Adapter::ShellPerfStat => AdapterShellPerfStat::$method($input, $settings),
+            Adapter::Http => AdapterHttp::$method($input, $settings),
+            Adapter::HttpK6 => AdapterHttpK6::$method($input, $settings),
+            Adapter::HttpWrk => AdapterHttpWrk::$method($input, $settings),
+            Adapter::HttpOha => AdapterHttpOha::$method($input, $settings),
+            Adapter::Swift => AdapterSwift::$method($input, $settings),
+            Adapter::SwiftBenchmark => AdapterSwiftBenchmark::$method($input, $settings),
+            Adapter::Zig => AdapterZig::$method($input, $settings),
+            Adapter::ZigZbench => AdapterZigZbench::$method($input, $settings),
+            Adapter::Elixir => AdapterElixir::$method($input, $settings),
+            Adapter::ElixirBenchee => AdapterElixirBenchee::$method($input, $settings),
+        }
+    };
+}
+
 pub trait Adaptable {
     fn convert(&self, input: &str, settings: Settings) -> Option<AdapterResults> {
         Self::parse(input, settings)
     }
 
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults>;
+
+    /// Explain why the input could not be converted.
+    fn explain(&self, input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        Self::diagnose(input, settings)
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic>;
 }
 
 impl Adaptable for Adapter {
     fn convert(&self, input: &str, settings: Settings) -> Option<AdapterResults> {
-        match self {
-            Adapter::Magic => AdapterMagic::parse(input, settings),
-            Adapter::Json => AdapterJson::parse(input, settings),
-            Adapter::CSharp => AdapterCSharp::parse(input, settings),
-            Adapter::CSharpDotNet => AdapterCSharpDotNet::parse(input, settings),
-            Adapter::Cpp => AdapterCpp::parse(input, settings),
-            Adapter::CppCatch2 => AdapterCppCatch2::parse(input, settings),
-            Adapter::CppGoogle => AdapterCppGoogle::parse(input, settings),
-            Adapter::Go => AdapterGo::parse(input, settings),
-            Adapter::GoBench => AdapterGoBench::parse(input, settings),
-            Adapter::Java => AdapterJava::parse(input, settings),
-            Adapter::JavaJmh => AdapterJavaJmh::parse(input, settings),
-            Adapter::Js => AdapterJs::parse(input, settings),
-            Adapter::JsBenchmark => AdapterJsBenchmark::parse(input, settings),
-            Adapter::JsTime => AdapterJsTime::parse(input, settings),
-            Adapter::Python => AdapterPython::parse(input, settings),
-            Adapter::PythonAsv => AdapterPythonAsv::parse(input, settings),
-            Adapter::PythonPytest => AdapterPythonPytest::parse(input, settings),
-            Adapter::Ruby => AdapterRuby::parse(input, settings),
-            Adapter::RubyBenchmark => AdapterRubyBenchmark::parse(input, settings),
-            Adapter::Rust => AdapterRust::parse(input, settings),
-            Adapter::RustBench => AdapterRustBench::parse(input, settings),
-            Adapter::RustCriterion => AdapterRustCriterion::parse(input, settings),
-            Adapter::RustIai => AdapterRustIai::parse(input, settings),
-            Adapter::RustIaiCallgrind => AdapterRustIaiCallgrind::parse(input, settings),
-            Adapter::Shell => AdapterShell::parse(input, settings),
-            Adapter::ShellHyperfine => AdapterShellHyperfine::parse(input, settings),
-        }
+        adaptable!(self, parse(input, settings))
     }
 
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
         AdapterMagic::parse(input, settings)
     }
+
+    fn explain(&self, input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        adaptable!(self, diagnose(input, settings))
+    }
+
+    fn diagnose(input: &str, settings: Settings) -> Vec<AdapterDiagnostic> {
+        AdapterMagic::diagnose(input, settings)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -3,15 +3,16 @@ use std::{collections::HashMap, str::FromStr};
 use bencher_json::{
     project::{
         measure::{
+            ALLOCATED_BYTES_SLUG_STR, ALLOCATIONS_SLUG_STR, ERROR_RATE_SLUG_STR,
             ESTIMATED_CYCLES_SLUG_STR, INSTRUCTIONS_SLUG_STR, L1_ACCESSES_SLUG_STR,
-            L2_ACCESSES_SLUG_STR, LATENCY_SLUG_STR, RAM_ACCESSES_SLUG_STR, THROUGHPUT_SLUG_STR,
-            TOTAL_ACCESSES_SLUG_STR,
+            L2_ACCESSES_SLUG_STR, LATENCY_P50_SLUG_STR, LATENCY_P90_SLUG_STR, LATENCY_P99_SLUG_STR,
+            LATENCY_SLUG_STR, RAM_ACCESSES_SLUG_STR, SYSTEM_TIME_SLUG_STR, THROUGHPUT_SLUG_STR,
+            TOTAL_ACCESSES_SLUG_STR, USER_TIME_SLUG_STR,
         },
         metric::Mean,
     },
-    BenchmarkName, JsonMetric, NameId,
+    BenchmarkName, JsonMetric, MeasureNameId, NameId,
 };
-use literally::hmap;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 
@@ -51,6 +52,38 @@ pub static TOTAL_ACCESSES_NAME_ID: Lazy<NameId> =
 pub static ESTIMATED_CYCLES_NAME_ID: Lazy<NameId> =
     Lazy::new(|| ESTIMATED_CYCLES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
 
+#[allow(clippy::expect_used)]
+pub static ALLOCATED_BYTES_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| ALLOCATED_BYTES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static ALLOCATIONS_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| ALLOCATIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static LATENCY_P50_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| LATENCY_P50_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static LATENCY_P90_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| LATENCY_P90_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static LATENCY_P99_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| LATENCY_P99_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static ERROR_RATE_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| ERROR_RATE_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static USER_TIME_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| USER_TIME_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static SYSTEM_TIME_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| SYSTEM_TIME_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AdapterResults {
     #[serde(flatten)]
@@ -65,10 +98,20 @@ impl From<ResultsMap> for AdapterResults {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AdapterMeasure {
     Latency(JsonMetric),
     Throughput(JsonMetric),
+    UserTime(JsonMetric),
+    SystemTime(JsonMetric),
+    AllocatedBytes(JsonMetric),
+    Allocations(JsonMetric),
+    LatencyP50(JsonMetric),
+    LatencyP90(JsonMetric),
+    LatencyP99(JsonMetric),
+    ErrorRate(JsonMetric),
+    /// Any other measure by its name or slug, ie a Go `b.ReportMetric` or a `perf stat` event.
+    Custom(MeasureNameId, JsonMetric),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -91,28 +134,50 @@ pub enum IaiCallgrindMeasure {
 }
 
 impl AdapterResults {
-    pub fn new(benchmark_metrics: Vec<(BenchmarkName, AdapterMeasure)>) -> Option<Self> {
+    pub fn new(benchmark_metrics: Vec<(BenchmarkName, Vec<AdapterMeasure>)>) -> Option<Self> {
         if benchmark_metrics.is_empty() {
             return None;
         }
 
         let mut results_map = HashMap::new();
-        for (benchmark_name, measure) in benchmark_metrics {
-            let adapter_metrics = AdapterMetrics {
-                inner: match measure {
-                    AdapterMeasure::Latency(json_metric) => {
-                        hmap! {
-                            LATENCY_NAME_ID.clone() => json_metric
-                        }
-                    },
+        for (benchmark_name, metrics) in benchmark_metrics {
+            let metrics_value = results_map
+                .entry(benchmark_name)
+                .or_insert_with(AdapterMetrics::default);
+            for metric in metrics {
+                let (resource_id, metric) = match metric {
+                    AdapterMeasure::Latency(json_metric) => (LATENCY_NAME_ID.clone(), json_metric),
                     AdapterMeasure::Throughput(json_metric) => {
-                        hmap! {
-                            THROUGHPUT_NAME_ID.clone() => json_metric
-                        }
+                        (THROUGHPUT_NAME_ID.clone(), json_metric)
                     },
-                },
-            };
-            results_map.insert(benchmark_name, adapter_metrics);
+                    AdapterMeasure::UserTime(json_metric) => {
+                        (USER_TIME_NAME_ID.clone(), json_metric)
+                    },
+                    AdapterMeasure::SystemTime(json_metric) => {
+                        (SYSTEM_TIME_NAME_ID.clone(), json_metric)
+                    },
+                    AdapterMeasure::AllocatedBytes(json_metric) => {
+                        (ALLOCATED_BYTES_NAME_ID.clone(), json_metric)
+                    },
+                    AdapterMeasure::Allocations(json_metric) => {
+                        (ALLOCATIONS_NAME_ID.clone(), json_metric)
+                    },
+                    AdapterMeasure::LatencyP50(json_metric) => {
+                        (LATENCY_P50_NAME_ID.clone(), json_metric)
+                    },
+                    AdapterMeasure::LatencyP90(json_metric) => {
+                        (LATENCY_P90_NAME_ID.clone(), json_metric)
+                    },
+                    AdapterMeasure::LatencyP99(json_metric) => {
+                        (LATENCY_P99_NAME_ID.clone(), json_metric)
+                    },
+                    AdapterMeasure::ErrorRate(json_metric) => {
+                        (ERROR_RATE_NAME_ID.clone(), json_metric)
+                    },
+                    AdapterMeasure::Custom(measure, json_metric) => (measure, json_metric),
+                };
+                metrics_value.inner.insert(resource_id, metric);
+            }
         }
 
         Some(results_map.into())
@@ -123,7 +188,7 @@ impl AdapterResults {
             benchmark_metrics
                 .into_iter()
                 .map(|(benchmark_name, json_metric)| {
-                    (benchmark_name, AdapterMeasure::Latency(json_metric))
+                    (benchmark_name, vec![AdapterMeasure::Latency(json_metric)])
                 })
                 .collect(),
         )
@@ -134,7 +199,10 @@ impl AdapterResults {
             benchmark_metrics
                 .into_iter()
                 .map(|(benchmark_name, json_metric)| {
-                    (benchmark_name, AdapterMeasure::Throughput(json_metric))
+                    (
+                        benchmark_name,
+                        vec![AdapterMeasure::Throughput(json_metric)],
+                    )
                 })
                 .collect(),
         )
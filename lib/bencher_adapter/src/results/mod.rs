@@ -5,7 +5,7 @@ use bencher_json::project::{
     report::{Adapter, JsonFold},
 };
 
-use crate::{Adaptable, AdapterError, Settings};
+use crate::{diagnose, AdapterError, Settings};
 
 pub mod adapter_metrics;
 pub mod adapter_results;
@@ -35,9 +35,12 @@ impl AdapterResultsArray {
     ) -> Result<Self, AdapterError> {
         let mut parsed_results_array = Vec::new();
         for &results in results_array {
-            let parsed_results = adapter
-                .convert(results, settings)
-                .ok_or_else(|| AdapterError::Convert((results).to_owned()))?;
+            let parsed_results = diagnose(adapter, results, settings).map_err(|diagnostics| {
+                AdapterError::Convert {
+                    results: results.to_owned(),
+                    diagnostics,
+                }
+            })?;
             parsed_results_array.push(parsed_results);
         }
         Ok(parsed_results_array.into())
@@ -0,0 +1,159 @@
+use crate::{mean::mean, quartiles::Quartiles};
+
+// The number of times to resample the historical data
+const RESAMPLES: usize = 10_000;
+// Always use the same seed, so the same historical data always results in the same boundary limits
+const SEED: u64 = 0x0BE4_C4E2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BootstrapStatistic {
+    Mean,
+    Median,
+}
+
+impl BootstrapStatistic {
+    fn calculate(self, data: &[f64]) -> Option<f64> {
+        match self {
+            Self::Mean => mean(data),
+            Self::Median => Quartiles::new(data).map(|quartiles| quartiles.q2),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bootstrap {
+    /// The statistic for the historical data.
+    pub baseline: f64,
+    /// The statistic for the new sample.
+    pub sample_statistic: f64,
+    /// The sorted statistics for every resample of the historical data.
+    pub statistics: Vec<f64>,
+}
+
+impl Bootstrap {
+    // Resample the historical data with replacement,
+    // using resamples that are the same size as the new sample.
+    // This estimates the distribution of the statistic for the new sample
+    // if it came from the same distribution as the historical data.
+    // https://en.wikipedia.org/wiki/Bootstrapping_(statistics)
+    pub fn new(data: &[f64], sample: &[f64], statistic: BootstrapStatistic) -> Option<Self> {
+        if data.is_empty() {
+            return None;
+        }
+        let baseline = statistic.calculate(data)?;
+        let sample_statistic = statistic.calculate(sample)?;
+
+        let mut rng = SplitMix64::new(SEED);
+        let mut resample = vec![0.0; sample.len()];
+        let mut statistics = Vec::with_capacity(RESAMPLES);
+        for _ in 0..RESAMPLES {
+            for value in &mut resample {
+                *value = *data.get(rng.next_index(data.len()))?;
+            }
+            statistics.push(statistic.calculate(&resample)?);
+        }
+        statistics.sort_unstable_by(|l, r| l.partial_cmp(r).unwrap_or(std::cmp::Ordering::Equal));
+
+        Some(Self {
+            baseline,
+            sample_statistic,
+            statistics,
+        })
+    }
+
+    pub fn percentile(&self, percentile: f64) -> Option<f64> {
+        Quartiles::percentile_of_sorted(&self.statistics, percentile)
+            .and_then(|value| value.is_finite().then_some(value))
+    }
+}
+
+// A small, fast, and most importantly stable pseudorandom number generator
+// https://prng.di.unimi.it/splitmix64.c
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Map the next value into `0..len` without modulo bias
+    // https://lemire.me/blog/2016/06/27/a-fast-alternative-to-the-modulo-reduction/
+    #[allow(clippy::cast_possible_truncation)]
+    fn next_index(&mut self, len: usize) -> usize {
+        ((u128::from(self.next()) * len as u128) >> 64) as usize
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{Bootstrap, BootstrapStatistic, SplitMix64};
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_FIVE: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0];
+    const DATA_BI: &[f64] = &[
+        1.0, 1.1, 0.9, 1.0, 1.0, 5.0, 5.1, 4.9, 5.0, 5.0, 1.0, 1.1, 0.9, 5.0, 5.1, 4.9,
+    ];
+
+    #[test]
+    fn test_split_mix_64() {
+        let mut rng = SplitMix64::new(1234567);
+        assert_eq!(rng.next(), 6457827717110365317);
+        assert_eq!(rng.next(), 3203168211198807973);
+        let mut rng = SplitMix64::new(0);
+        for _ in 0..1_000 {
+            assert!(rng.next_index(5) < 5, "Index out of bounds");
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_zero() {
+        let bootstrap = Bootstrap::new(DATA_ZERO, DATA_ONE, BootstrapStatistic::Mean);
+        assert_eq!(bootstrap, None);
+        let bootstrap = Bootstrap::new(DATA_FIVE, DATA_ZERO, BootstrapStatistic::Mean);
+        assert_eq!(bootstrap, None);
+    }
+
+    #[test]
+    fn test_bootstrap_one() {
+        let bootstrap = Bootstrap::new(DATA_ONE, DATA_ONE, BootstrapStatistic::Median).unwrap();
+        assert_eq!(bootstrap.baseline, 1.0);
+        assert_eq!(bootstrap.sample_statistic, 1.0);
+        assert_eq!(bootstrap.percentile(0.0), Some(1.0));
+        assert_eq!(bootstrap.percentile(1.0), Some(1.0));
+    }
+
+    #[test]
+    fn test_bootstrap_deterministic() {
+        let sample = &[2.0, 3.0];
+        let bootstrap = Bootstrap::new(DATA_FIVE, sample, BootstrapStatistic::Mean).unwrap();
+        assert_eq!(
+            bootstrap,
+            Bootstrap::new(DATA_FIVE, sample, BootstrapStatistic::Mean).unwrap()
+        );
+        assert_eq!(bootstrap.baseline, 3.0);
+        assert_eq!(bootstrap.sample_statistic, 2.5);
+        assert_eq!(bootstrap.percentile(0.0), Some(1.0));
+        assert_eq!(bootstrap.percentile(1.0), Some(5.0));
+    }
+
+    #[test]
+    fn test_bootstrap_bimodal() {
+        // A single datum is compared against the empirical distribution,
+        // so both modes are within the limits.
+        let bootstrap = Bootstrap::new(DATA_BI, &[5.0], BootstrapStatistic::Median).unwrap();
+        assert_eq!(bootstrap.percentile(0.01), Some(0.9));
+        assert_eq!(bootstrap.percentile(0.99), Some(5.1));
+    }
+}
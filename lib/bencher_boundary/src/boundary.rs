@@ -1,46 +1,102 @@
-use bencher_json::{project::boundary::BoundaryLimit, Boundary, ModelTest, SampleSize};
+use bencher_json::{
+    project::boundary::BoundaryLimit, Boundary, ModelTest, SampleSize, SmoothingFactor,
+};
 use slog::Logger;
 
+use crate::bootstrap::{Bootstrap, BootstrapStatistic};
+use crate::change_point::ChangePoint;
+use crate::ewma::Ewma;
+use crate::inverse_variance::InverseVariance;
 use crate::limits::{MetricsLimits, NormalTestKind};
 use crate::ln::Ln;
 use crate::mean::Mean;
+use crate::p_value::PValue;
 use crate::quartiles::Quartiles;
+use crate::two_sample::{MannWhitney, Welch};
 use crate::{BoundaryError, MetricsData};
 
 #[derive(Debug, Default)]
 pub struct MetricsBoundary {
     pub limits: MetricsLimits,
     pub outlier: Option<BoundaryLimit>,
+    /// The one-sided p-values for the datum, if the model test has them.
+    pub p_value: Option<PValue>,
 }
 
 impl MetricsBoundary {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         log: &Logger,
         datum: f64,
+        datum_spread: Option<f64>,
         metrics_data: &MetricsData,
         model_test: ModelTest,
         min_sample_size: Option<SampleSize>,
+        smoothing_factor: Option<SmoothingFactor>,
         lower_boundary: Option<Boundary>,
         upper_boundary: Option<Boundary>,
     ) -> Result<Self, BoundaryError> {
         Self::new_inner(
             log,
-            datum,
+            &[datum],
+            datum_spread,
             metrics_data,
             model_test,
             min_sample_size,
+            smoothing_factor,
             lower_boundary,
             upper_boundary,
         )
         .map(Option::unwrap_or_default)
     }
 
+    // Two-sample tests compare the entire sample against the historical data.
+    // All other tests only check the last datum in the sample.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_sample(
+        log: &Logger,
+        sample: &[f64],
+        metrics_data: &MetricsData,
+        model_test: ModelTest,
+        min_sample_size: Option<SampleSize>,
+        smoothing_factor: Option<SmoothingFactor>,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Self, BoundaryError> {
+        Self::new_inner(
+            log,
+            sample,
+            None,
+            metrics_data,
+            model_test,
+            min_sample_size,
+            smoothing_factor,
+            lower_boundary,
+            upper_boundary,
+        )
+        .map(Option::unwrap_or_default)
+    }
+
+    // The significance ratio is used to correct for multiple comparisons across a report.
+    // Model tests without a p-value are never corrected.
+    pub fn significance_ratio(
+        &self,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Option<f64> {
+        self.p_value
+            .and_then(|p_value| p_value.significance_ratio(lower_boundary, upper_boundary))
+    }
+
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
     fn new_inner(
         log: &Logger,
-        datum: f64,
+        sample: &[f64],
+        datum_spread: Option<f64>,
         metrics_data: &MetricsData,
         model_test: ModelTest,
         min_sample_size: Option<SampleSize>,
+        smoothing_factor: Option<SmoothingFactor>,
         lower_boundary: Option<Boundary>,
         upper_boundary: Option<Boundary>,
     ) -> Result<Option<Self>, BoundaryError> {
@@ -52,6 +108,10 @@ impl MetricsBoundary {
             );
             return Ok(None);
         }
+        let Some(&datum) = sample.last() else {
+            slog::debug!(log, "No sample for threshold model test {model_test:?}");
+            return Ok(None);
+        };
         let data = &metrics_data.data;
         let data_len = data.len();
         // If there is a min sample size, then check to see if it is met.
@@ -106,6 +166,67 @@ impl MetricsBoundary {
             ModelTest::DeltaIqr => {
                 Self::new_iqr(log, datum, data, true, lower_boundary, upper_boundary)
             },
+            ModelTest::ChangePoint => {
+                Self::new_change_point(log, datum, data, lower_boundary, upper_boundary)
+            },
+            // Welch's t-test needs at least two iterations to estimate the sample variance,
+            // so a single iteration falls back to a t-test against the historical data.
+            ModelTest::WelchTTest if sample.len() < 2 => {
+                slog::debug!(
+                    log,
+                    "Sample length ({}) is too small for Welch's t-test, using a t-test",
+                    sample.len()
+                );
+                Self::new_normal(
+                    log,
+                    datum,
+                    data,
+                    #[allow(clippy::cast_precision_loss)]
+                    NormalTestKind::T {
+                        freedom: (data.len() - 1) as f64,
+                    },
+                    lower_boundary,
+                    upper_boundary,
+                )
+            },
+            ModelTest::WelchTTest => {
+                Self::new_welch(log, sample, data, lower_boundary, upper_boundary)
+            },
+            ModelTest::MannWhitneyU => {
+                Self::new_mann_whitney(log, sample, data, lower_boundary, upper_boundary)
+            },
+            ModelTest::BootstrapMean => Self::new_bootstrap(
+                log,
+                sample,
+                data,
+                BootstrapStatistic::Mean,
+                lower_boundary,
+                upper_boundary,
+            ),
+            ModelTest::BootstrapMedian => Self::new_bootstrap(
+                log,
+                sample,
+                data,
+                BootstrapStatistic::Median,
+                lower_boundary,
+                upper_boundary,
+            ),
+            ModelTest::Ewma => Self::new_ewma(
+                log,
+                datum,
+                data,
+                smoothing_factor.unwrap_or(SmoothingFactor::DEFAULT),
+                lower_boundary,
+                upper_boundary,
+            ),
+            ModelTest::InverseVariance => Self::new_inverse_variance(
+                log,
+                datum,
+                datum_spread,
+                metrics_data,
+                lower_boundary,
+                upper_boundary,
+            ),
         }
     }
 
@@ -117,7 +238,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_static(lower_boundary, upper_boundary);
         let outlier = limits.outlier(datum);
 
-        Self { limits, outlier }
+        Self {
+            limits,
+            outlier,
+            p_value: None,
+        }
     }
 
     fn new_percentage(
@@ -144,7 +269,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_percentage(log, mean, lower_boundary, upper_boundary);
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: None,
+        }))
     }
 
     fn new_normal(
@@ -183,7 +312,11 @@ impl MetricsBoundary {
         )?;
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: PValue::normal(mean, std_dev, test_kind, datum),
+        }))
     }
 
     fn new_log_normal(
@@ -209,7 +342,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_log_normal(log, ln, lower_boundary, upper_boundary)?;
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: PValue::log_normal(ln, datum),
+        }))
     }
 
     fn new_iqr(
@@ -251,6 +388,249 @@ impl MetricsBoundary {
         );
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: None,
+        }))
+    }
+
+    fn new_change_point(
+        log: &Logger,
+        datum: f64,
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(ChangePoint {
+            mean,
+            std_dev,
+            shift,
+        }) = ChangePoint::new(data, datum)
+        else {
+            return Ok(None);
+        };
+        slog::debug!(log, "Change point: shift={shift}");
+
+        let limits = MetricsLimits::new_normal(
+            log,
+            mean,
+            std_dev,
+            NormalTestKind::Z,
+            lower_boundary,
+            upper_boundary,
+        )?;
+        // Only the datum that begins a new segment can be an outlier,
+        // so that a sustained shift only ever generates a single alert.
+        let outlier = if shift { limits.outlier(datum) } else { None };
+
+        // Likewise, only the datum that begins a new segment has a p-value.
+        let p_value = if shift {
+            PValue::normal(mean, std_dev, NormalTestKind::Z, datum)
+        } else {
+            None
+        };
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value,
+        }))
+    }
+
+    fn new_ewma(
+        log: &Logger,
+        datum: f64,
+        data: &[f64],
+        smoothing_factor: SmoothingFactor,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        // Get the exponentially weighted mean and standard deviation of the historical data.
+        let Some(Ewma { mean, std_dev }) = Ewma::new(data, smoothing_factor.into()) else {
+            return Ok(None);
+        };
+
+        let limits = MetricsLimits::new_normal(
+            log,
+            mean,
+            std_dev,
+            NormalTestKind::Z,
+            lower_boundary,
+            upper_boundary,
+        )?;
+        let outlier = limits.outlier(datum);
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: PValue::normal(mean, std_dev, NormalTestKind::Z, datum),
+        }))
+    }
+
+    fn new_inverse_variance(
+        log: &Logger,
+        datum: f64,
+        datum_spread: Option<f64>,
+        metrics_data: &MetricsData,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        // Get the inverse variance weighted mean and the combined standard deviation.
+        let Some(InverseVariance { mean, std_dev }) =
+            InverseVariance::new(&metrics_data.data, &metrics_data.spread, datum_spread)
+        else {
+            return Ok(None);
+        };
+
+        let limits = MetricsLimits::new_normal(
+            log,
+            mean,
+            std_dev,
+            NormalTestKind::Z,
+            lower_boundary,
+            upper_boundary,
+        )?;
+        let outlier = limits.outlier(datum);
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: PValue::normal(mean, std_dev, NormalTestKind::Z, datum),
+        }))
+    }
+
+    fn new_welch(
+        log: &Logger,
+        sample: &[f64],
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(Welch {
+            mean,
+            sample_mean,
+            std_err,
+            freedom,
+        }) = Welch::new(data, sample)
+        else {
+            return Ok(None);
+        };
+
+        // The limits are the critical values for the difference in means,
+        // so the sample mean is only an outlier if the p-value is below the boundary significance.
+        let limits = MetricsLimits::new_normal(
+            log,
+            mean,
+            std_err,
+            NormalTestKind::T { freedom },
+            lower_boundary,
+            upper_boundary,
+        )?;
+        let outlier = limits.outlier(sample_mean);
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: PValue::normal(mean, std_err, NormalTestKind::T { freedom }, sample_mean),
+        }))
+    }
+
+    fn new_mann_whitney(
+        log: &Logger,
+        sample: &[f64],
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(mann_whitney) = MannWhitney::new(data, sample) else {
+            return Ok(None);
+        };
+
+        let limits =
+            MetricsLimits::new_mann_whitney(log, &mann_whitney, lower_boundary, upper_boundary)?;
+        let outlier = limits.outlier(mann_whitney.sample_median);
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: mann_whitney.z_score().and_then(PValue::standard_normal),
+        }))
+    }
+
+    fn new_bootstrap(
+        log: &Logger,
+        sample: &[f64],
+        data: &[f64],
+        statistic: BootstrapStatistic,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(bootstrap) = Bootstrap::new(data, sample, statistic) else {
+            return Ok(None);
+        };
+
+        let limits = MetricsLimits::new_bootstrap(log, &bootstrap, lower_boundary, upper_boundary);
+        let outlier = limits.outlier(bootstrap.sample_statistic);
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: PValue::empirical(&bootstrap.statistics, bootstrap.sample_statistic),
+        }))
     }
 }
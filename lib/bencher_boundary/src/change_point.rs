@@ -0,0 +1,207 @@
+use crate::{mean::mean, quartiles::Quartiles};
+
+// Scale factor to convert a median absolute deviation into a standard deviation
+// https://en.wikipedia.org/wiki/Median_absolute_deviation#Relation_to_standard_deviation
+const MAD_SCALE: f64 = 1.482_602_218_505_602;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChangePoint {
+    /// The mean of the segment that the datum is compared against.
+    pub mean: f64,
+    /// The noise of the series, estimated from the differences between adjacent values.
+    pub std_dev: f64,
+    /// Whether the datum begins a new segment.
+    pub shift: bool,
+}
+
+impl ChangePoint {
+    // The historical data is expected to be ordered from most recent to least recent,
+    // and it must not include the datum itself.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(data: &[f64], datum: f64) -> Option<Self> {
+        if data.is_empty() {
+            return None;
+        }
+
+        let series = data
+            .iter()
+            .rev()
+            .copied()
+            .chain(std::iter::once(datum))
+            .collect::<Vec<_>>();
+        let std_dev = noise(&series)?;
+        // Bayesian information criterion (BIC) penalty for a change in mean with a known variance
+        let penalty = 2.0 * std_dev.powi(2) * (series.len() as f64).ln();
+        let change_points = pelt(&series, penalty);
+
+        let last = series.len() - 1;
+        let (shift, start) = match change_points.as_slice() {
+            [.., previous, current] if *current == last => (true, *previous),
+            [current] if *current == last => (true, 0),
+            [.., current] => (false, *current),
+            [] => (false, 0),
+        };
+        // Compare the datum to the segment that precedes it,
+        // or to the rest of its own segment if it does not begin a new one.
+        let mean = mean(series.get(start..last)?)?;
+
+        Some(Self {
+            mean,
+            std_dev,
+            shift,
+        })
+    }
+}
+
+// Estimate the noise of a series that may contain step changes
+// using the median absolute difference between adjacent values.
+// If more than half of the adjacent values are equal, then fall back to the mean squared successive difference.
+// https://en.wikipedia.org/wiki/Von_Neumann_ratio
+#[allow(clippy::cast_precision_loss)]
+fn noise(series: &[f64]) -> Option<f64> {
+    let diffs = series
+        .windows(2)
+        .filter_map(|window| match window {
+            [a, b] => Some((b - a).abs()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if diffs.is_empty() {
+        return None;
+    }
+
+    let Quartiles { q2: median, .. } = Quartiles::new(&diffs)?;
+    let std_dev = if median > 0.0 {
+        MAD_SCALE * median / std::f64::consts::SQRT_2
+    } else {
+        let mssd = diffs.iter().map(|diff| diff.powi(2)).sum::<f64>() / diffs.len() as f64;
+        (mssd / 2.0).sqrt()
+    };
+    (std_dev.is_finite() && std_dev > 0.0).then_some(std_dev)
+}
+
+// Pruned Exact Linear Time (PELT) change point detection for a change in mean
+// https://arxiv.org/abs/1101.1438
+// Returns the index of the first value of each segment after the first.
+#[allow(clippy::cast_precision_loss, clippy::indexing_slicing)]
+fn pelt(series: &[f64], penalty: f64) -> Vec<usize> {
+    let len = series.len();
+    let mut sum = Vec::with_capacity(len + 1);
+    let mut sum_sq = Vec::with_capacity(len + 1);
+    sum.push(0.0);
+    sum_sq.push(0.0);
+    for (i, value) in series.iter().enumerate() {
+        sum.push(sum[i] + value);
+        sum_sq.push(sum_sq[i] + value.powi(2));
+    }
+    // The sum of squared deviations from the mean for the segment `start..end`
+    let cost = |start: usize, end: usize| {
+        let n = (end - start) as f64;
+        let segment_sum = sum[end] - sum[start];
+        (sum_sq[end] - sum_sq[start]) - segment_sum.powi(2) / n
+    };
+
+    let mut optimal = vec![-penalty; len + 1];
+    let mut previous = vec![0; len + 1];
+    let mut candidates = vec![0];
+    for end in 1..=len {
+        let mut best = (0, f64::INFINITY);
+        for &start in &candidates {
+            let total = optimal[start] + cost(start, end) + penalty;
+            if total < best.1 {
+                best = (start, total);
+            }
+        }
+        let (start, total) = best;
+        optimal[end] = total;
+        previous[end] = start;
+        candidates.retain(|&start| optimal[start] + cost(start, end) <= total);
+        candidates.push(end);
+    }
+
+    let mut change_points = Vec::new();
+    let mut end = len;
+    while end > 0 {
+        let start = previous[end];
+        if start > 0 {
+            change_points.push(start);
+        }
+        end = start;
+    }
+    change_points.reverse();
+    change_points
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{noise, pelt, ChangePoint};
+
+    // Most recent first
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_FLAT: &[f64] = &[
+        10.2, 9.9, 10.1, 9.8, 10.0, 10.3, 9.7, 10.1, 9.9, 10.0, 10.2, 9.8,
+    ];
+    const DATA_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+    const DATA_STEP: &[f64] = &[
+        20.1, 19.8, 20.2, 10.2, 9.9, 10.1, 9.8, 10.0, 10.3, 9.7, 10.1, 9.9,
+    ];
+
+    #[test]
+    fn test_change_point_zero() {
+        let change_point = ChangePoint::new(DATA_ZERO, 10.0);
+        assert_eq!(change_point, None);
+    }
+
+    #[test]
+    fn test_change_point_const() {
+        let change_point = ChangePoint::new(DATA_CONST, 1.0);
+        assert_eq!(change_point, None);
+    }
+
+    #[test]
+    fn test_change_point_flat() {
+        let change_point = ChangePoint::new(DATA_FLAT, 10.1).unwrap();
+        assert!(!change_point.shift, "Unexpected shift: {change_point:?}");
+        assert_eq!(change_point.mean, 10.0);
+    }
+
+    #[test]
+    fn test_change_point_shift() {
+        let change_point = ChangePoint::new(DATA_FLAT, 20.0).unwrap();
+        assert!(change_point.shift, "Expected shift: {change_point:?}");
+        assert_eq!(change_point.mean, 10.0);
+    }
+
+    #[test]
+    fn test_change_point_sustained() {
+        // The shift began three data points ago, so the datum is part of the new segment.
+        let change_point = ChangePoint::new(DATA_STEP, 20.0).unwrap();
+        assert!(!change_point.shift, "Unexpected shift: {change_point:?}");
+        assert_eq!(change_point.mean, 20.033333333333335);
+    }
+
+    #[test]
+    fn test_change_point_const_shift() {
+        let change_point = ChangePoint::new(DATA_CONST, 2.0).unwrap();
+        assert!(change_point.shift, "Expected shift: {change_point:?}");
+        assert_eq!(change_point.mean, 1.0);
+    }
+
+    #[test]
+    fn test_noise() {
+        assert_eq!(noise(&[]), None);
+        assert_eq!(noise(&[1.0]), None);
+        assert_eq!(noise(&[1.0, 1.0, 1.0]), None);
+        assert_eq!(noise(&[1.0, 1.0, 1.0, 3.0]), Some(0.816496580927726));
+    }
+
+    #[test]
+    fn test_pelt() {
+        let series = [1.0, 1.1, 0.9, 1.0, 5.0, 5.1, 4.9, 5.0, 1.0, 0.9, 1.1];
+        assert_eq!(pelt(&series, 1.0), vec![4, 8]);
+        assert_eq!(pelt(&series, 1_000.0), Vec::<usize>::new());
+    }
+}
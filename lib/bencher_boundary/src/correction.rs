@@ -0,0 +1,93 @@
+use bencher_json::{project::report::JsonCorrection, Boundary};
+
+// The factor that the significance level (one minus the boundary) of every test is multiplied by,
+// given the significance ratio of every test that has a p-value.
+// https://en.wikipedia.org/wiki/Bonferroni_correction
+// https://en.wikipedia.org/wiki/False_discovery_rate#Benjamini%E2%80%93Hochberg_procedure
+#[allow(clippy::cast_precision_loss)]
+pub fn correction_factor(correction: JsonCorrection, significance_ratios: &[f64]) -> f64 {
+    let tests = significance_ratios.len();
+    if tests == 0 {
+        return 1.0;
+    }
+    let tests_f64 = tests as f64;
+    match correction {
+        JsonCorrection::Bonferroni => 1.0 / tests_f64,
+        JsonCorrection::BenjaminiHochberg => {
+            let mut sorted_ratios = significance_ratios.to_vec();
+            sorted_ratios.sort_unstable_by(f64::total_cmp);
+            // Find the largest rank where the ratio is at or below its rank-scaled threshold.
+            // If there is no such rank, then nothing is significant and the strictest factor is used.
+            let rank = sorted_ratios
+                .iter()
+                .enumerate()
+                .filter_map(|(index, ratio)| {
+                    let rank = index + 1;
+                    (*ratio <= rank as f64 / tests_f64).then_some(rank)
+                })
+                .last()
+                .unwrap_or(1);
+            rank as f64 / tests_f64
+        },
+    }
+}
+
+// Scale the significance level of the boundary by the correction factor
+pub fn adjust_boundary(boundary: Boundary, factor: f64) -> Option<Boundary> {
+    let significance = 1.0 - f64::from(boundary);
+    (1.0 - significance * factor).try_into().ok()
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unwrap_used)]
+mod test {
+    use bencher_json::{project::report::JsonCorrection, Boundary};
+    use pretty_assertions::assert_eq;
+
+    use super::{adjust_boundary, correction_factor};
+
+    #[test]
+    fn test_correction_factor_empty() {
+        assert_eq!(correction_factor(JsonCorrection::Bonferroni, &[]), 1.0);
+        assert_eq!(
+            correction_factor(JsonCorrection::BenjaminiHochberg, &[]),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_correction_factor_bonferroni() {
+        let ratios = [0.1, 0.5, 2.0, 10.0];
+        assert_eq!(correction_factor(JsonCorrection::Bonferroni, &ratios), 0.25);
+    }
+
+    #[test]
+    fn test_correction_factor_benjamini_hochberg() {
+        // The first two ratios are at or below 1/4 and 2/4
+        let ratios = [10.0, 0.5, 0.1, 2.0];
+        assert_eq!(
+            correction_factor(JsonCorrection::BenjaminiHochberg, &ratios),
+            0.5
+        );
+        // Nothing is significant
+        let ratios = [10.0, 5.0, 2.0, 1.5];
+        assert_eq!(
+            correction_factor(JsonCorrection::BenjaminiHochberg, &ratios),
+            0.25
+        );
+        // Everything is significant
+        let ratios = [0.1, 0.2, 0.3, 0.4];
+        assert_eq!(
+            correction_factor(JsonCorrection::BenjaminiHochberg, &ratios),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_adjust_boundary() {
+        let boundary = adjust_boundary(Boundary::NINETY, 0.5).unwrap();
+        assert_eq!(f64::from(boundary), 0.95);
+        let boundary = adjust_boundary(Boundary::NINETY, 1.0).unwrap();
+        assert_eq!(f64::from(boundary), 0.9);
+    }
+}
@@ -0,0 +1,85 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ewma {
+    /// The exponentially weighted moving average of the historical data.
+    pub mean: f64,
+    /// The square root of the exponentially weighted moving variance of the historical data.
+    pub std_dev: f64,
+}
+
+impl Ewma {
+    // The historical data is expected to be ordered from most recent to least recent,
+    // so that the most recent data is given the most weight.
+    // https://en.wikipedia.org/wiki/Moving_average#Exponentially_weighted_moving_variance_and_standard_deviation
+    pub fn new(data: &[f64], smoothing_factor: f64) -> Option<Self> {
+        // Do not calculate the variance if there are less than 2 data points
+        if data.len() < 2 {
+            return None;
+        }
+
+        let mut values = data.iter().rev();
+        let mut mean = *values.next()?;
+        let mut variance = 0.0;
+        for value in values {
+            let diff = value - mean;
+            let increment = smoothing_factor * diff;
+            mean += increment;
+            variance = (1.0 - smoothing_factor) * (variance + diff * increment);
+        }
+
+        let std_dev = variance.sqrt();
+        (mean.is_finite() && std_dev.is_finite() && std_dev > 0.0).then_some(Self { mean, std_dev })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::Ewma;
+
+    // Most recent first
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_THREE: &[f64] = &[3.0, 2.0, 1.0];
+    const DATA_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+    const DATA_DRIFT: &[f64] = &[20.0, 19.0, 18.0, 17.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0];
+
+    #[test]
+    fn test_ewma_zero() {
+        assert_eq!(Ewma::new(DATA_ZERO, 0.5), None);
+    }
+
+    #[test]
+    fn test_ewma_one() {
+        assert_eq!(Ewma::new(DATA_ONE, 0.5), None);
+    }
+
+    #[test]
+    fn test_ewma_const() {
+        assert_eq!(Ewma::new(DATA_CONST, 0.5), None);
+    }
+
+    #[test]
+    fn test_ewma_three() {
+        let ewma = Ewma::new(DATA_THREE, 0.5).unwrap();
+        assert_eq!(
+            ewma,
+            Ewma {
+                mean: 2.25,
+                std_dev: 0.82915619758885,
+            }
+        );
+        // Without any smoothing only the most recent datum is used, so there is no variance.
+        assert_eq!(Ewma::new(DATA_THREE, 1.0), None);
+    }
+
+    #[test]
+    fn test_ewma_drift() {
+        // The most recent data are given more weight than the unweighted mean.
+        let ewma = Ewma::new(DATA_DRIFT, 0.5).unwrap();
+        assert!(ewma.mean > 17.0, "Unexpected mean: {ewma:?}");
+        let unweighted = DATA_DRIFT.iter().sum::<f64>() / 10.0;
+        assert!(ewma.mean > unweighted, "Unexpected mean: {ewma:?}");
+    }
+}
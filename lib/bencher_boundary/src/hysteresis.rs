@@ -0,0 +1,149 @@
+use bencher_json::{project::boundary::BoundaryLimit, ModelTest, Violations};
+
+/// A previous boundary check: the metric value and its boundary limits.
+#[derive(Debug, Clone, Copy)]
+pub struct BoundaryCheck {
+    pub value: f64,
+    pub lower_limit: Option<f64>,
+    pub upper_limit: Option<f64>,
+}
+
+impl BoundaryCheck {
+    fn violates(self, outlier: BoundaryLimit) -> bool {
+        match outlier {
+            BoundaryLimit::Lower => self.lower_limit.is_some_and(|limit| self.value < limit),
+            BoundaryLimit::Upper => self.upper_limit.is_some_and(|limit| self.value > limit),
+        }
+    }
+}
+
+/// `Hysteresis` requires a boundary violation to persist before an alert is generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hysteresis {
+    /// The number of violations required, including the current one.
+    violations: usize,
+    /// The number of most recent boundary checks, including the current one.
+    window: usize,
+}
+
+impl Hysteresis {
+    // If there is no violations count or only a single violation is required,
+    // then every violation generates an alert.
+    // Previous violations are found by comparing each metric value against its limits,
+    // so models that do not work that way also generate an alert for every violation.
+    pub fn new(
+        test: ModelTest,
+        violations: Option<Violations>,
+        window: Option<Violations>,
+    ) -> Option<Self> {
+        if !test.is_value_limit() {
+            return None;
+        }
+        let violations = usize::from(violations?);
+        let window = window.map_or(violations, usize::from).max(violations);
+        (violations > 1).then_some(Self { violations, window })
+    }
+
+    /// The number of previous boundary checks needed.
+    pub fn history_len(self) -> usize {
+        self.window - 1
+    }
+
+    // The history is the previous boundary checks, ordered from most recent to least recent.
+    // Only violations on the same side as the current outlier are counted.
+    pub fn persists<I>(self, outlier: BoundaryLimit, history: I) -> bool
+    where
+        I: IntoIterator<Item = BoundaryCheck>,
+    {
+        let previous = history
+            .into_iter()
+            .take(self.history_len())
+            .filter(|check| check.violates(outlier))
+            .count();
+        previous + 1 >= self.violations
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use bencher_json::{project::boundary::BoundaryLimit, ModelTest, Violations};
+    use pretty_assertions::assert_eq;
+
+    use super::{BoundaryCheck, Hysteresis};
+
+    const LOW: BoundaryCheck = check(0.0);
+    const OK: BoundaryCheck = check(2.0);
+    const HIGH: BoundaryCheck = check(4.0);
+
+    const fn check(value: f64) -> BoundaryCheck {
+        BoundaryCheck {
+            value,
+            lower_limit: Some(1.0),
+            upper_limit: Some(3.0),
+        }
+    }
+
+    fn violations(violations: u32) -> Violations {
+        violations.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_hysteresis_none() {
+        assert_eq!(Hysteresis::new(ModelTest::TTest, None, None), None);
+        assert_eq!(
+            Hysteresis::new(ModelTest::TTest, None, Some(violations(3))),
+            None
+        );
+        assert_eq!(
+            Hysteresis::new(ModelTest::TTest, Some(violations(1)), None),
+            None
+        );
+        assert_eq!(
+            Hysteresis::new(ModelTest::TTest, Some(violations(1)), Some(violations(3))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hysteresis_consecutive() {
+        let hysteresis = Hysteresis::new(ModelTest::TTest, Some(violations(3)), None).unwrap();
+        assert_eq!(hysteresis.history_len(), 2);
+        assert!(hysteresis.persists(BoundaryLimit::Upper, [HIGH, HIGH, OK]));
+        assert!(!hysteresis.persists(BoundaryLimit::Upper, [HIGH, OK, HIGH]));
+        assert!(!hysteresis.persists(BoundaryLimit::Upper, [HIGH]));
+        assert!(!hysteresis.persists(BoundaryLimit::Upper, []));
+        // Violations on the other side do not count
+        assert!(!hysteresis.persists(BoundaryLimit::Upper, [HIGH, LOW]));
+        assert!(hysteresis.persists(BoundaryLimit::Lower, [LOW, LOW]));
+    }
+
+    #[test]
+    fn test_hysteresis_window() {
+        let hysteresis =
+            Hysteresis::new(ModelTest::TTest, Some(violations(3)), Some(violations(5))).unwrap();
+        assert_eq!(hysteresis.history_len(), 4);
+        assert!(hysteresis.persists(BoundaryLimit::Upper, [HIGH, OK, OK, HIGH]));
+        assert!(!hysteresis.persists(BoundaryLimit::Upper, [HIGH, OK, OK, OK, HIGH]));
+        // No limit on that side is never a violation
+        let no_upper = BoundaryCheck {
+            upper_limit: None,
+            ..HIGH
+        };
+        assert!(!hysteresis.persists(BoundaryLimit::Upper, [no_upper; 4]));
+    }
+
+    #[test]
+    fn test_hysteresis_model_test() {
+        for test in [
+            ModelTest::ChangePoint,
+            ModelTest::WelchTTest,
+            ModelTest::MannWhitneyU,
+            ModelTest::BootstrapMean,
+            ModelTest::BootstrapMedian,
+        ] {
+            assert_eq!(Hysteresis::new(test, Some(violations(3)), None), None);
+        }
+        assert!(Hysteresis::new(ModelTest::Ewma, Some(violations(3)), None).is_some());
+    }
+}
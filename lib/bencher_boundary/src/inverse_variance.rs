@@ -0,0 +1,150 @@
+use crate::quartiles::Quartiles;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InverseVariance {
+    /// The inverse variance weighted mean of the historical data.
+    pub mean: f64,
+    /// The combined standard deviation of the historical data and the datum itself.
+    pub std_dev: f64,
+}
+
+impl InverseVariance {
+    // Each historical datum is weighted by the inverse of its own reported variance,
+    // so that a datum with a wide interval counts less than a datum with a narrow one.
+    // The spread of the new datum is then added to the spread of the historical data,
+    // so that a datum with a wide interval is less likely to be an outlier.
+    // If a historical datum does not have an interval, then it is given the median spread.
+    // If no historical datum has an interval, then every datum is given the same weight.
+    // https://en.wikipedia.org/wiki/Inverse-variance_weighting
+    pub fn new(data: &[f64], spread: &[Option<f64>], datum_spread: Option<f64>) -> Option<Self> {
+        // Do not calculate the variance if there are less than 2 data points
+        if data.len() < 2 || data.len() != spread.len() {
+            return None;
+        }
+
+        let known_spread = spread
+            .iter()
+            .filter_map(|spread| spread.filter(|spread| is_valid_spread(*spread)))
+            .collect::<Vec<_>>();
+        let default_spread = Quartiles::new(&known_spread).map_or(1.0, |quartiles| quartiles.q2);
+        let weights = spread
+            .iter()
+            .map(|spread| {
+                let spread = spread
+                    .filter(|spread| is_valid_spread(*spread))
+                    .unwrap_or(default_spread);
+                1.0 / spread.powi(2)
+            })
+            .collect::<Vec<_>>();
+
+        let total_weight = weights.iter().sum::<f64>();
+        let mean = data
+            .iter()
+            .zip(&weights)
+            .map(|(value, weight)| value * weight)
+            .sum::<f64>()
+            / total_weight;
+        let variance = data
+            .iter()
+            .zip(&weights)
+            .map(|(value, weight)| weight * (value - mean).powi(2))
+            .sum::<f64>()
+            / total_weight;
+        let datum_variance = datum_spread
+            .filter(|spread| is_valid_spread(*spread))
+            .map_or(0.0, |spread| spread.powi(2));
+
+        let std_dev = (variance + datum_variance).sqrt();
+        (mean.is_finite() && std_dev.is_finite() && std_dev > 0.0).then_some(Self { mean, std_dev })
+    }
+}
+
+fn is_valid_spread(spread: f64) -> bool {
+    spread.is_finite() && spread > 0.0
+}
+
+// The spread of a datum is half of the width of its interval.
+// If only one side of the interval is known, then it is assumed to be symmetric.
+pub fn metric_spread(
+    value: f64,
+    lower_value: Option<f64>,
+    upper_value: Option<f64>,
+) -> Option<f64> {
+    let spread = match (lower_value, upper_value) {
+        (Some(lower_value), Some(upper_value)) => (upper_value - lower_value) / 2.0,
+        (Some(lower_value), None) => value - lower_value,
+        (None, Some(upper_value)) => upper_value - value,
+        (None, None) => return None,
+    };
+    is_valid_spread(spread).then_some(spread)
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{metric_spread, InverseVariance};
+
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_TWO: &[f64] = &[1.0, 3.0];
+    const DATA_THREE: &[f64] = &[1.0, 2.0, 3.0];
+    const SPREAD_NONE: &[Option<f64>] = &[None, None, None];
+
+    #[test]
+    fn test_inverse_variance_one() {
+        assert_eq!(InverseVariance::new(DATA_ONE, &[Some(1.0)], None), None);
+    }
+
+    #[test]
+    fn test_inverse_variance_mismatch() {
+        assert_eq!(InverseVariance::new(DATA_THREE, &[None], None), None);
+    }
+
+    #[test]
+    fn test_inverse_variance_unweighted() {
+        let inverse_variance = InverseVariance::new(DATA_THREE, SPREAD_NONE, None).unwrap();
+        assert_eq!(
+            inverse_variance,
+            InverseVariance {
+                mean: 2.0,
+                std_dev: 0.816496580927726,
+            }
+        );
+    }
+
+    #[test]
+    fn test_inverse_variance_datum_spread() {
+        // A wide interval for the datum widens the limits.
+        let inverse_variance = InverseVariance::new(DATA_THREE, SPREAD_NONE, Some(1.0)).unwrap();
+        assert_eq!(inverse_variance.mean, 2.0);
+        assert_eq!(inverse_variance.std_dev, 1.2909944487358056);
+    }
+
+    #[test]
+    fn test_inverse_variance_weighted() {
+        // The narrow interval of the second datum gives it four times the weight.
+        let inverse_variance =
+            InverseVariance::new(DATA_TWO, &[Some(1.0), Some(0.5)], None).unwrap();
+        assert_eq!(inverse_variance.mean, 2.6);
+        assert_eq!(inverse_variance.std_dev, 0.8);
+    }
+
+    #[test]
+    fn test_inverse_variance_missing_spread() {
+        // A datum without an interval is given the median spread.
+        let inverse_variance =
+            InverseVariance::new(DATA_THREE, &[Some(1.0), None, Some(1.0)], None).unwrap();
+        assert_eq!(inverse_variance.mean, 2.0);
+    }
+
+    #[test]
+    fn test_metric_spread() {
+        assert_eq!(metric_spread(2.0, None, None), None);
+        assert_eq!(metric_spread(2.0, Some(1.0), Some(4.0)), Some(1.5));
+        assert_eq!(metric_spread(2.0, Some(1.0), None), Some(1.0));
+        assert_eq!(metric_spread(2.0, None, Some(4.0)), Some(2.0));
+        assert_eq!(metric_spread(2.0, Some(2.0), Some(2.0)), None);
+        assert_eq!(metric_spread(2.0, Some(4.0), Some(1.0)), None);
+    }
+}
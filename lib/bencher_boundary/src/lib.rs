@@ -1,14 +1,43 @@
+mod bootstrap;
 pub mod boundary;
+mod change_point;
+pub mod correction;
 mod error;
+mod ewma;
+pub mod hysteresis;
+mod inverse_variance;
 pub mod limits;
 mod ln;
 mod mean;
+pub mod p_value;
 mod quartiles;
+pub mod simulation;
+mod two_sample;
 
 pub use boundary::MetricsBoundary;
 pub use error::BoundaryError;
+pub use hysteresis::{BoundaryCheck, Hysteresis};
+pub use inverse_variance::metric_spread;
+pub use p_value::PValue;
 
 #[derive(Debug, Clone)]
 pub struct MetricsData {
     pub data: Vec<f64>,
+    /// The spread of each datum, as reported by its lower and upper values.
+    pub spread: Vec<Option<f64>>,
+}
+
+impl MetricsData {
+    pub fn new<I>(metrics: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, Option<f64>, Option<f64>)>,
+    {
+        let (data, spread) = metrics
+            .into_iter()
+            .map(|(value, lower_value, upper_value)| {
+                (value, metric_spread(value, lower_value, upper_value))
+            })
+            .unzip();
+        Self { data, spread }
+    }
 }
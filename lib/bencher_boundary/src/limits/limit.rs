@@ -1,6 +1,6 @@
-use bencher_json::{Boundary, IqrBoundary, PercentageBoundary};
+use bencher_json::{Boundary, CdfBoundary, IqrBoundary, PercentageBoundary};
 
-use crate::quartiles::Quartiles;
+use crate::{bootstrap::Bootstrap, quartiles::Quartiles};
 
 #[derive(Debug, PartialEq)]
 pub struct MetricsLimit {
@@ -70,6 +70,33 @@ impl MetricsLimit {
     fn delta(median: f64, delta_quartiles: Quartiles, boundary: IqrBoundary) -> f64 {
         median * delta_quartiles.iqr(boundary)
     }
+
+    // The lower limit is the percentile of the resampled statistics that leaves the boundary to its right.
+    pub fn bootstrap_lower(bootstrap: &Bootstrap, boundary: CdfBoundary) -> Option<Self> {
+        bootstrap
+            .percentile(1.0 - f64::from(boundary))
+            .map(|value| Self { value })
+    }
+
+    pub fn bootstrap_upper(bootstrap: &Bootstrap, boundary: CdfBoundary) -> Option<Self> {
+        bootstrap
+            .percentile(boundary.into())
+            .map(|value| Self { value })
+    }
+
+    // Shift the sample median back by the confidence bound for the shift in location,
+    // so that the sample median is only beyond the limit if a shift of zero is outside of the bound.
+    pub fn mann_whitney_lower(sample_median: f64, upper_shift: f64) -> Self {
+        Self {
+            value: sample_median - upper_shift,
+        }
+    }
+
+    pub fn mann_whitney_upper(sample_median: f64, lower_shift: f64) -> Self {
+        Self {
+            value: sample_median - lower_shift,
+        }
+    }
 }
 
 impl From<MetricsLimit> for f64 {
@@ -91,7 +118,15 @@ impl From<Boundary> for MetricsLimit {
 mod test {
     use pretty_assertions::assert_eq;
 
-    use crate::{limits::MetricsLimit, quartiles::Quartiles};
+    use crate::{bootstrap::Bootstrap, limits::MetricsLimit, quartiles::Quartiles};
+
+    fn bootstrap() -> Bootstrap {
+        Bootstrap {
+            baseline: 50.0,
+            sample_statistic: 50.0,
+            statistics: (0..=100).map(f64::from).collect(),
+        }
+    }
 
     #[test]
     fn test_limit_percentage_lower() {
@@ -165,6 +200,32 @@ mod test {
         assert_eq!(limit.value, -1.0);
     }
 
+    #[test]
+    fn test_limit_mann_whitney_lower() {
+        let limit = MetricsLimit::mann_whitney_lower(7.0, 6.0);
+        assert_eq!(limit.value, 1.0);
+    }
+
+    #[test]
+    fn test_limit_mann_whitney_upper() {
+        let limit = MetricsLimit::mann_whitney_upper(7.0, 2.0);
+        assert_eq!(limit.value, 5.0);
+    }
+
+    #[test]
+    fn test_limit_bootstrap_lower() {
+        let boundary = 0.75.try_into().unwrap();
+        let limit = MetricsLimit::bootstrap_lower(&bootstrap(), boundary).unwrap();
+        assert_eq!(limit.value, 25.0);
+    }
+
+    #[test]
+    fn test_limit_bootstrap_upper() {
+        let boundary = 0.75.try_into().unwrap();
+        let limit = MetricsLimit::bootstrap_upper(&bootstrap(), boundary).unwrap();
+        assert_eq!(limit.value, 75.0);
+    }
+
     #[test]
     fn test_limit_delta_iqr_upper() {
         let quartiles = Quartiles {
@@ -4,7 +4,9 @@ use bencher_json::{
 use slog::{debug, Logger};
 use statrs::distribution::{ContinuousCDF, LogNormal, Normal, StudentsT};
 
-use crate::{ln::Ln, quartiles::Quartiles, BoundaryError};
+use crate::{
+    bootstrap::Bootstrap, ln::Ln, quartiles::Quartiles, two_sample::MannWhitney, BoundaryError,
+};
 
 mod limit;
 
@@ -197,6 +199,83 @@ impl MetricsLimits {
         }
     }
 
+    pub fn new_bootstrap(
+        log: &Logger,
+        bootstrap: &Bootstrap,
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Self {
+        if lower_boundary.is_none() && upper_boundary.is_none() {
+            return Self::default();
+        }
+
+        let Bootstrap {
+            baseline,
+            sample_statistic,
+            ..
+        } = *bootstrap;
+        debug!(
+            log,
+            "Bootstrap: baseline={baseline}, sample_statistic={sample_statistic}"
+        );
+        let lower =
+            lower_boundary.and_then(|boundary| MetricsLimit::bootstrap_lower(bootstrap, boundary));
+        let upper =
+            upper_boundary.and_then(|boundary| MetricsLimit::bootstrap_upper(bootstrap, boundary));
+
+        Self {
+            baseline: Some(baseline),
+            lower,
+            upper,
+        }
+    }
+
+    pub fn new_mann_whitney(
+        log: &Logger,
+        mann_whitney: &MannWhitney,
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Result<Self, BoundaryError> {
+        if lower_boundary.is_none() && upper_boundary.is_none() {
+            return Ok(Self::default());
+        }
+
+        let MannWhitney {
+            median,
+            sample_median,
+            ..
+        } = *mann_whitney;
+        debug!(
+            log,
+            "Mann-Whitney U: median={median}, sample_median={sample_median}"
+        );
+        let (mean, std_dev) = (0.0, 1.0);
+        let normal = Normal::new(mean, std_dev).map_err(|error| BoundaryError::Normal {
+            mean,
+            std_dev,
+            error,
+        })?;
+        // Use the upper confidence bound for the shift to find the lower limit and vice versa.
+        let lower = lower_boundary.and_then(|limit| {
+            let z = normal.inverse_cdf(limit.into());
+            mann_whitney.shift_bounds(z).map(|(_, upper_shift)| {
+                MetricsLimit::mann_whitney_lower(sample_median, upper_shift)
+            })
+        });
+        let upper = upper_boundary.and_then(|limit| {
+            let z = normal.inverse_cdf(limit.into());
+            mann_whitney.shift_bounds(z).map(|(lower_shift, _)| {
+                MetricsLimit::mann_whitney_upper(sample_median, lower_shift)
+            })
+        });
+
+        Ok(Self {
+            baseline: Some(median),
+            lower,
+            upper,
+        })
+    }
+
     // An outlier occurs when the  datum exceeds a boundary limit.
     pub fn outlier(&self, datum: f64) -> Option<BoundaryLimit> {
         match (self.lower.as_ref(), self.upper.as_ref()) {
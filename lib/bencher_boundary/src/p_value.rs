@@ -0,0 +1,141 @@
+use bencher_json::Boundary;
+use statrs::distribution::{ContinuousCDF, LogNormal, Normal, StudentsT};
+
+use crate::{limits::NormalTestKind, ln::Ln};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PValue {
+    /// The probability of a value less than or equal to the datum.
+    pub lower: f64,
+    /// The probability of a value greater than or equal to the datum.
+    pub upper: f64,
+}
+
+impl PValue {
+    fn new<D>(distribution: &D, datum: f64) -> Option<Self>
+    where
+        D: ContinuousCDF<f64, f64>,
+    {
+        Self::checked(distribution.cdf(datum), distribution.sf(datum))
+    }
+
+    fn checked(lower: f64, upper: f64) -> Option<Self> {
+        (lower.is_finite() && upper.is_finite()).then_some(Self { lower, upper })
+    }
+
+    pub fn normal(mean: f64, std_dev: f64, test_kind: NormalTestKind, datum: f64) -> Option<Self> {
+        match test_kind {
+            NormalTestKind::Z => Self::new(&Normal::new(mean, std_dev).ok()?, datum),
+            NormalTestKind::T { freedom } => {
+                Self::new(&StudentsT::new(mean, std_dev, freedom).ok()?, datum)
+            },
+        }
+    }
+
+    pub fn log_normal(ln: Ln, datum: f64) -> Option<Self> {
+        let Ln { location, scale } = ln;
+        Self::new(&LogNormal::new(location, scale).ok()?, datum)
+    }
+
+    pub fn standard_normal(z: f64) -> Option<Self> {
+        Self::normal(0.0, 1.0, NormalTestKind::Z, z)
+    }
+
+    // The fraction of the sorted data that is less than or equal to
+    // and greater than or equal to the datum
+    #[allow(clippy::cast_precision_loss)]
+    pub fn empirical(sorted_data: &[f64], datum: f64) -> Option<Self> {
+        if sorted_data.is_empty() {
+            return None;
+        }
+        let len = sorted_data.len() as f64;
+        let less_or_equal = sorted_data.partition_point(|value| *value <= datum) as f64;
+        let less = sorted_data.partition_point(|value| *value < datum) as f64;
+        Self::checked(less_or_equal / len, (len - less) / len)
+    }
+
+    // The smallest ratio of the p-value to the significance level (one minus the boundary)
+    // for each side that has a boundary.
+    // A ratio less than one means that the datum is significant on that side.
+    pub fn significance_ratio(
+        self,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Option<f64> {
+        let ratio = |p_value: f64, boundary: Boundary| {
+            let significance = 1.0 - f64::from(boundary);
+            (significance > 0.0).then(|| p_value / significance)
+        };
+        let lower = lower_boundary.and_then(|boundary| ratio(self.lower, boundary));
+        let upper = upper_boundary.and_then(|boundary| ratio(self.upper, boundary));
+        match (lower, upper) {
+            (Some(lower), Some(upper)) => Some(lower.min(upper)),
+            (Some(ratio), None) | (None, Some(ratio)) => Some(ratio),
+            (None, None) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use bencher_json::Boundary;
+    use pretty_assertions::assert_eq;
+
+    use crate::limits::NormalTestKind;
+
+    use super::PValue;
+
+    #[test]
+    fn test_p_value_normal() {
+        let p_value = PValue::normal(0.0, 1.0, NormalTestKind::Z, 0.0).unwrap();
+        assert_eq!(
+            p_value,
+            PValue {
+                lower: 0.5,
+                upper: 0.5
+            }
+        );
+        let p_value = PValue::normal(0.0, 1.0, NormalTestKind::Z, 3.0).unwrap();
+        assert!(p_value.upper < 0.01, "Unexpected p-value: {p_value:?}");
+        assert!(p_value.lower > 0.99, "Unexpected p-value: {p_value:?}");
+        assert_eq!(PValue::normal(0.0, 0.0, NormalTestKind::Z, 3.0), None);
+    }
+
+    #[test]
+    fn test_p_value_empirical() {
+        let sorted_data = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(PValue::empirical(&[], 1.0), None);
+        assert_eq!(
+            PValue::empirical(&sorted_data, 2.0),
+            Some(PValue {
+                lower: 0.5,
+                upper: 0.75
+            })
+        );
+        assert_eq!(
+            PValue::empirical(&sorted_data, 5.0),
+            Some(PValue {
+                lower: 1.0,
+                upper: 0.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_p_value_significance_ratio() {
+        let p_value = PValue {
+            lower: 0.75,
+            upper: 0.25,
+        };
+        assert_eq!(p_value.significance_ratio(None, None), None);
+        assert_eq!(
+            p_value.significance_ratio(None, Some(Boundary::SEVENTY_FIVE)),
+            Some(1.0)
+        );
+        assert_eq!(
+            p_value.significance_ratio(Some(Boundary::SEVENTY_FIVE), Some(Boundary::FIFTY)),
+            Some(0.5)
+        );
+    }
+}
@@ -62,7 +62,7 @@ impl Quartiles {
         clippy::indexing_slicing
     )]
     // https://doc.rust-lang.org/1.75.0/src/test/stats.rs.html#260
-    fn percentile_of_sorted(sorted_data: &[f64], percentile: f64) -> Option<f64> {
+    pub fn percentile_of_sorted(sorted_data: &[f64], percentile: f64) -> Option<f64> {
         if sorted_data.is_empty() || !(0.0..=1.0).contains(&percentile) {
             None
         } else if sorted_data.len() == 1 {
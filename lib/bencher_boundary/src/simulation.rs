@@ -0,0 +1,345 @@
+use bencher_json::{project::boundary::BoundaryLimit, Model, ModelTest, SampleSize};
+use slog::Logger;
+
+use crate::{
+    metric_spread, BoundaryCheck, BoundaryError, Hysteresis, MetricsBoundary, MetricsData,
+};
+
+// Every metric is replayed against its own history,
+// so when the model does not set a max sample size the history is still capped
+// to keep the total work for a simulation from growing quadratically.
+const SIMULATION_MAX_SAMPLE_SIZE: SampleSize = SampleSize::TWO_FIFTY_FIVE;
+
+/// A stored metric to replay through a threshold model.
+#[derive(Debug, Clone, Copy)]
+pub struct SimulationMetric {
+    /// Consecutive metrics with the same sample are from the same report.
+    pub sample: usize,
+    /// The report start time, in seconds since the Unix epoch.
+    pub start_time: i64,
+    pub value: f64,
+    pub lower_value: Option<f64>,
+    pub upper_value: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SimulatedBoundary {
+    pub baseline: Option<f64>,
+    pub lower_limit: Option<f64>,
+    pub upper_limit: Option<f64>,
+    pub outlier: Option<BoundaryLimit>,
+    /// The side of the boundary that would have generated an alert, if any.
+    pub alert: Option<BoundaryLimit>,
+}
+
+impl From<MetricsBoundary> for SimulatedBoundary {
+    fn from(boundary: MetricsBoundary) -> Self {
+        let MetricsBoundary {
+            limits, outlier, ..
+        } = boundary;
+        Self {
+            baseline: limits.baseline,
+            lower_limit: limits.lower.map(|limit| limit.value),
+            upper_limit: limits.upper.map(|limit| limit.value),
+            outlier,
+            alert: None,
+        }
+    }
+}
+
+// Replay the metrics, ordered from least recent to most recent, through the threshold model.
+// Each metric only has access to the metrics that came before it,
+// just as if it were being checked when its report was first created.
+// If there is a fixed baseline, ordered from least recent to most recent,
+// then each metric is compared against the baseline instead.
+// A boundary is returned for every metric, in the same order.
+#[allow(clippy::indexing_slicing)]
+pub fn simulate(
+    log: &Logger,
+    model: Model,
+    baseline: Option<&[SimulationMetric]>,
+    metrics: &[SimulationMetric],
+) -> Result<Vec<SimulatedBoundary>, BoundaryError> {
+    let hysteresis = Hysteresis::new(model.test, model.violations, model.violation_window);
+    let mut boundaries = Vec::with_capacity(metrics.len());
+    let mut start = 0;
+    while let Some(first) = metrics.get(start) {
+        // Two-sample tests check every metric from the same report at once.
+        let end = if model.test.is_two_sample() {
+            start
+                + metrics[start..]
+                    .iter()
+                    .take_while(|metric| metric.sample == first.sample)
+                    .count()
+        } else {
+            start + 1
+        };
+        let sample = &metrics[start..end];
+        let Some(last) = sample.last() else {
+            break;
+        };
+
+        // The current metric has already been stored by the time it is checked,
+        // so it is part of its own history for all single-sample tests
+        // except change point and EWMA, which compare it against the series that precedes it.
+        let history_end = if model.test.is_two_sample()
+            || matches!(model.test, ModelTest::ChangePoint | ModelTest::Ewma)
+        {
+            start
+        } else {
+            end
+        };
+        let history = baseline.unwrap_or(&metrics[..history_end]);
+        let metrics_data = metrics_data(model, history, last.start_time);
+
+        let boundary = if model.test.is_two_sample() {
+            let values = sample.iter().map(|metric| metric.value).collect::<Vec<_>>();
+            MetricsBoundary::new_sample(
+                log,
+                &values,
+                &metrics_data,
+                model.test,
+                model.min_sample_size,
+                model.smoothing_factor,
+                model.lower_boundary,
+                model.upper_boundary,
+            )?
+        } else {
+            MetricsBoundary::new(
+                log,
+                last.value,
+                metric_spread(last.value, last.lower_value, last.upper_value),
+                &metrics_data,
+                model.test,
+                model.min_sample_size,
+                model.smoothing_factor,
+                model.lower_boundary,
+                model.upper_boundary,
+            )?
+        };
+        let mut boundary = SimulatedBoundary::from(boundary);
+
+        // Every metric in the sample shares the same boundary,
+        // but only the last metric can have an alert.
+        for _ in 1..sample.len() {
+            boundaries.push(boundary);
+        }
+        boundary.alert = boundary.outlier.filter(|&outlier| {
+            hysteresis.map_or(true, |hysteresis| {
+                let history =
+                    metrics[..start]
+                        .iter()
+                        .zip(&boundaries)
+                        .rev()
+                        .map(|(metric, boundary)| BoundaryCheck {
+                            value: metric.value,
+                            lower_limit: boundary.lower_limit,
+                            upper_limit: boundary.upper_limit,
+                        });
+                hysteresis.persists(outlier, history)
+            })
+        });
+        boundaries.push(boundary);
+
+        start = end;
+    }
+    Ok(boundaries)
+}
+
+// The historical data is ordered from most recent to least recent,
+// limited by the model window relative to the current report and by the max sample size.
+fn metrics_data(model: Model, history: &[SimulationMetric], now: i64) -> MetricsData {
+    let start_time = model
+        .window
+        .and_then(|window| now.checked_sub(window.into()));
+    let max_sample_size = usize::from(
+        model
+            .max_sample_size
+            .or_else(|| model.test.default_max_sample_size())
+            .unwrap_or(SIMULATION_MAX_SAMPLE_SIZE),
+    );
+    MetricsData::new(
+        history
+            .iter()
+            .rev()
+            .filter(|metric| start_time.map_or(true, |start_time| metric.start_time >= start_time))
+            .take(max_sample_size)
+            .map(|metric| (metric.value, metric.lower_value, metric.upper_value)),
+    )
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::indexing_slicing, clippy::unwrap_used)]
+mod test {
+    use bencher_json::{project::boundary::BoundaryLimit, Model, ModelTest};
+    use bencher_logger::bootstrap_logger;
+    use pretty_assertions::assert_eq;
+
+    use super::{simulate, SimulationMetric};
+
+    fn metrics(values: &[f64]) -> Vec<SimulationMetric> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, &value)| SimulationMetric {
+                sample: index,
+                start_time: i64::try_from(index).unwrap() * 100,
+                value,
+                lower_value: None,
+                upper_value: None,
+            })
+            .collect()
+    }
+
+    fn model(test: ModelTest, lower_boundary: f64, upper_boundary: f64) -> Model {
+        Model {
+            test,
+            min_sample_size: None,
+            max_sample_size: None,
+            window: None,
+            smoothing_factor: None,
+            violations: None,
+            violation_window: None,
+            start_point_baseline: None,
+            lower_boundary: Some(lower_boundary.try_into().unwrap()),
+            upper_boundary: Some(upper_boundary.try_into().unwrap()),
+        }
+    }
+
+    fn alerts(model: Model, values: &[f64]) -> Vec<Option<BoundaryLimit>> {
+        let log = bootstrap_logger();
+        simulate(&log, model, None, &metrics(values))
+            .unwrap()
+            .into_iter()
+            .map(|boundary| boundary.alert)
+            .collect()
+    }
+
+    #[test]
+    fn test_simulate_empty() {
+        let log = bootstrap_logger();
+        let boundaries = simulate(&log, model(ModelTest::Static, 1.0, 3.0), None, &[]).unwrap();
+        assert_eq!(boundaries, Vec::new());
+    }
+
+    #[test]
+    fn test_simulate_static() {
+        let alerts = alerts(
+            model(ModelTest::Static, 1.0, 3.0),
+            &[2.0, 4.0, 2.0, 4.0, 4.0, 0.0],
+        );
+        assert_eq!(
+            alerts,
+            vec![
+                None,
+                Some(BoundaryLimit::Upper),
+                None,
+                Some(BoundaryLimit::Upper),
+                Some(BoundaryLimit::Upper),
+                Some(BoundaryLimit::Lower),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_simulate_ewma_step() {
+        let mut model = model(ModelTest::Ewma, 0.99, 0.99);
+        model.smoothing_factor = Some(0.5.try_into().unwrap());
+        let alerts = alerts(model, &[10.0, 10.5, 9.5, 10.0, 10.5, 9.5, 10.0, 20.0]);
+        assert_eq!(alerts.last(), Some(&Some(BoundaryLimit::Upper)));
+    }
+
+    #[test]
+    fn test_simulate_violations() {
+        let mut model = model(ModelTest::Static, 1.0, 3.0);
+        model.violations = Some(2.try_into().unwrap());
+        let alerts = alerts(model, &[2.0, 4.0, 2.0, 4.0, 4.0, 0.0]);
+        assert_eq!(
+            alerts,
+            vec![None, None, None, None, Some(BoundaryLimit::Upper), None]
+        );
+    }
+
+    #[test]
+    fn test_simulate_percentage_history() {
+        let log = bootstrap_logger();
+        let data = metrics(&[10.0, 10.0, 10.0, 20.0]);
+
+        let model = model(ModelTest::Percentage, 0.1, 0.1);
+        let boundaries = simulate(&log, model, None, &data).unwrap();
+        assert_eq!(boundaries[0].baseline, Some(10.0));
+        assert_eq!(boundaries[3].baseline, Some(12.5));
+        assert_eq!(boundaries[3].alert, Some(BoundaryLimit::Upper));
+
+        let mut max_sample_size_model = model;
+        max_sample_size_model.max_sample_size = Some(2.try_into().unwrap());
+        let boundaries = simulate(&log, max_sample_size_model, None, &data).unwrap();
+        assert_eq!(boundaries[3].baseline, Some(15.0));
+
+        let mut window_model = model;
+        window_model.window = Some(150.try_into().unwrap());
+        let boundaries = simulate(&log, window_model, None, &data).unwrap();
+        assert_eq!(boundaries[3].baseline, Some(15.0));
+    }
+
+    #[test]
+    fn test_simulate_default_max_sample_size() {
+        let log = bootstrap_logger();
+        // Without a max sample size, the history is still capped at 255 metrics.
+        let values = [vec![1_000.0; 100], vec![10.0; 300]].concat();
+        let data = metrics(&values);
+
+        let model = model(ModelTest::Percentage, 0.1, 0.1);
+        let boundaries = simulate(&log, model, None, &data).unwrap();
+        assert_eq!(boundaries.last().unwrap().baseline, Some(10.0));
+        assert!(boundaries[300].baseline.unwrap() > 10.0);
+    }
+
+    #[test]
+    fn test_simulate_two_sample() {
+        let log = bootstrap_logger();
+        let mut data = metrics(&[10.0, 11.0, 10.0, 11.0, 20.0, 21.0, 20.0]);
+        for metric in &mut data[4..] {
+            metric.sample = 4;
+        }
+
+        let model = model(ModelTest::WelchTTest, 0.95, 0.95);
+        let boundaries = simulate(&log, model, None, &data).unwrap();
+        assert_eq!(boundaries.len(), data.len());
+        // Every metric in the sample shares the same boundary, but only the last one alerts.
+        assert_eq!(boundaries[4].upper_limit, boundaries[6].upper_limit);
+        assert_eq!(boundaries[4].outlier, Some(BoundaryLimit::Upper));
+        assert_eq!(boundaries[4].alert, None);
+        assert_eq!(boundaries[6].alert, Some(BoundaryLimit::Upper));
+    }
+
+    #[test]
+    fn test_simulate_welch_single_iteration() {
+        // Every metric is from its own report with a single iteration.
+        let alerts = alerts(
+            model(ModelTest::WelchTTest, 0.99, 0.99),
+            &[10.0, 10.5, 9.5, 10.0, 10.5, 9.5, 10.0, 20.0],
+        );
+        assert_eq!(alerts.last(), Some(&Some(BoundaryLimit::Upper)));
+    }
+
+    #[test]
+    fn test_simulate_baseline() {
+        let log = bootstrap_logger();
+        let baseline = metrics(&[10.0, 10.0]);
+        let data = metrics(&[20.0, 20.0, 10.0]);
+
+        // Every metric is only compared against the fixed baseline, not the metrics before it.
+        let model = model(ModelTest::Percentage, 0.1, 0.1);
+        let boundaries = simulate(&log, model, Some(&baseline), &data).unwrap();
+        assert_eq!(boundaries[0].baseline, Some(10.0));
+        assert_eq!(boundaries[1].baseline, Some(10.0));
+        assert_eq!(
+            boundaries
+                .iter()
+                .map(|boundary| boundary.alert)
+                .collect::<Vec<_>>(),
+            vec![Some(BoundaryLimit::Upper), Some(BoundaryLimit::Upper), None]
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{mean::mean, quartiles::Quartiles};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Welch {
+    /// The mean of the historical data.
+    pub mean: f64,
+    /// The mean of the new sample.
+    pub sample_mean: f64,
+    /// The standard error of the difference between the two means.
+    pub std_err: f64,
+    /// The Welch–Satterthwaite degrees of freedom.
+    pub freedom: f64,
+}
+
+impl Welch {
+    // https://en.wikipedia.org/wiki/Welch%27s_t-test
+    pub fn new(data: &[f64], sample: &[f64]) -> Option<Self> {
+        let (mean, data_err) = mean_err(data)?;
+        let (sample_mean, sample_err) = mean_err(sample)?;
+
+        let std_err = (data_err + sample_err).sqrt();
+        #[allow(clippy::cast_precision_loss)]
+        let freedom = (data_err + sample_err).powi(2)
+            / (data_err.powi(2) / (data.len() - 1) as f64
+                + sample_err.powi(2) / (sample.len() - 1) as f64);
+
+        (std_err.is_finite() && std_err > 0.0 && freedom.is_finite()).then_some(Self {
+            mean,
+            sample_mean,
+            std_err,
+            freedom,
+        })
+    }
+}
+
+// The mean and the squared standard error of the mean
+fn mean_err(data: &[f64]) -> Option<(f64, f64)> {
+    // Do not calculate the sample variance if there are less than 2 data points
+    if data.len() < 2 {
+        return None;
+    }
+    let mean = mean(data)?;
+    #[allow(clippy::cast_precision_loss)]
+    let len = data.len() as f64;
+    let variance = data.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / (len - 1.0);
+    let err = variance / len;
+    err.is_finite().then_some((mean, err))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MannWhitney {
+    /// The median of the historical data.
+    pub median: f64,
+    /// The median of the new sample.
+    pub sample_median: f64,
+    /// The number of historical data points.
+    pub data_len: usize,
+    /// The number of sample data points.
+    pub sample_len: usize,
+    /// The sorted differences between every sample value and every historical value.
+    pub differences: Vec<f64>,
+}
+
+impl MannWhitney {
+    // https://en.wikipedia.org/wiki/Mann%E2%80%93Whitney_U_test
+    pub fn new(data: &[f64], sample: &[f64]) -> Option<Self> {
+        let median = Quartiles::new(data)?.q2;
+        let sample_median = Quartiles::new(sample)?.q2;
+
+        let mut differences = sample
+            .iter()
+            .flat_map(|y| data.iter().map(move |x| y - x))
+            .collect::<Vec<_>>();
+        if differences.iter().any(|d| !d.is_finite()) {
+            return None;
+        }
+        differences.sort_unstable_by(|l, r| l.partial_cmp(r).unwrap_or(std::cmp::Ordering::Equal));
+
+        Some(Self {
+            median,
+            sample_median,
+            data_len: data.len(),
+            sample_len: sample.len(),
+            differences,
+        })
+    }
+
+    // The confidence bounds for the shift in location between the sample and the historical data,
+    // found by inverting the Mann–Whitney U test with its normal approximation.
+    // If a shift of zero is outside of these bounds, then the test is significant.
+    // If the sample is too small to ever be significant at the given `z` critical value,
+    // then there are no bounds.
+    // https://en.wikipedia.org/wiki/Hodges%E2%80%93Lehmann_estimator
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn shift_bounds(&self, z: f64) -> Option<(f64, f64)> {
+        let n1 = self.data_len as f64;
+        let n2 = self.sample_len as f64;
+        let u_mean = n1 * n2 / 2.0;
+        let u_std_dev = (n1 * n2 * (n1 + n2 + 1.0) / 12.0).sqrt();
+        let rank = (u_mean - z * u_std_dev).floor();
+        if !rank.is_finite() || rank < 0.0 {
+            return None;
+        }
+        let rank = rank as usize;
+        let lower = self.differences.get(rank)?;
+        let upper = self
+            .differences
+            .len()
+            .checked_sub(rank + 1)
+            .and_then(|index| self.differences.get(index))?;
+        Some((*lower, *upper))
+    }
+
+    // The normal approximation of the Mann–Whitney U statistic for the sample,
+    // where ties count as half.
+    // A negative value means that the sample tends to be less than the historical data.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn z_score(&self) -> Option<f64> {
+        let n1 = self.data_len as f64;
+        let n2 = self.sample_len as f64;
+        let u_mean = n1 * n2 / 2.0;
+        let u_std_dev = (n1 * n2 * (n1 + n2 + 1.0) / 12.0).sqrt();
+        let greater = self.differences.len() - self.differences.partition_point(|d| *d <= 0.0);
+        let ties = self.differences.partition_point(|d| *d <= 0.0)
+            - self.differences.partition_point(|d| *d < 0.0);
+        let u = greater as f64 + ties as f64 / 2.0;
+        let z = (u - u_mean) / u_std_dev;
+        z.is_finite().then_some(z)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{MannWhitney, Welch};
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_FIVE: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0];
+    const DATA_FIVE_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+    const DATA_TEN: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    const SAMPLE_THREE: &[f64] = &[6.0, 7.0, 8.0];
+
+    #[test]
+    fn test_welch_zero() {
+        assert_eq!(Welch::new(DATA_ZERO, SAMPLE_THREE), None);
+        assert_eq!(Welch::new(DATA_FIVE, DATA_ZERO), None);
+    }
+
+    #[test]
+    fn test_welch_one() {
+        assert_eq!(Welch::new(DATA_ONE, SAMPLE_THREE), None);
+        assert_eq!(Welch::new(DATA_FIVE, DATA_ONE), None);
+    }
+
+    #[test]
+    fn test_welch_const() {
+        assert_eq!(Welch::new(DATA_FIVE_CONST, DATA_FIVE_CONST), None);
+    }
+
+    #[test]
+    fn test_welch_five() {
+        let welch = Welch::new(DATA_FIVE, SAMPLE_THREE).unwrap();
+        assert_eq!(
+            welch,
+            Welch {
+                mean: 3.0,
+                sample_mean: 7.0,
+                std_err: 0.9128709291752768,
+                freedom: 5.882352941176469,
+            }
+        );
+    }
+
+    #[test]
+    fn test_mann_whitney_zero() {
+        assert_eq!(MannWhitney::new(DATA_ZERO, SAMPLE_THREE), None);
+        assert_eq!(MannWhitney::new(DATA_FIVE, DATA_ZERO), None);
+    }
+
+    #[test]
+    fn test_mann_whitney_ten() {
+        let mann_whitney = MannWhitney::new(DATA_TEN, SAMPLE_THREE).unwrap();
+        assert_eq!(mann_whitney.median, 3.0);
+        assert_eq!(mann_whitney.sample_median, 7.0);
+
+        let (lower, upper) = mann_whitney.shift_bounds(1.0).unwrap();
+        assert_eq!(lower, 3.0);
+        assert_eq!(upper, 5.0);
+        // A shift of zero is well outside of the bounds
+        let (lower, upper) = mann_whitney.shift_bounds(1.6448536269514715).unwrap();
+        assert_eq!(lower, 2.0);
+        assert_eq!(upper, 6.0);
+        // The sample is too small to be that significant
+        assert_eq!(mann_whitney.shift_bounds(10.0), None);
+        // Every sample value is greater than every historical value
+        assert_eq!(mann_whitney.z_score(), Some(2.53546276418555));
+    }
+}
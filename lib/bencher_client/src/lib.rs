@@ -40,8 +40,10 @@ from_client!(
     ResourceName,
     SampleSize,
     Slug,
+    SmoothingFactor,
     Url,
     UserName,
+    Violations,
     Window,
     Index
 );
@@ -127,6 +129,7 @@ try_from_client!(
     JsonTestbed,
     JsonThresholds,
     JsonThreshold,
+    JsonThresholdSimulation,
     JsonSignup,
     JsonLogin,
     JsonConfirm,
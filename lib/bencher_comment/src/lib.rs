@@ -5,8 +5,8 @@ use bencher_json::{
         boundary::BoundaryLimit,
         plot::{LOWER_BOUNDARY, UPPER_BOUNDARY},
     },
-    AlertUuid, BenchmarkName, BenchmarkUuid, BranchUuid, DateTime, JsonBoundary, JsonPerfQuery,
-    JsonReport, MeasureUuid, ResourceName, Slug, TestbedUuid,
+    AlertUuid, BenchmarkName, BenchmarkUuid, BranchUuid, DateTime, GitHash, JsonBoundary,
+    JsonPerfQuery, JsonReport, MeasureUuid, ResourceName, Slug, TestbedUuid,
 };
 use url::Url;
 
@@ -81,7 +81,20 @@ impl ReportComment {
 
     fn html_report_table(&self, html: &mut String, public_links: bool) {
         html.push_str("<table>");
-        for (row, name, path) in [
+        for (row, name, url) in self.report_rows(public_links) {
+            if let Some(url) = url {
+                html.push_str(&format!(
+                    r#"<tr><td>{row}</td><td><a href="{url}">{name}</a></td></tr>"#
+                ));
+            } else {
+                html.push_str(&format!(r#"<tr><td>{row}</td><td>{name}</td></tr>"#));
+            }
+        }
+        html.push_str("</table>");
+    }
+
+    fn report_rows(&self, public_links: bool) -> [(&'static str, String, Option<Url>); 4] {
+        [
             (
                 "Report",
                 self.json_report
@@ -119,18 +132,14 @@ impl ReportComment {
                     self.project_slug, self.json_report.testbed.slug
                 )),
             ),
-        ] {
-            if let Some(path) = path {
+        ]
+        .map(|(row, name, path)| {
+            let url = path.map(|path| {
                 let url = self.console_url.clone();
-                let url = url.join(&path).unwrap_or(url);
-                html.push_str(&format!(
-                    r#"<tr><td>{row}</td><td><a href="{url}">{name}</a></td></tr>"#
-                ));
-            } else {
-                html.push_str(&format!(r#"<tr><td>{row}</td><td>{name}</td></tr>"#));
-            }
-        }
-        html.push_str("</table>");
+                url.join(&path).unwrap_or(url)
+            });
+            (row, name, url)
+        })
     }
 
     fn html_benchmarks(&self, html: &mut String, require_threshold: bool, public_links: bool) {
@@ -409,6 +418,24 @@ impl ReportComment {
         limit: Option<BoundaryLimit>,
         pad: bool,
     ) {
+        for (cell, bold) in Self::metric_boundary_cells(value, boundary, limit, pad) {
+            if bold {
+                html.push_str(&format!("<td><b>{cell}</b></td>"));
+            } else {
+                html.push_str(&format!("<td>{cell}</td>"));
+            }
+        }
+    }
+
+    // Each cell is returned along with whether it should be emphasized
+    fn metric_boundary_cells(
+        value: f64,
+        boundary: Option<Boundary>,
+        limit: Option<BoundaryLimit>,
+        pad: bool,
+    ) -> Vec<(String, bool)> {
+        let mut cells = Vec::new();
+
         // If there is a boundary with a baseline then show the percentage difference
         if let Some(Boundary {
             baseline: Some(baseline),
@@ -422,20 +449,20 @@ impl ReportComment {
             };
             let value_plus = if value_percent > 0.0 { "+" } else { "" };
 
-            let bold = limit.is_some();
-            html.push_str(&format!(
-                "<td>{}{} ({value_plus}{}%){}</td>",
-                if bold { "<b>" } else { "" },
-                format_number(value),
-                format_number(value_percent),
-                if bold { "</b>" } else { "" },
+            cells.push((
+                format!(
+                    "{} ({value_plus}{}%)",
+                    format_number(value),
+                    format_number(value_percent),
+                ),
+                limit.is_some(),
             ));
         } else {
-            html.push_str(&format!("<td>{}</td>", format_number(value)));
+            cells.push((format_number(value), false));
         }
 
         let Some(boundary) = boundary else {
-            return;
+            return cells;
         };
         if let Some(lower_limit) = boundary.lower_limit {
             let limit_percent = if value.is_normal() && lower_limit.is_normal() {
@@ -443,16 +470,16 @@ impl ReportComment {
             } else {
                 0.0
             };
-            let bold = matches!(limit, Some(BoundaryLimit::Lower));
-            html.push_str(&format!(
-                "<td>{}{} ({}%){}</td>",
-                if bold { "<b>" } else { "" },
-                format_number(lower_limit),
-                format_number(limit_percent),
-                if bold { "</b>" } else { "" },
+            cells.push((
+                format!(
+                    "{} ({}%)",
+                    format_number(lower_limit),
+                    format_number(limit_percent),
+                ),
+                matches!(limit, Some(BoundaryLimit::Lower)),
             ));
         } else if pad {
-            html.push_str("<td></td>");
+            cells.push((String::new(), false));
         }
         if let Some(upper_limit) = boundary.upper_limit {
             let limit_percent = if value.is_normal() && upper_limit.is_normal() {
@@ -460,17 +487,19 @@ impl ReportComment {
             } else {
                 0.0
             };
-            let bold = matches!(limit, Some(BoundaryLimit::Upper));
-            html.push_str(&format!(
-                "<td>{}{} ({}%){}</td>",
-                if bold { "<b>" } else { "" },
-                format_number(upper_limit),
-                format_number(limit_percent),
-                if bold { "</b>" } else { "" },
+            cells.push((
+                format!(
+                    "{} ({}%)",
+                    format_number(upper_limit),
+                    format_number(limit_percent),
+                ),
+                matches!(limit, Some(BoundaryLimit::Upper)),
             ));
         } else if pad {
-            html.push_str("<td></td>");
+            cells.push((String::new(), false));
         }
+
+        cells
     }
 
     fn html_footer(&self, html: &mut String) {
@@ -490,9 +519,437 @@ impl ReportComment {
         html.push_str(&self.bencher_tag(id));
     }
 
+    pub fn markdown(&self, require_threshold: bool) -> String {
+        let mut markdown = String::new();
+        let markdown_mut = &mut markdown;
+        let public_links = self.json_report.project.visibility.is_public();
+        self.markdown_header(markdown_mut);
+        self.markdown_report_list(markdown_mut, public_links);
+        self.markdown_benchmarks(markdown_mut, require_threshold, public_links);
+        self.markdown_footer(markdown_mut);
+        markdown
+    }
+
+    fn markdown_header(&self, markdown: &mut String) {
+        markdown.push_str(&format!(
+            "## [🐰 Bencher]({console_url})\n\n",
+            console_url = self.console_url,
+        ));
+    }
+
+    fn markdown_report_list(&self, markdown: &mut String, public_links: bool) {
+        for (row, name, url) in self.report_rows(public_links) {
+            if let Some(url) = url {
+                markdown.push_str(&format!("- **{row}:** [{name}]({url})\n"));
+            } else {
+                markdown.push_str(&format!("- **{row}:** {name}\n"));
+            }
+        }
+    }
+
+    fn markdown_benchmarks(
+        &self,
+        markdown: &mut String,
+        require_threshold: bool,
+        public_links: bool,
+    ) {
+        let Some((_benchmark, measures)) = self.benchmark_urls.0.first_key_value() else {
+            markdown.push_str("\n> ⚠️ **WARNING:** No benchmarks found!\n");
+            return;
+        };
+        self.markdown_no_threshold_warning(markdown, measures);
+
+        let alerts_len = self.alert_urls.0.len();
+        if alerts_len > 0 {
+            let (capital, lower) = if alerts_len == 1 {
+                ("", "")
+            } else {
+                ("S", "s")
+            };
+            markdown.push_str(&format!(
+                "\n> 🚨 **{alerts_len} ALERT{capital}:** Threshold Boundary Limit{lower} exceeded!\n\n",
+            ));
+            self.markdown_alerts_table(markdown, public_links);
+        }
+
+        markdown.push_str("\n### Benchmark Results\n\n");
+        self.markdown_benchmarks_table(markdown, measures, require_threshold, public_links);
+    }
+
+    fn markdown_no_threshold_warning(&self, markdown: &mut String, measures: &MeasuresMap) {
+        let no_threshold = measures
+            .iter()
+            .filter_map(|(measure, MeasureData { boundary, .. })| {
+                boundary.is_none().then_some(measure)
+            })
+            .collect::<Vec<_>>();
+        if no_threshold.is_empty() {
+            return;
+        }
+        let plural_measure = if no_threshold.len() == 1 {
+            "Measure does"
+        } else {
+            "Measures do"
+        };
+        markdown.push_str(&format!("\n> ⚠️ **WARNING:** The following {plural_measure} not have a Threshold. Without a Threshold, no Alerts will ever be generated!\n>\n"));
+        for measure in no_threshold {
+            markdown.push_str(&format!(
+                "> - {name} ({slug})\n",
+                name = measure.name,
+                slug = measure.slug
+            ));
+        }
+        markdown.push_str(&format!(">\n> [Click here to create a new Threshold]({console_url}console/projects/{project}/thresholds/add)\n", console_url = self.console_url, project = self.project_slug));
+        markdown.push_str(">\n> For more information, see [the Threshold documentation](https://bencher.dev/docs/explanation/thresholds/).\n");
+    }
+
+    fn markdown_alerts_table(&self, markdown: &mut String, public_links: bool) {
+        markdown_row(
+            markdown,
+            [
+                "Benchmark",
+                "Measure (units)",
+                "View",
+                "Value",
+                "Lower Boundary",
+                "Upper Boundary",
+            ]
+            .map(ToOwned::to_owned),
+        );
+        markdown_row(markdown, ["---"; 6].map(ToOwned::to_owned));
+        for ((benchmark, measure), alert) in &self.alert_urls.0 {
+            let Some(data) = self
+                .benchmark_urls
+                .0
+                .get(benchmark)
+                .and_then(|m| m.get(measure))
+            else {
+                continue;
+            };
+
+            let mut cells = Vec::new();
+            let benchmark_name = markdown_escape(benchmark.name.as_ref());
+            let measure_name = markdown_escape(&format!(
+                "{name} ({units})",
+                name = measure.name,
+                units = measure.units
+            ));
+            if public_links {
+                cells.push(benchmark_name);
+                cells.push(measure_name);
+                cells.push(format!(
+                    r"🚨 ([view plot]({}) \| [view alert]({}))",
+                    data.public_url, alert.public_url,
+                ));
+            } else {
+                let benchmark_path = format!(
+                    "/console/projects/{}/benchmarks/{}",
+                    self.project_slug, benchmark.slug
+                );
+                let url = self.console_url.clone();
+                let url = url.join(&benchmark_path).unwrap_or(url);
+                cells.push(format!("[{benchmark_name}]({url})"));
+                let measure_path = format!(
+                    "/console/projects/{}/measures/{}",
+                    self.project_slug, measure.slug
+                );
+                let url = self.console_url.clone();
+                let url = url.join(&measure_path).unwrap_or(url);
+                cells.push(format!("[{measure_name}]({url})"));
+                cells.push(format!(
+                    r"🚨 ([view plot]({}) \| [view alert]({}))",
+                    data.console_url, alert.console_url,
+                ));
+            }
+            markdown_metric_boundary_cells(
+                &mut cells,
+                data.value,
+                data.boundary,
+                Some(alert.limit),
+                true,
+            );
+            markdown_row(markdown, cells);
+        }
+    }
+
+    fn markdown_benchmarks_table(
+        &self,
+        markdown: &mut String,
+        measures: &MeasuresMap,
+        require_threshold: bool,
+        public_links: bool,
+    ) {
+        let mut header = vec!["Benchmark".to_owned()];
+        for (measure, MeasureData { boundary, .. }) in measures {
+            if require_threshold && boundary.is_none() {
+                continue;
+            }
+            let measure_name = markdown_escape(measure.name.as_ref());
+            if public_links {
+                header.push(measure_name.clone());
+            } else {
+                let measure_path = format!(
+                    "/console/projects/{}/measures/{}",
+                    self.project_slug, measure.slug
+                );
+                let url = self.console_url.clone();
+                let url = url.join(&measure_path).unwrap_or(url);
+                header.push(format!("[{measure_name}]({url})"));
+            }
+            let units = markdown_escape(measure.units.as_ref());
+            // If there is a boundary then we will show the percentage difference
+            if boundary.is_some() {
+                header.push(format!(r"{measure_name} Results<br/>{units} \| (Δ%)"));
+            } else {
+                header.push(format!("{measure_name} Results<br/>{units}"));
+            }
+            let Some(boundary) = boundary else {
+                continue;
+            };
+            if boundary.lower_limit.is_some() {
+                header.push(format!(r"{measure_name} Lower Boundary<br/>{units} \| (%)"));
+            }
+            if boundary.upper_limit.is_some() {
+                header.push(format!(r"{measure_name} Upper Boundary<br/>{units} \| (%)"));
+            }
+        }
+        let columns = header.len();
+        markdown_row(markdown, header);
+        markdown_row(markdown, vec!["---".to_owned(); columns]);
+
+        for (benchmark, measures) in &self.benchmark_urls.0 {
+            let mut cells = Vec::new();
+            let benchmark_name = markdown_escape(benchmark.name.as_ref());
+            if public_links {
+                cells.push(benchmark_name);
+            } else {
+                let benchmark_path = format!(
+                    "/console/projects/{}/benchmarks/{}",
+                    self.project_slug, benchmark.slug
+                );
+                let url = self.console_url.clone();
+                let url = url.join(&benchmark_path).unwrap_or(url);
+                cells.push(format!("[{benchmark_name}]({url})"));
+            }
+            for (
+                measure,
+                MeasureData {
+                    public_url,
+                    console_url,
+                    value,
+                    boundary,
+                },
+            ) in measures
+            {
+                if require_threshold && boundary.is_none() {
+                    continue;
+                }
+                let plot_url = if public_links {
+                    public_url
+                } else {
+                    console_url
+                };
+                let alert = self.alert_urls.0.get(&(benchmark.clone(), measure.clone()));
+                if let Some(alert) = alert {
+                    let alert_url = if public_links {
+                        &alert.public_url
+                    } else {
+                        &alert.console_url
+                    };
+                    cells.push(format!(
+                        r"🚨 ([view plot]({plot_url}) \| [view alert]({alert_url}))"
+                    ));
+                } else if boundary.is_some() {
+                    cells.push(format!("✅ ([view plot]({plot_url}))"));
+                } else {
+                    cells.push(format!("➖ ([view plot]({plot_url}))"));
+                }
+                markdown_metric_boundary_cells(
+                    &mut cells,
+                    *value,
+                    *boundary,
+                    alert.map(|alert| alert.limit),
+                    false,
+                );
+            }
+            markdown_row(markdown, cells);
+        }
+    }
+
+    fn markdown_footer(&self, markdown: &mut String) {
+        markdown.push_str("\n---\n\n[Bencher - Continuous Benchmarking](https://bencher.dev)");
+        if self.json_report.project.visibility.is_public() {
+            let path = format!("/perf/{}", self.project_slug);
+            let url = self.console_url.clone();
+            let url = url.join(&path).unwrap_or(url);
+            markdown.push_str(&format!(" | [View Public Perf Page]({url})"));
+        }
+        markdown.push_str("\n\n[Docs](https://bencher.dev/docs/) | [Repo](https://bencher.dev/repo/) | [Chat](https://bencher.dev/chat/) | [Help](https://bencher.dev/help/)\n");
+    }
+
+    // An annotation for each alert, naming the benchmark, measure, boundary limit, and value
+    pub fn alert_annotations(&self) -> Vec<AlertAnnotation> {
+        let public_links = self.json_report.project.visibility.is_public();
+        self.alert_urls
+            .0
+            .iter()
+            .filter_map(|((benchmark, measure), alert)| {
+                let data = self
+                    .benchmark_urls
+                    .0
+                    .get(benchmark)
+                    .and_then(|m| m.get(measure))?;
+                Some(AlertAnnotation {
+                    title: format!(
+                        "Bencher Alert: {benchmark} ({measure})",
+                        benchmark = benchmark.name,
+                        measure = measure.name
+                    ),
+                    message: Self::alert_message(benchmark, measure, data, alert, public_links),
+                })
+            })
+            .collect()
+    }
+
+    fn alert_message(
+        benchmark: &Benchmark,
+        measure: &Measure,
+        data: &MeasureData,
+        alert: &AlertData,
+        public_links: bool,
+    ) -> String {
+        let (limit, limit_value) = match alert.limit {
+            BoundaryLimit::Lower => ("Lower", data.boundary.and_then(|b| b.lower_limit)),
+            BoundaryLimit::Upper => ("Upper", data.boundary.and_then(|b| b.upper_limit)),
+        };
+        let units = &measure.units;
+        let limit_value = limit_value
+            .map(|limit_value| format!(" of {} {units}", format_number(limit_value)))
+            .unwrap_or_default();
+        let alert_url = if public_links {
+            &alert.public_url
+        } else {
+            &alert.console_url
+        };
+        format!(
+            "{benchmark} ({measure}) value of {value} {units} exceeded the {limit} Boundary Limit{limit_value}\nView alert: {alert_url}",
+            benchmark = benchmark.name,
+            measure = measure.name,
+            value = format_number(data.value),
+        )
+    }
+
+    // Each Benchmark and Measure is a test case, and each Alert is a failure
+    pub fn junit(&self) -> String {
+        let public_links = self.json_report.project.visibility.is_public();
+        let tests = self
+            .benchmark_urls
+            .0
+            .values()
+            .map(BTreeMap::len)
+            .sum::<usize>();
+        let failures = self.alert_urls.0.len();
+        let timestamp = self
+            .json_report
+            .start_time
+            .into_inner()
+            .format("%Y-%m-%dT%H:%M:%S");
+
+        let mut junit = String::new();
+        junit.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        junit.push('\n');
+        junit.push_str(&format!(
+            r#"<testsuites name="Bencher" tests="{tests}" failures="{failures}">"#
+        ));
+        junit.push('\n');
+        junit.push_str(&format!(
+            r#"  <testsuite name="{name}" tests="{tests}" failures="{failures}" errors="0" skipped="0" timestamp="{timestamp}">"#,
+            name = xml_escape(&format!(
+                "{project}/{branch}/{testbed}",
+                project = self.json_report.project.slug,
+                branch = self.json_report.branch.slug,
+                testbed = self.json_report.testbed.slug
+            )),
+        ));
+        junit.push('\n');
+        for (benchmark, measures) in &self.benchmark_urls.0 {
+            for (measure, data) in measures {
+                junit.push_str(&format!(
+                    r#"    <testcase classname="{classname}" name="{name}">"#,
+                    classname = xml_escape(benchmark.name.as_ref()),
+                    name = xml_escape(&format!(
+                        "{name} ({units})",
+                        name = measure.name,
+                        units = measure.units
+                    )),
+                ));
+                junit.push('\n');
+                if let Some(alert) = self.alert_urls.0.get(&(benchmark.clone(), measure.clone())) {
+                    let limit = match alert.limit {
+                        BoundaryLimit::Lower => "LowerBoundaryLimit",
+                        BoundaryLimit::Upper => "UpperBoundaryLimit",
+                    };
+                    let message =
+                        Self::alert_message(benchmark, measure, data, alert, public_links);
+                    junit.push_str(&format!(
+                        r#"      <failure message="{message}" type="{limit}">{message}</failure>"#,
+                        message = xml_escape(&message),
+                    ));
+                    junit.push('\n');
+                }
+                junit.push_str(&format!(
+                    "      <system-out>{}</system-out>\n",
+                    xml_escape(&Self::junit_system_out(measure, data, public_links))
+                ));
+                junit.push_str("    </testcase>\n");
+            }
+        }
+        junit.push_str("  </testsuite>\n");
+        junit.push_str("</testsuites>\n");
+        junit
+    }
+
+    fn junit_system_out(measure: &Measure, data: &MeasureData, public_links: bool) -> String {
+        let units = &measure.units;
+        let mut system_out = format!("Value: {} {units}", format_number(data.value));
+        if let Some(boundary) = data.boundary {
+            if let Some(baseline) = boundary.baseline {
+                system_out.push_str(&format!("\nBaseline: {} {units}", format_number(baseline)));
+            }
+            if let Some(lower_limit) = boundary.lower_limit {
+                system_out.push_str(&format!(
+                    "\nLower Boundary: {} {units}",
+                    format_number(lower_limit)
+                ));
+            }
+            if let Some(upper_limit) = boundary.upper_limit {
+                system_out.push_str(&format!(
+                    "\nUpper Boundary: {} {units}",
+                    format_number(upper_limit)
+                ));
+            }
+        }
+        let plot_url = if public_links {
+            &data.public_url
+        } else {
+            &data.console_url
+        };
+        system_out.push_str(&format!("\nView plot: {plot_url}"));
+        system_out
+    }
+
     // The Bencher tag allows us to easily check whether a comment is a Bencher report when updating
     pub fn bencher_tag(&self, id: Option<&str>) -> String {
-        let id = id.map_or_else(
+        format!(
+            r#"<div id="bencher.dev/projects/{project}/id/{id}"></div>"#,
+            project = self.json_report.project.slug,
+            id = self.bencher_id(id),
+        )
+    }
+
+    // The Bencher ID segments out results by the combination of: Branch, Testbed, and Adapter
+    pub fn bencher_id(&self, id: Option<&str>) -> String {
+        id.map_or_else(
             || {
                 format!(
                     "{branch}/{testbed}/{adapter}",
@@ -502,13 +959,13 @@ impl ReportComment {
                 )
             },
             ToString::to_string,
-        );
-        format!(
-            r#"<div id="bencher.dev/projects/{project}/id/{id}"></div>"#,
-            project = self.json_report.project.slug,
         )
     }
 
+    pub fn hash(&self) -> Option<&GitHash> {
+        self.json_report.branch.version.hash.as_ref()
+    }
+
     pub fn has_threshold(&self) -> bool {
         self.benchmark_urls.has_threshold()
     }
@@ -516,6 +973,72 @@ impl ReportComment {
     pub fn has_alert(&self) -> bool {
         !self.json_report.alerts.is_empty()
     }
+
+    pub fn alerts_count(&self) -> usize {
+        self.json_report.alerts.len()
+    }
+}
+
+pub struct AlertAnnotation {
+    pub title: String,
+    pub message: String,
+}
+
+fn markdown_row<I>(markdown: &mut String, cells: I)
+where
+    I: IntoIterator<Item = String>,
+{
+    markdown.push('|');
+    for cell in cells {
+        markdown.push_str(&format!(" {cell} |"));
+    }
+    markdown.push('\n');
+}
+
+fn markdown_metric_boundary_cells(
+    cells: &mut Vec<String>,
+    value: f64,
+    boundary: Option<Boundary>,
+    limit: Option<BoundaryLimit>,
+    pad: bool,
+) {
+    for (cell, bold) in ReportComment::metric_boundary_cells(value, boundary, limit, pad) {
+        if bold {
+            cells.push(format!("**{cell}**"));
+        } else {
+            cells.push(cell);
+        }
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // https://www.w3.org/TR/xml/#charsets
+            '\t'
+            | '\n'
+            | '\r'
+            | '\u{20}'..='\u{D7FF}'
+            | '\u{E000}'..='\u{FFFD}'
+            | '\u{10000}'.. => {
+                escaped.push(c);
+            },
+            // Any other character is not allowed in XML 1.0, even as a character reference.
+            _ => {},
+        }
+    }
+    escaped
+}
+
+// Pipes would otherwise split a table cell
+fn markdown_escape(cell: &str) -> String {
+    cell.replace('|', r"\|")
 }
 
 pub struct BenchmarkUrls(BTreeMap<Benchmark, MeasuresMap>);
@@ -800,3 +1323,350 @@ fn format_number(number: f64) -> String {
     }
     number_str.chars().rev().collect()
 }
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{xml_escape, ReportComment};
+
+    const PROJECT: &str = "f4c1b0a6-4c43-4b8b-9b1b-0e2f5b3f2d1e";
+    const THRESHOLD: &str = "9d3e2f1a-6b5c-4d8e-a7f0-1c2b3a4d5e6f";
+    const MEASURE: &str = r#"{
+        "uuid": "3c7a9e1b-5d2f-4b6a-8c0e-9f1d3b5a7c2e",
+        "project": "f4c1b0a6-4c43-4b8b-9b1b-0e2f5b3f2d1e",
+        "name": "Latency",
+        "slug": "latency",
+        "units": "nanoseconds (ns)",
+        "created": "2024-01-01T00:00:00Z",
+        "modified": "2024-01-01T00:00:00Z"
+    }"#;
+    const MODEL: &str = r#"{
+        "uuid": "7b1e3d5f-2a4c-4e6b-9d8f-0a2c4e6b8d1f",
+        "threshold": "9d3e2f1a-6b5c-4d8e-a7f0-1c2b3a4d5e6f",
+        "test": "t_test",
+        "min_sample_size": null,
+        "max_sample_size": null,
+        "window": null,
+        "smoothing_factor": null,
+        "violations": null,
+        "violation_window": null,
+        "start_point_baseline": null,
+        "lower_boundary": null,
+        "upper_boundary": 0.99,
+        "created": "2024-01-01T00:00:00Z",
+        "replaced": null
+    }"#;
+
+    fn benchmark(uuid: &str, name: &str, value: f64, boundary: bool) -> String {
+        let slug = name.replace('_', "-");
+        let boundary = if boundary {
+            r#"{
+                "baseline": 100.0,
+                "lower_limit": null,
+                "upper_limit": 150.0,
+                "adjusted_lower_boundary": null,
+                "adjusted_upper_boundary": null
+            }"#
+        } else {
+            "null"
+        };
+        format!(
+            r#"{{
+                "uuid": "{uuid}",
+                "project": "{PROJECT}",
+                "name": "{name}",
+                "slug": "{slug}",
+                "metric": {{ "value": {value}, "lower_value": null, "upper_value": null }},
+                "boundary": {boundary},
+                "created": "2024-01-01T00:00:00Z",
+                "modified": "2024-01-01T00:00:00Z"
+            }}"#
+        )
+    }
+
+    fn alert_json(benchmark: &str) -> String {
+        format!(
+            r#"{{
+            "uuid": "4e8a2c6d-0b3f-4d7a-9e1c-5f8b2d6a0c3e",
+            "report": "2b4a0e4c-2ab5-4a3f-9b43-0bd8d3ddc8cb",
+            "iteration": 0,
+            "threshold": {{
+                "uuid": "{THRESHOLD}",
+                "project": "{PROJECT}",
+                "branch": {{
+                    "uuid": "0a8e9c2b-7d5f-4e3a-b1c6-2f4d8e6a9b0c",
+                    "project": "{PROJECT}",
+                    "name": "main",
+                    "slug": "main",
+                    "start_point": null,
+                    "created": "2024-01-01T00:00:00Z",
+                    "modified": "2024-01-01T00:00:00Z"
+                }},
+                "testbed": {{
+                    "uuid": "5e2d7b1a-9c4f-4a6e-8b3d-1f0c2e4a6b8d",
+                    "project": "{PROJECT}",
+                    "name": "localhost",
+                    "slug": "localhost",
+                    "created": "2024-01-01T00:00:00Z",
+                    "modified": "2024-01-01T00:00:00Z"
+                }},
+                "measure": {MEASURE},
+                "statistic": null,
+                "model": {MODEL},
+                "created": "2024-01-01T00:00:00Z",
+                "modified": "2024-01-01T00:00:00Z"
+            }},
+            "benchmark": {benchmark},
+            "limit": "upper",
+            "status": "active",
+            "resolved_report": null,
+            "created": "2024-01-01T00:01:00Z",
+            "modified": "2024-01-01T00:01:00Z"
+        }}"#
+        )
+    }
+
+    fn report_comment(threshold: bool, alert: bool) -> ReportComment {
+        let bench_a = benchmark(
+            "1a2b3c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d",
+            "bench_a",
+            200.0,
+            threshold,
+        );
+        let bench_b = benchmark(
+            "6f5e4d3c-2b1a-4f9e-8d7c-6b5a4f3e2d1c",
+            "bench_b",
+            1234.5,
+            threshold,
+        );
+        let threshold_model = if threshold {
+            format!(
+                r#"{{
+                    "uuid": "{THRESHOLD}",
+                    "project": "{PROJECT}",
+                    "statistic": null,
+                    "model": {MODEL},
+                    "created": "2024-01-01T00:00:00Z"
+                }}"#
+            )
+        } else {
+            "null".to_owned()
+        };
+        let alerts = if alert {
+            format!("[{}]", alert_json(&bench_a))
+        } else {
+            "[]".to_owned()
+        };
+        let report = format!(
+            r#"{{
+                "uuid": "2b4a0e4c-2ab5-4a3f-9b43-0bd8d3ddc8cb",
+                "user": {{
+                    "uuid": "6c1b4a1e-bd9a-4c1e-a0f9-0f6e1a48b3a2",
+                    "name": "Muriel Bagge",
+                    "slug": "muriel-bagge",
+                    "email": "muriel.bagge@nowhere.com",
+                    "admin": false,
+                    "locked": false
+                }},
+                "project": {{
+                    "uuid": "{PROJECT}",
+                    "organization": "a1f1d3c5-0e8b-4f4e-8c4f-3b5d9e2f1a7c",
+                    "name": "The Computer",
+                    "slug": "the-computer",
+                    "url": null,
+                    "visibility": "public",
+                    "created": "2024-01-01T00:00:00Z",
+                    "modified": "2024-01-01T00:00:00Z"
+                }},
+                "branch": {{
+                    "uuid": "0a8e9c2b-7d5f-4e3a-b1c6-2f4d8e6a9b0c",
+                    "project": "{PROJECT}",
+                    "name": "main",
+                    "slug": "main",
+                    "version": {{
+                        "number": 1,
+                        "hash": "1234567890abcdef1234567890abcdef12345678"
+                    }},
+                    "start_point": null,
+                    "created": "2024-01-01T00:00:00Z",
+                    "modified": "2024-01-01T00:00:00Z"
+                }},
+                "testbed": {{
+                    "uuid": "5e2d7b1a-9c4f-4a6e-8b3d-1f0c2e4a6b8d",
+                    "project": "{PROJECT}",
+                    "name": "localhost",
+                    "slug": "localhost",
+                    "created": "2024-01-01T00:00:00Z",
+                    "modified": "2024-01-01T00:00:00Z"
+                }},
+                "start_time": "2024-01-01T00:00:00Z",
+                "end_time": "2024-01-01T00:01:00Z",
+                "adapter": "magic",
+                "results": [[{{
+                    "iteration": 0,
+                    "measure": {MEASURE},
+                    "threshold": {threshold_model},
+                    "benchmarks": [{bench_a}, {bench_b}]
+                }}]],
+                "alerts": {alerts},
+                "created": "2024-01-01T00:01:00Z"
+            }}"#
+        );
+        let console_url = "https://bencher.dev".parse().unwrap();
+        ReportComment::new(console_url, serde_json::from_str(&report).unwrap())
+    }
+
+    const ALERT_URL: &str =
+        "https://bencher.dev/perf/the-computer/alerts/4e8a2c6d-0b3f-4d7a-9e1c-5f8b2d6a0c3e";
+
+    #[test]
+    fn test_markdown_alert() {
+        let markdown = report_comment(true, true).markdown(false);
+        let lines = markdown.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "## [🐰 Bencher](https://bencher.dev/)");
+        assert!(
+            lines.contains(&"- **Project:** [The Computer](https://bencher.dev/perf/the-computer)")
+        );
+        assert!(lines.contains(&"- **Branch:** main"));
+        assert!(lines.contains(&"- **Testbed:** localhost"));
+        assert!(lines.contains(&"> 🚨 **1 ALERT:** Threshold Boundary Limit exceeded!"));
+        assert!(!markdown.contains("WARNING"));
+
+        // The Alerts table
+        let alert_row = lines
+            .iter()
+            .find(|line| line.starts_with("| bench_a | Latency (nanoseconds (ns)) |"))
+            .unwrap();
+        assert!(alert_row.contains(&format!("[view alert]({ALERT_URL})")));
+        assert!(alert_row.ends_with("| **200.00 (+100.00%)** |  | **150.00 (133.33%)** |"));
+
+        // The Benchmark Results table
+        assert!(lines.contains(&"### Benchmark Results"));
+        assert!(lines.contains(&"| Benchmark | Latency | Latency Results<br/>nanoseconds (ns) \\| (Δ%) | Latency Upper Boundary<br/>nanoseconds (ns) \\| (%) |"));
+        let bench_a = lines
+            .iter()
+            .find(|line| line.starts_with("| bench_a | 🚨 "))
+            .unwrap();
+        assert!(bench_a.ends_with("| **200.00 (+100.00%)** | **150.00 (133.33%)** |"));
+        let bench_b = lines
+            .iter()
+            .find(|line| line.starts_with("| bench_b | ✅ "))
+            .unwrap();
+        assert!(!bench_b.contains("view alert"));
+        assert!(bench_b.ends_with("| 1,234.50 (+1,134.50%) | 150.00 (823.00%) |"));
+    }
+
+    #[test]
+    fn test_markdown_no_threshold() {
+        let report_comment = report_comment(false, false);
+
+        let markdown = report_comment.markdown(false);
+        let lines = markdown.lines().collect::<Vec<_>>();
+        assert!(lines.contains(&"> ⚠️ **WARNING:** The following Measure does not have a Threshold. Without a Threshold, no Alerts will ever be generated!"));
+        assert!(lines.contains(&"> - Latency (latency)"));
+        assert!(!markdown.contains("ALERT"));
+        assert!(lines.contains(&"| Benchmark | Latency | Latency Results<br/>nanoseconds (ns) |"));
+        let bench_a = lines
+            .iter()
+            .find(|line| line.starts_with("| bench_a | ➖ "))
+            .unwrap();
+        assert!(bench_a.ends_with("| 200.00 |"));
+        let bench_b = lines
+            .iter()
+            .find(|line| line.starts_with("| bench_b | ➖ "))
+            .unwrap();
+        assert!(bench_b.ends_with("| 1,234.50 |"));
+
+        // Measures without a Threshold are left out when one is required.
+        let markdown = report_comment.markdown(true);
+        assert!(markdown.lines().any(|line| line == "| Benchmark |"));
+        assert!(!markdown.contains("Latency Results"));
+    }
+
+    #[test]
+    fn test_alert_annotations() {
+        let annotations = report_comment(true, true).alert_annotations();
+        assert_eq!(annotations.len(), 1);
+        let annotation = &annotations[0];
+        assert_eq!(annotation.title, "Bencher Alert: bench_a (Latency)");
+        assert_eq!(
+            annotation.message,
+            format!("bench_a (Latency) value of 200.00 nanoseconds (ns) exceeded the Upper Boundary Limit of 150.00 nanoseconds (ns)\nView alert: {ALERT_URL}")
+        );
+
+        assert!(report_comment(true, false).alert_annotations().is_empty());
+        assert!(report_comment(false, false).alert_annotations().is_empty());
+    }
+
+    #[test]
+    fn test_junit() {
+        let junit = report_comment(true, true).junit();
+        let lines = junit.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        assert_eq!(
+            lines[1],
+            r#"<testsuites name="Bencher" tests="2" failures="1">"#
+        );
+        assert_eq!(
+            lines[2],
+            r#"  <testsuite name="the-computer/main/localhost" tests="2" failures="1" errors="0" skipped="0" timestamp="2024-01-01T00:00:00">"#
+        );
+        assert_eq!(
+            lines[3],
+            r#"    <testcase classname="bench_a" name="Latency (nanoseconds (ns))">"#
+        );
+        assert!(lines[4].starts_with(r#"      <failure message="bench_a (Latency) value of 200.00 nanoseconds (ns) exceeded the Upper Boundary Limit of 150.00 nanoseconds (ns)"#));
+        assert_eq!(
+            lines[5],
+            format!(
+                r#"View alert: {ALERT_URL}" type="UpperBoundaryLimit">bench_a (Latency) value of 200.00 nanoseconds (ns) exceeded the Upper Boundary Limit of 150.00 nanoseconds (ns)"#
+            )
+        );
+        assert_eq!(lines[6], format!("View alert: {ALERT_URL}</failure>"));
+        assert_eq!(lines[7], "      <system-out>Value: 200.00 nanoseconds (ns)");
+        assert_eq!(lines[8], "Baseline: 100.00 nanoseconds (ns)");
+        assert_eq!(lines[9], "Upper Boundary: 150.00 nanoseconds (ns)");
+        // The query string separators are escaped.
+        assert!(lines[10].starts_with("View plot: https://bencher.dev/perf/the-computer?branches=0a8e9c2b-7d5f-4e3a-b1c6-2f4d8e6a9b0c&amp;testbeds="));
+        assert!(lines[10].ends_with("&amp;upper_boundary=true</system-out>"));
+        assert_eq!(lines[11], "    </testcase>");
+        assert_eq!(
+            lines[12],
+            r#"    <testcase classname="bench_b" name="Latency (nanoseconds (ns))">"#
+        );
+        assert_eq!(
+            lines[13],
+            "      <system-out>Value: 1,234.50 nanoseconds (ns)"
+        );
+        assert_eq!(lines[17], "    </testcase>");
+        assert_eq!(lines[18], "  </testsuite>");
+        assert_eq!(lines[19], "</testsuites>");
+        assert_eq!(lines.len(), 20);
+    }
+
+    #[test]
+    fn test_junit_no_alerts() {
+        let junit = report_comment(false, false).junit();
+        assert!(junit.contains(r#"<testsuites name="Bencher" tests="2" failures="0">"#));
+        assert!(!junit.contains("<failure"));
+        assert!(junit.contains("<system-out>Value: 200.00 nanoseconds (ns)\nView plot: "));
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(xml_escape("bench_a"), "bench_a");
+        assert_eq!(
+            xml_escape(r#"<a href="x?y=1&z='2'">"#),
+            "&lt;a href=&quot;x?y=1&amp;z=&apos;2&apos;&quot;&gt;"
+        );
+        // Whitespace is allowed.
+        assert_eq!(xml_escape("a\tb\nc\rd"), "a\tb\nc\rd");
+        // Other control characters are not allowed in XML 1.0.
+        assert_eq!(xml_escape("a\u{0}b\u{8}c\u{1B}[0md\u{1F}"), "abc[0md");
+        assert_eq!(xml_escape("\u{FFFE}\u{FFFF}"), "");
+        // Everything else is allowed, including characters outside of the BMP.
+        assert_eq!(xml_escape("Δ% 🐰 \u{7F}"), "Δ% 🐰 \u{7F}");
+    }
+}
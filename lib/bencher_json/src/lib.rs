@@ -1,8 +1,8 @@
 pub use bencher_valid::{
     BenchmarkName, Boundary, BranchName, CdfBoundary, DateTime, DateTimeMillis, Email, GitHash,
     Index, IqrBoundary, Jwt, Model, ModelTest, NameId, NameIdKind, NonEmpty, PercentageBoundary,
-    ResourceId, ResourceIdKind, ResourceName, SampleSize, Sanitize, Secret, Slug, Url, UserName,
-    ValidError, Window,
+    ResourceId, ResourceIdKind, ResourceName, SampleSize, Sanitize, Secret, Slug, SmoothingFactor,
+    Url, UserName, ValidError, Violations, Window,
 };
 #[cfg(feature = "plus")]
 pub use bencher_valid::{
@@ -44,7 +44,9 @@ pub use project::{
     plot::{JsonNewPlot, JsonPlot, JsonPlots, PlotUuid},
     report::{JsonNewReport, JsonReport, JsonReports, ReportUuid},
     testbed::{JsonNewTestbed, JsonTestbed, JsonTestbeds, TestbedUuid},
-    threshold::{JsonNewThreshold, JsonThreshold, JsonThresholds, ThresholdUuid},
+    threshold::{
+        JsonNewThreshold, JsonThreshold, JsonThresholdSimulation, JsonThresholds, ThresholdUuid,
+    },
     JsonNewProject, JsonProject, JsonProjects, ProjectUuid,
 };
 #[cfg(feature = "plus")]
@@ -27,12 +27,15 @@ pub struct JsonAlert {
     pub benchmark: JsonBenchmarkMetric,
     pub limit: BoundaryLimit,
     pub status: AlertStatus,
+    /// The report in which the benchmark returned within its boundary, if resolved.
+    pub resolved_report: Option<ReportUuid>,
     pub created: DateTime,
     pub modified: DateTime,
 }
 
 const ACTIVE_INT: i32 = 0;
 const DISMISSED_INT: i32 = 1;
+const RESOLVED_INT: i32 = 2;
 
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Copy, Default, derive_more::Display, Serialize, Deserialize)]
@@ -45,11 +48,12 @@ pub enum AlertStatus {
     #[default]
     Active = ACTIVE_INT,
     Dismissed = DISMISSED_INT,
+    Resolved = RESOLVED_INT,
 }
 
 #[cfg(feature = "db")]
 mod alert_status {
-    use super::{AlertStatus, ACTIVE_INT, DISMISSED_INT};
+    use super::{AlertStatus, ACTIVE_INT, DISMISSED_INT, RESOLVED_INT};
 
     #[derive(Debug, thiserror::Error)]
     pub enum AlertStatusError {
@@ -69,6 +73,7 @@ mod alert_status {
             match self {
                 Self::Active => ACTIVE_INT.to_sql(out),
                 Self::Dismissed => DISMISSED_INT.to_sql(out),
+                Self::Resolved => RESOLVED_INT.to_sql(out),
             }
         }
     }
@@ -82,6 +87,7 @@ mod alert_status {
             match i32::from_sql(bytes)? {
                 ACTIVE_INT => Ok(Self::Active),
                 DISMISSED_INT => Ok(Self::Dismissed),
+                RESOLVED_INT => Ok(Self::Resolved),
                 value => Err(Box::new(AlertStatusError::Invalid(value))),
             }
         }
@@ -100,6 +106,7 @@ pub struct JsonAlertStats {
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonUpdateAlert {
     /// The new status of the alert.
+    /// An alert can only be resolved by a later report, not manually.
     pub status: Option<AlertStatus>,
 }
 
@@ -1,3 +1,4 @@
+use bencher_valid::Boundary;
 use ordered_float::OrderedFloat;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
@@ -18,6 +19,10 @@ pub struct JsonBoundary {
     pub baseline: Option<OrderedFloat<f64>>,
     pub lower_limit: Option<OrderedFloat<f64>>,
     pub upper_limit: Option<OrderedFloat<f64>>,
+    /// The lower boundary after correcting for multiple comparisons across the report.
+    pub adjusted_lower_boundary: Option<Boundary>,
+    /// The upper boundary after correcting for multiple comparisons across the report.
+    pub adjusted_upper_boundary: Option<Boundary>,
 }
 
 const LOWER_BOOL: bool = false;
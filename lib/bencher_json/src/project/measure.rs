@@ -110,6 +110,137 @@ static ESTIMATED_CYCLES_UNITS: Lazy<ResourceName> = Lazy::new(|| {
         .expect(MEASURE_UNITS_ERROR)
 });
 
+// Go bench measures
+
+pub const ALLOCATED_BYTES_NAME_STR: &str = "Allocated Bytes";
+pub const ALLOCATED_BYTES_SLUG_STR: &str = "allocated-bytes";
+pub const ALLOCATED_BYTES_UNITS_STR: &str = "bytes / operation (B/op)";
+
+static ALLOCATED_BYTES_NAME: Lazy<ResourceName> =
+    Lazy::new(|| ALLOCATED_BYTES_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static ALLOCATED_BYTES_SLUG: Lazy<Slug> =
+    Lazy::new(|| ALLOCATED_BYTES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static ALLOCATED_BYTES_UNITS: Lazy<ResourceName> = Lazy::new(|| {
+    ALLOCATED_BYTES_UNITS_STR
+        .parse()
+        .expect(MEASURE_UNITS_ERROR)
+});
+
+pub const ALLOCATIONS_NAME_STR: &str = "Allocations";
+pub const ALLOCATIONS_SLUG_STR: &str = "allocations";
+pub const ALLOCATIONS_UNITS_STR: &str = "allocations / operation (allocs/op)";
+
+static ALLOCATIONS_NAME: Lazy<ResourceName> =
+    Lazy::new(|| ALLOCATIONS_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static ALLOCATIONS_SLUG: Lazy<Slug> =
+    Lazy::new(|| ALLOCATIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static ALLOCATIONS_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| ALLOCATIONS_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+// perf stat measures
+
+pub const CYCLES_NAME_STR: &str = "Cycles";
+pub const CYCLES_SLUG_STR: &str = "cycles";
+pub const CYCLES_UNITS_STR: &str = "cycles";
+
+static CYCLES_NAME: Lazy<ResourceName> =
+    Lazy::new(|| CYCLES_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static CYCLES_SLUG: Lazy<Slug> = Lazy::new(|| CYCLES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static CYCLES_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| CYCLES_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const BRANCH_MISSES_NAME_STR: &str = "Branch Misses";
+pub const BRANCH_MISSES_SLUG_STR: &str = "branch-misses";
+pub const BRANCH_MISSES_UNITS_STR: &str = "branch misses";
+
+static BRANCH_MISSES_NAME: Lazy<ResourceName> =
+    Lazy::new(|| BRANCH_MISSES_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static BRANCH_MISSES_SLUG: Lazy<Slug> =
+    Lazy::new(|| BRANCH_MISSES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static BRANCH_MISSES_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| BRANCH_MISSES_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const CACHE_MISSES_NAME_STR: &str = "Cache Misses";
+pub const CACHE_MISSES_SLUG_STR: &str = "cache-misses";
+pub const CACHE_MISSES_UNITS_STR: &str = "cache misses";
+
+static CACHE_MISSES_NAME: Lazy<ResourceName> =
+    Lazy::new(|| CACHE_MISSES_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static CACHE_MISSES_SLUG: Lazy<Slug> =
+    Lazy::new(|| CACHE_MISSES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static CACHE_MISSES_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| CACHE_MISSES_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+// HTTP load test measures
+
+pub const LATENCY_P50_NAME_STR: &str = "Latency p50";
+pub const LATENCY_P50_SLUG_STR: &str = "latency-p50";
+pub const LATENCY_P50_UNITS_STR: &str = LATENCY_UNITS_STR;
+
+static LATENCY_P50_NAME: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_P50_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static LATENCY_P50_SLUG: Lazy<Slug> =
+    Lazy::new(|| LATENCY_P50_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static LATENCY_P50_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_P50_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const LATENCY_P90_NAME_STR: &str = "Latency p90";
+pub const LATENCY_P90_SLUG_STR: &str = "latency-p90";
+pub const LATENCY_P90_UNITS_STR: &str = LATENCY_UNITS_STR;
+
+static LATENCY_P90_NAME: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_P90_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static LATENCY_P90_SLUG: Lazy<Slug> =
+    Lazy::new(|| LATENCY_P90_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static LATENCY_P90_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_P90_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const LATENCY_P99_NAME_STR: &str = "Latency p99";
+pub const LATENCY_P99_SLUG_STR: &str = "latency-p99";
+pub const LATENCY_P99_UNITS_STR: &str = LATENCY_UNITS_STR;
+
+static LATENCY_P99_NAME: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_P99_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static LATENCY_P99_SLUG: Lazy<Slug> =
+    Lazy::new(|| LATENCY_P99_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static LATENCY_P99_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_P99_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const ERROR_RATE_NAME_STR: &str = "Error Rate";
+pub const ERROR_RATE_SLUG_STR: &str = "error-rate";
+pub const ERROR_RATE_UNITS_STR: &str = "percent (%)";
+
+static ERROR_RATE_NAME: Lazy<ResourceName> =
+    Lazy::new(|| ERROR_RATE_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static ERROR_RATE_SLUG: Lazy<Slug> =
+    Lazy::new(|| ERROR_RATE_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static ERROR_RATE_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| ERROR_RATE_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+// CPU time measures
+
+pub const USER_TIME_NAME_STR: &str = "User Time";
+pub const USER_TIME_SLUG_STR: &str = "user-time";
+pub const USER_TIME_UNITS_STR: &str = LATENCY_UNITS_STR;
+
+static USER_TIME_NAME: Lazy<ResourceName> =
+    Lazy::new(|| USER_TIME_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static USER_TIME_SLUG: Lazy<Slug> =
+    Lazy::new(|| USER_TIME_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static USER_TIME_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| USER_TIME_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const SYSTEM_TIME_NAME_STR: &str = "System Time";
+pub const SYSTEM_TIME_SLUG_STR: &str = "system-time";
+pub const SYSTEM_TIME_UNITS_STR: &str = LATENCY_UNITS_STR;
+
+static SYSTEM_TIME_NAME: Lazy<ResourceName> =
+    Lazy::new(|| SYSTEM_TIME_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static SYSTEM_TIME_SLUG: Lazy<Slug> =
+    Lazy::new(|| SYSTEM_TIME_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static SYSTEM_TIME_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| SYSTEM_TIME_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
 // File size measures
 
 pub const FILE_SIZE_NAME_STR: &str = "File Size";
@@ -206,6 +337,94 @@ impl JsonNewMeasure {
         }
     }
 
+    pub fn allocated_bytes() -> Self {
+        Self {
+            name: ALLOCATED_BYTES_NAME.clone(),
+            slug: Some(ALLOCATED_BYTES_SLUG.clone()),
+            units: ALLOCATED_BYTES_UNITS.clone(),
+        }
+    }
+
+    pub fn allocations() -> Self {
+        Self {
+            name: ALLOCATIONS_NAME.clone(),
+            slug: Some(ALLOCATIONS_SLUG.clone()),
+            units: ALLOCATIONS_UNITS.clone(),
+        }
+    }
+
+    pub fn cycles() -> Self {
+        Self {
+            name: CYCLES_NAME.clone(),
+            slug: Some(CYCLES_SLUG.clone()),
+            units: CYCLES_UNITS.clone(),
+        }
+    }
+
+    pub fn branch_misses() -> Self {
+        Self {
+            name: BRANCH_MISSES_NAME.clone(),
+            slug: Some(BRANCH_MISSES_SLUG.clone()),
+            units: BRANCH_MISSES_UNITS.clone(),
+        }
+    }
+
+    pub fn cache_misses() -> Self {
+        Self {
+            name: CACHE_MISSES_NAME.clone(),
+            slug: Some(CACHE_MISSES_SLUG.clone()),
+            units: CACHE_MISSES_UNITS.clone(),
+        }
+    }
+
+    pub fn latency_p50() -> Self {
+        Self {
+            name: LATENCY_P50_NAME.clone(),
+            slug: Some(LATENCY_P50_SLUG.clone()),
+            units: LATENCY_P50_UNITS.clone(),
+        }
+    }
+
+    pub fn latency_p90() -> Self {
+        Self {
+            name: LATENCY_P90_NAME.clone(),
+            slug: Some(LATENCY_P90_SLUG.clone()),
+            units: LATENCY_P90_UNITS.clone(),
+        }
+    }
+
+    pub fn latency_p99() -> Self {
+        Self {
+            name: LATENCY_P99_NAME.clone(),
+            slug: Some(LATENCY_P99_SLUG.clone()),
+            units: LATENCY_P99_UNITS.clone(),
+        }
+    }
+
+    pub fn error_rate() -> Self {
+        Self {
+            name: ERROR_RATE_NAME.clone(),
+            slug: Some(ERROR_RATE_SLUG.clone()),
+            units: ERROR_RATE_UNITS.clone(),
+        }
+    }
+
+    pub fn user_time() -> Self {
+        Self {
+            name: USER_TIME_NAME.clone(),
+            slug: Some(USER_TIME_SLUG.clone()),
+            units: USER_TIME_UNITS.clone(),
+        }
+    }
+
+    pub fn system_time() -> Self {
+        Self {
+            name: SYSTEM_TIME_NAME.clone(),
+            slug: Some(SYSTEM_TIME_SLUG.clone()),
+            units: SYSTEM_TIME_UNITS.clone(),
+        }
+    }
+
     pub fn file_size() -> Self {
         Self {
             name: FILE_SIZE_NAME.clone(),
@@ -1,4 +1,6 @@
-use bencher_valid::{Boundary, DateTime, ModelTest, SampleSize, Window};
+use bencher_valid::{
+    Boundary, DateTime, ModelTest, SampleSize, SmoothingFactor, Violations, Window,
+};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -17,6 +19,10 @@ pub struct JsonModel {
     pub min_sample_size: Option<SampleSize>,
     pub max_sample_size: Option<SampleSize>,
     pub window: Option<Window>,
+    pub smoothing_factor: Option<SmoothingFactor>,
+    pub violations: Option<Violations>,
+    pub violation_window: Option<Violations>,
+    pub start_point_baseline: Option<bool>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
     pub created: DateTime,
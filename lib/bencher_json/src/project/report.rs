@@ -49,6 +49,10 @@ pub struct JsonReportSettings {
     /// Fold multiple results into a single result using the selected operation.
     /// This can be useful for taking the min, max, mean, or median of the benchmark results.
     pub fold: Option<JsonFold>,
+    /// Correct for multiple comparisons across all of the benchmarks in the report
+    /// before any alerts are generated.
+    /// The adjusted boundaries are recorded on each boundary.
+    pub correction: Option<JsonCorrection>,
 }
 
 const MAGIC_INT: i32 = 0;
@@ -58,6 +62,7 @@ const RUST_BENCH_INT: i32 = 21;
 const RUST_CRITERION_INT: i32 = 22;
 const RUST_IAI_INT: i32 = 23;
 const RUST_IAI_CALLGRIND_INT: i32 = 24;
+const RUST_DIVAN_INT: i32 = 25;
 const CPP_INT: i32 = 30;
 const CPP_GOOGLE_INT: i32 = 31;
 const CPP_CATCH2_INT: i32 = 32;
@@ -70,16 +75,29 @@ const C_SHARP_DOT_NET_INT: i32 = 61;
 const JS_INT: i32 = 70;
 const JS_BENCHMARK_INT: i32 = 71;
 const JS_TIME_INT: i32 = 72;
+const JS_VITEST_INT: i32 = 73;
 const PYTHON_INT: i32 = 80;
 const PYTHON_ASV_INT: i32 = 81;
 const PYTHON_PYTEST_INT: i32 = 82;
+const PYTHON_PYPERF_INT: i32 = 83;
 const RUBY_INT: i32 = 90;
 const RUBY_BENCHMARK_INT: i32 = 91;
 const SHELL_INT: i32 = 100;
 const SHELL_HYPERFINE_INT: i32 = 101;
+const SHELL_PERF_STAT_INT: i32 = 102;
+const HTTP_INT: i32 = 110;
+const HTTP_K6_INT: i32 = 111;
+const HTTP_WRK_INT: i32 = 112;
+const HTTP_OHA_INT: i32 = 113;
+const SWIFT_INT: i32 = 120;
+const SWIFT_BENCHMARK_INT: i32 = 121;
+const ZIG_INT: i32 = 130;
+const ZIG_ZBENCH_INT: i32 = 131;
+const ELIXIR_INT: i32 = 140;
+const ELIXIR_BENCHEE_INT: i32 = 141;
 
 #[typeshare::typeshare]
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
 #[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
@@ -94,6 +112,7 @@ pub enum Adapter {
     RustCriterion = RUST_CRITERION_INT,
     RustIai = RUST_IAI_INT,
     RustIaiCallgrind = RUST_IAI_CALLGRIND_INT,
+    RustDivan = RUST_DIVAN_INT,
     Cpp = CPP_INT,
     CppGoogle = CPP_GOOGLE_INT,
     CppCatch2 = CPP_CATCH2_INT,
@@ -106,13 +125,26 @@ pub enum Adapter {
     Js = JS_INT,
     JsBenchmark = JS_BENCHMARK_INT,
     JsTime = JS_TIME_INT,
+    JsVitest = JS_VITEST_INT,
     Python = PYTHON_INT,
     PythonAsv = PYTHON_ASV_INT,
     PythonPytest = PYTHON_PYTEST_INT,
+    PythonPyperf = PYTHON_PYPERF_INT,
     Ruby = RUBY_INT,
     RubyBenchmark = RUBY_BENCHMARK_INT,
     Shell = SHELL_INT,
     ShellHyperfine = SHELL_HYPERFINE_INT,
+    ShellPerfStat = SHELL_PERF_STAT_INT,
+    Http = HTTP_INT,
+    HttpK6 = HTTP_K6_INT,
+    HttpWrk = HTTP_WRK_INT,
+    HttpOha = HTTP_OHA_INT,
+    Swift = SWIFT_INT,
+    SwiftBenchmark = SWIFT_BENCHMARK_INT,
+    Zig = ZIG_INT,
+    ZigZbench = ZIG_ZBENCH_INT,
+    Elixir = ELIXIR_INT,
+    ElixirBenchee = ELIXIR_BENCHEE_INT,
 }
 
 impl fmt::Display for Adapter {
@@ -125,6 +157,7 @@ impl fmt::Display for Adapter {
             Self::RustCriterion => write!(f, "rust_criterion"),
             Self::RustIai => write!(f, "rust_iai"),
             Self::RustIaiCallgrind => write!(f, "rust_iai_callgrind"),
+            Self::RustDivan => write!(f, "rust_divan"),
             Self::Cpp => write!(f, "cpp"),
             Self::CppGoogle => write!(f, "cpp_google"),
             Self::CppCatch2 => write!(f, "cpp_catch2"),
@@ -137,13 +170,26 @@ impl fmt::Display for Adapter {
             Self::Js => write!(f, "js"),
             Self::JsBenchmark => write!(f, "js_benchmark"),
             Self::JsTime => write!(f, "js_time"),
+            Self::JsVitest => write!(f, "js_vitest"),
             Self::Python => write!(f, "python"),
             Self::PythonAsv => write!(f, "python_asv"),
             Self::PythonPytest => write!(f, "python_pytest"),
+            Self::PythonPyperf => write!(f, "python_pyperf"),
             Self::Ruby => write!(f, "ruby"),
             Self::RubyBenchmark => write!(f, "ruby_benchmark"),
             Self::Shell => write!(f, "shell"),
             Self::ShellHyperfine => write!(f, "shell_hyperfine"),
+            Self::ShellPerfStat => write!(f, "shell_perf_stat"),
+            Self::Http => write!(f, "http"),
+            Self::HttpK6 => write!(f, "http_k6"),
+            Self::HttpWrk => write!(f, "http_wrk"),
+            Self::HttpOha => write!(f, "http_oha"),
+            Self::Swift => write!(f, "swift"),
+            Self::SwiftBenchmark => write!(f, "swift_benchmark"),
+            Self::Zig => write!(f, "zig"),
+            Self::ZigZbench => write!(f, "zig_zbench"),
+            Self::Elixir => write!(f, "elixir"),
+            Self::ElixirBenchee => write!(f, "elixir_benchee"),
         }
     }
 }
@@ -152,10 +198,12 @@ impl fmt::Display for Adapter {
 mod adapter {
     use super::{
         Adapter, CPP_CATCH2_INT, CPP_GOOGLE_INT, CPP_INT, C_SHARP_DOT_NET_INT, C_SHARP_INT,
-        GO_BENCH_INT, GO_INT, JAVA_INT, JAVA_JMH_INT, JSON_INT, JS_BENCHMARK_INT, JS_INT,
-        JS_TIME_INT, MAGIC_INT, PYTHON_ASV_INT, PYTHON_INT, PYTHON_PYTEST_INT, RUBY_BENCHMARK_INT,
-        RUBY_INT, RUST_BENCH_INT, RUST_CRITERION_INT, RUST_IAI_CALLGRIND_INT, RUST_IAI_INT,
-        RUST_INT, SHELL_HYPERFINE_INT, SHELL_INT,
+        ELIXIR_BENCHEE_INT, ELIXIR_INT, GO_BENCH_INT, GO_INT, HTTP_INT, HTTP_K6_INT, HTTP_OHA_INT,
+        HTTP_WRK_INT, JAVA_INT, JAVA_JMH_INT, JSON_INT, JS_BENCHMARK_INT, JS_INT, JS_TIME_INT,
+        JS_VITEST_INT, MAGIC_INT, PYTHON_ASV_INT, PYTHON_INT, PYTHON_PYPERF_INT, PYTHON_PYTEST_INT,
+        RUBY_BENCHMARK_INT, RUBY_INT, RUST_BENCH_INT, RUST_CRITERION_INT, RUST_DIVAN_INT,
+        RUST_IAI_CALLGRIND_INT, RUST_IAI_INT, RUST_INT, SHELL_HYPERFINE_INT, SHELL_INT,
+        SHELL_PERF_STAT_INT, SWIFT_BENCHMARK_INT, SWIFT_INT, ZIG_INT, ZIG_ZBENCH_INT,
     };
 
     #[derive(Debug, thiserror::Error)]
@@ -181,6 +229,7 @@ mod adapter {
                 Self::RustCriterion => RUST_CRITERION_INT.to_sql(out),
                 Self::RustIai => RUST_IAI_INT.to_sql(out),
                 Self::RustIaiCallgrind => RUST_IAI_CALLGRIND_INT.to_sql(out),
+                Self::RustDivan => RUST_DIVAN_INT.to_sql(out),
                 Self::Cpp => CPP_INT.to_sql(out),
                 Self::CppGoogle => CPP_GOOGLE_INT.to_sql(out),
                 Self::CppCatch2 => CPP_CATCH2_INT.to_sql(out),
@@ -193,13 +242,26 @@ mod adapter {
                 Self::Js => JS_INT.to_sql(out),
                 Self::JsBenchmark => JS_BENCHMARK_INT.to_sql(out),
                 Self::JsTime => JS_TIME_INT.to_sql(out),
+                Self::JsVitest => JS_VITEST_INT.to_sql(out),
                 Self::Python => PYTHON_INT.to_sql(out),
                 Self::PythonAsv => PYTHON_ASV_INT.to_sql(out),
                 Self::PythonPytest => PYTHON_PYTEST_INT.to_sql(out),
+                Self::PythonPyperf => PYTHON_PYPERF_INT.to_sql(out),
                 Self::Ruby => RUBY_INT.to_sql(out),
                 Self::RubyBenchmark => RUBY_BENCHMARK_INT.to_sql(out),
                 Self::Shell => SHELL_INT.to_sql(out),
                 Self::ShellHyperfine => SHELL_HYPERFINE_INT.to_sql(out),
+                Self::ShellPerfStat => SHELL_PERF_STAT_INT.to_sql(out),
+                Self::Http => HTTP_INT.to_sql(out),
+                Self::HttpK6 => HTTP_K6_INT.to_sql(out),
+                Self::HttpWrk => HTTP_WRK_INT.to_sql(out),
+                Self::HttpOha => HTTP_OHA_INT.to_sql(out),
+                Self::Swift => SWIFT_INT.to_sql(out),
+                Self::SwiftBenchmark => SWIFT_BENCHMARK_INT.to_sql(out),
+                Self::Zig => ZIG_INT.to_sql(out),
+                Self::ZigZbench => ZIG_ZBENCH_INT.to_sql(out),
+                Self::Elixir => ELIXIR_INT.to_sql(out),
+                Self::ElixirBenchee => ELIXIR_BENCHEE_INT.to_sql(out),
             }
         }
     }
@@ -218,6 +280,7 @@ mod adapter {
                 RUST_CRITERION_INT => Ok(Self::RustCriterion),
                 RUST_IAI_INT => Ok(Self::RustIai),
                 RUST_IAI_CALLGRIND_INT => Ok(Self::RustIaiCallgrind),
+                RUST_DIVAN_INT => Ok(Self::RustDivan),
                 CPP_INT => Ok(Self::Cpp),
                 CPP_GOOGLE_INT => Ok(Self::CppGoogle),
                 CPP_CATCH2_INT => Ok(Self::CppCatch2),
@@ -230,20 +293,33 @@ mod adapter {
                 JS_INT => Ok(Self::Js),
                 JS_BENCHMARK_INT => Ok(Self::JsBenchmark),
                 JS_TIME_INT => Ok(Self::JsTime),
+                JS_VITEST_INT => Ok(Self::JsVitest),
                 PYTHON_INT => Ok(Self::Python),
                 PYTHON_ASV_INT => Ok(Self::PythonAsv),
                 PYTHON_PYTEST_INT => Ok(Self::PythonPytest),
+                PYTHON_PYPERF_INT => Ok(Self::PythonPyperf),
                 RUBY_INT => Ok(Self::Ruby),
                 RUBY_BENCHMARK_INT => Ok(Self::RubyBenchmark),
                 SHELL_INT => Ok(Self::Shell),
                 SHELL_HYPERFINE_INT => Ok(Self::ShellHyperfine),
+                SHELL_PERF_STAT_INT => Ok(Self::ShellPerfStat),
+                HTTP_INT => Ok(Self::Http),
+                HTTP_K6_INT => Ok(Self::HttpK6),
+                HTTP_WRK_INT => Ok(Self::HttpWrk),
+                HTTP_OHA_INT => Ok(Self::HttpOha),
+                SWIFT_INT => Ok(Self::Swift),
+                SWIFT_BENCHMARK_INT => Ok(Self::SwiftBenchmark),
+                ZIG_INT => Ok(Self::Zig),
+                ZIG_ZBENCH_INT => Ok(Self::ZigZbench),
+                ELIXIR_INT => Ok(Self::Elixir),
+                ELIXIR_BENCHEE_INT => Ok(Self::ElixirBenchee),
                 value => Err(Box::new(AdapterError::Invalid(value))),
             }
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum JsonAverage {
@@ -262,6 +338,14 @@ pub enum JsonFold {
     Median,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonCorrection {
+    Bonferroni,
+    BenjaminiHochberg,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonReports(pub Vec<JsonReport>);
@@ -5,9 +5,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     urlencoded::{from_urlencoded, to_urlencoded, UrlEncodedError},
-    JsonBranch, JsonMeasure, JsonModel, JsonTestbed, ProjectUuid,
+    BigInt, JsonBranch, JsonMeasure, JsonModel, JsonTestbed, ProjectUuid, ReportUuid,
 };
 
+use super::{benchmark::JsonBenchmarkMetric, boundary::BoundaryLimit, report::Iteration};
+
 crate::typed_uuid::typed_uuid!(ThresholdUuid);
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -129,3 +131,33 @@ pub struct JsonUpdateThreshold {
     #[serde(flatten)]
     pub model: Model,
 }
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonSimulateThreshold {
+    /// The proposed threshold model to replay the historical metrics through.
+    #[serde(flatten)]
+    pub model: Model,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonThresholdSimulation {
+    pub threshold: ThresholdUuid,
+    /// The number of alerts that the proposed threshold model would have generated.
+    pub alerts: BigInt,
+    /// The boundary for every historical metric, ordered from least recent to most recent for each benchmark.
+    pub boundaries: Vec<JsonSimulatedBoundary>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonSimulatedBoundary {
+    pub report: ReportUuid,
+    pub iteration: Iteration,
+    pub benchmark: JsonBenchmarkMetric,
+    /// The side of the boundary that would have generated an alert, if any.
+    pub alert: Option<BoundaryLimit>,
+}
@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::{Boundary, SampleSize, Window};
+use crate::{Boundary, ModelTest, SampleSize, Violations, Window};
 
 pub(crate) const REGEX_ERROR: &str = "Failed to compile regex.";
 
@@ -52,6 +52,14 @@ pub enum ValidError {
     Window(u32),
     #[error("Failed to parse model window: {0}")]
     WindowStr(std::num::ParseIntError),
+    #[error("Invalid model smoothing factor: {0}")]
+    SmoothingFactor(f64),
+    #[error("Failed to parse model smoothing factor: {0}")]
+    SmoothingFactorStr(std::num::ParseFloatError),
+    #[error("Invalid model violations: {0}")]
+    Violations(u32),
+    #[error("Failed to parse model violations: {0}")]
+    ViolationsStr(std::num::ParseIntError),
     #[error("Invalid plot index: {0}")]
     Index(u8),
     #[error("Failed to parse plot index: {0}")]
@@ -114,6 +122,21 @@ pub enum ValidError {
     StaticMaxSampleSize(SampleSize),
     #[error("Invalid static model, includes a sampling window: {0}")]
     StaticWindow(Window),
+    #[error("Invalid {0} model, only EWMA models have a smoothing factor")]
+    SmoothingFactorTest(ModelTest),
+    #[error(
+        "Invalid model, violations ({violations}) is greater than violation window ({window})"
+    )]
+    ViolationWindow {
+        violations: Violations,
+        window: Violations,
+    },
+    #[error("Invalid model, violation window ({0}) provided without violations")]
+    NoViolations(Violations),
+    #[error(
+        "Invalid {0} model, violations are only supported for models that compare each metric against its limits"
+    )]
+    ViolationsTest(ModelTest),
     #[error("Invalid percentage boundary: {0}")]
     PercentageBoundary(f64),
     #[error("Invalid statistical boundary: {0}")]
@@ -38,6 +38,8 @@ pub use model::{
     boundary::{Boundary, CdfBoundary, IqrBoundary, PercentageBoundary},
     model_test::ModelTest,
     sample_size::SampleSize,
+    smoothing_factor::SmoothingFactor,
+    violations::Violations,
     window::Window,
     Model,
 };
@@ -10,11 +10,15 @@ use crate::ValidError;
 pub mod boundary;
 pub mod model_test;
 pub mod sample_size;
+pub mod smoothing_factor;
+pub mod violations;
 pub mod window;
 
 use boundary::{Boundary, CdfBoundary, IqrBoundary, PercentageBoundary};
 use model_test::ModelTest;
 use sample_size::SampleSize;
+use smoothing_factor::SmoothingFactor;
+use violations::Violations;
 use window::Window;
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
@@ -27,10 +31,29 @@ pub struct Model {
     pub min_sample_size: Option<SampleSize>,
     /// The maximum number of samples used to perform the test.
     /// Only the most recent samples will be used if there are more.
+    /// If not set, then the `change_point`, `bootstrap_mean`, and `bootstrap_median` tests only use the most recent 255 samples.
     pub max_sample_size: Option<SampleSize>,
     /// The window of time for samples used to perform the test, in seconds.
     /// Samples outside of this window will be omitted.
     pub window: Option<Window>,
+    /// The smoothing factor used to weight recent samples more than older samples.
+    /// Only used by the `ewma` test, and it must be greater than 0 and less than or equal to 1.
+    pub smoothing_factor: Option<SmoothingFactor>,
+    /// The number of boundary violations required before an alert is generated.
+    /// This can be used to ignore one-off outliers on noisy testbeds.
+    /// If not set, then every boundary violation generates an alert.
+    /// It must be at most 255.
+    /// Not supported by the `change_point` test or by two-sample tests.
+    pub violations: Option<Violations>,
+    /// The number of most recent boundary checks in which `violations` must occur.
+    /// If not set, then the violations must be consecutive.
+    /// It must be greater than or equal to `violations` and at most 255.
+    pub violation_window: Option<Violations>,
+    /// Only use the historical metrics from the start point branch,
+    /// at or before the start point version, to perform the test.
+    /// This compares a feature branch against its start point at the point it was created.
+    /// If the branch does not have a start point, then its own historical metrics are used.
+    pub start_point_baseline: Option<bool>,
     /// The lower boundary used to calculate the lower boundary limit.
     /// The requirements for this field depend on which `test` is selected.
     pub lower_boundary: Option<Boundary>,
@@ -46,6 +69,10 @@ impl Model {
             min_sample_size: None,
             max_sample_size: Some(SampleSize::TWO_FIFTY_FIVE),
             window: None,
+            smoothing_factor: None,
+            violations: None,
+            violation_window: None,
+            start_point_baseline: None,
             lower_boundary: Some(Boundary::NINETY_EIGHT),
             upper_boundary: None,
         }
@@ -57,6 +84,10 @@ impl Model {
             min_sample_size: None,
             max_sample_size: Some(SampleSize::TWO_FIFTY_FIVE),
             window: None,
+            smoothing_factor: None,
+            violations: None,
+            violation_window: None,
+            start_point_baseline: None,
             lower_boundary: None,
             upper_boundary: Some(Boundary::NINETY_EIGHT),
         }
@@ -73,9 +104,17 @@ pub fn validate_model(model: Model) -> Result<(), ValidError> {
         min_sample_size,
         max_sample_size,
         window,
+        smoothing_factor,
+        violations,
+        violation_window,
+        start_point_baseline: _,
         lower_boundary,
         upper_boundary,
     } = model;
+    if smoothing_factor.is_some() && test != ModelTest::Ewma {
+        return Err(ValidError::SmoothingFactorTest(test));
+    }
+    validate_violations(test, violations, violation_window)?;
     match test {
         ModelTest::Static => {
             if let Some(&min_sample_size) = min_sample_size.as_ref() {
@@ -102,7 +141,16 @@ pub fn validate_model(model: Model) -> Result<(), ValidError> {
             validate_sample_size(min_sample_size, max_sample_size)?;
             validate_boundary::<PercentageBoundary>(lower_boundary, upper_boundary)
         },
-        ModelTest::ZScore | ModelTest::TTest | ModelTest::LogNormal => {
+        ModelTest::ZScore
+        | ModelTest::TTest
+        | ModelTest::LogNormal
+        | ModelTest::ChangePoint
+        | ModelTest::WelchTTest
+        | ModelTest::MannWhitneyU
+        | ModelTest::BootstrapMean
+        | ModelTest::BootstrapMedian
+        | ModelTest::Ewma
+        | ModelTest::InverseVariance => {
             validate_sample_size(min_sample_size, max_sample_size)?;
             validate_boundary::<CdfBoundary>(lower_boundary, upper_boundary)
         },
@@ -126,6 +174,28 @@ fn validate_sample_size(
     Ok(())
 }
 
+fn validate_violations(
+    test: ModelTest,
+    violations: Option<Violations>,
+    violation_window: Option<Violations>,
+) -> Result<(), ValidError> {
+    // Hysteresis compares previous metric values against their boundary limits.
+    if violations.is_some() && !test.is_value_limit() {
+        return Err(ValidError::ViolationsTest(test));
+    }
+    match (violations, violation_window) {
+        (Some(violations), Some(window)) => {
+            if u32::from(violations) > u32::from(window) {
+                Err(ValidError::ViolationWindow { violations, window })
+            } else {
+                Ok(())
+            }
+        },
+        (None, Some(window)) => Err(ValidError::NoViolations(window)),
+        (Some(_) | None, None) => Ok(()),
+    }
+}
+
 fn validate_boundary<B>(lower: Option<Boundary>, upper: Option<Boundary>) -> Result<(), ValidError>
 where
     B: TryFrom<Boundary, Error = ValidError>,
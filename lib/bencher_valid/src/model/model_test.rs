@@ -3,13 +3,22 @@ use schemars::JsonSchema;
 
 use serde::{Deserialize, Serialize};
 
+use crate::SampleSize;
+
 const STATIC_INT: i32 = 20;
 const PERCENTAGE_INT: i32 = 30;
 const Z_SCORE_INT: i32 = 0;
 const T_TEST_INT: i32 = 1;
+const WELCH_T_TEST_INT: i32 = 2;
 const LOG_NORMAL_INT: i32 = 10;
 const IQR_INT: i32 = 40;
 const DELTA_IQR_INT: i32 = 41;
+const CHANGE_POINT_INT: i32 = 50;
+const MANN_WHITNEY_U_INT: i32 = 60;
+const BOOTSTRAP_MEAN_INT: i32 = 70;
+const BOOTSTRAP_MEDIAN_INT: i32 = 71;
+const EWMA_INT: i32 = 80;
+const INVERSE_VARIANCE_INT: i32 = 90;
 
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
@@ -28,13 +37,64 @@ pub enum ModelTest {
     LogNormal = LOG_NORMAL_INT,
     Iqr = IQR_INT,
     DeltaIqr = DELTA_IQR_INT,
+    ChangePoint = CHANGE_POINT_INT,
+    #[serde(alias = "welch")]
+    WelchTTest = WELCH_T_TEST_INT,
+    #[serde(alias = "mann_whitney")]
+    MannWhitneyU = MANN_WHITNEY_U_INT,
+    BootstrapMean = BOOTSTRAP_MEAN_INT,
+    BootstrapMedian = BOOTSTRAP_MEDIAN_INT,
+    Ewma = EWMA_INT,
+    InverseVariance = INVERSE_VARIANCE_INT,
+}
+
+impl ModelTest {
+    /// Two-sample tests compare all of the iterations of a new report against the historical data,
+    /// instead of checking each iteration on its own.
+    pub fn is_two_sample(self) -> bool {
+        matches!(
+            self,
+            Self::WelchTTest | Self::MannWhitneyU | Self::BootstrapMean | Self::BootstrapMedian
+        )
+    }
+
+    /// Whether an outlier is any metric value outside of its boundary limits.
+    /// Two-sample tests compare a sample statistic instead,
+    /// and change point detection only flags the metric that begins a new segment.
+    pub fn is_value_limit(self) -> bool {
+        !self.is_two_sample() && self != Self::ChangePoint
+    }
+
+    /// The maximum sample size used when the model does not set one.
+    /// Change point detection is quadratic in the number of samples
+    /// and bootstrapping resamples the history thousands of times,
+    /// so neither is run over the whole history.
+    pub fn default_max_sample_size(self) -> Option<SampleSize> {
+        match self {
+            Self::ChangePoint | Self::BootstrapMean | Self::BootstrapMedian => {
+                Some(SampleSize::TWO_FIFTY_FIVE)
+            },
+            Self::Static
+            | Self::Percentage
+            | Self::ZScore
+            | Self::TTest
+            | Self::LogNormal
+            | Self::Iqr
+            | Self::DeltaIqr
+            | Self::WelchTTest
+            | Self::MannWhitneyU
+            | Self::Ewma
+            | Self::InverseVariance => None,
+        }
+    }
 }
 
 #[cfg(feature = "db")]
 mod db {
     use super::{
-        ModelTest, DELTA_IQR_INT, IQR_INT, LOG_NORMAL_INT, PERCENTAGE_INT, STATIC_INT, T_TEST_INT,
-        Z_SCORE_INT,
+        ModelTest, BOOTSTRAP_MEAN_INT, BOOTSTRAP_MEDIAN_INT, CHANGE_POINT_INT, DELTA_IQR_INT,
+        EWMA_INT, INVERSE_VARIANCE_INT, IQR_INT, LOG_NORMAL_INT, MANN_WHITNEY_U_INT,
+        PERCENTAGE_INT, STATIC_INT, T_TEST_INT, WELCH_T_TEST_INT, Z_SCORE_INT,
     };
 
     #[derive(Debug, thiserror::Error)]
@@ -60,6 +120,13 @@ mod db {
                 Self::LogNormal => LOG_NORMAL_INT.to_sql(out),
                 Self::Iqr => IQR_INT.to_sql(out),
                 Self::DeltaIqr => DELTA_IQR_INT.to_sql(out),
+                Self::ChangePoint => CHANGE_POINT_INT.to_sql(out),
+                Self::WelchTTest => WELCH_T_TEST_INT.to_sql(out),
+                Self::MannWhitneyU => MANN_WHITNEY_U_INT.to_sql(out),
+                Self::BootstrapMean => BOOTSTRAP_MEAN_INT.to_sql(out),
+                Self::BootstrapMedian => BOOTSTRAP_MEDIAN_INT.to_sql(out),
+                Self::Ewma => EWMA_INT.to_sql(out),
+                Self::InverseVariance => INVERSE_VARIANCE_INT.to_sql(out),
             }
         }
     }
@@ -78,6 +145,13 @@ mod db {
                 LOG_NORMAL_INT => Ok(Self::LogNormal),
                 IQR_INT => Ok(Self::Iqr),
                 DELTA_IQR_INT => Ok(Self::DeltaIqr),
+                CHANGE_POINT_INT => Ok(Self::ChangePoint),
+                WELCH_T_TEST_INT => Ok(Self::WelchTTest),
+                MANN_WHITNEY_U_INT => Ok(Self::MannWhitneyU),
+                BOOTSTRAP_MEAN_INT => Ok(Self::BootstrapMean),
+                BOOTSTRAP_MEDIAN_INT => Ok(Self::BootstrapMedian),
+                EWMA_INT => Ok(Self::Ewma),
+                INVERSE_VARIANCE_INT => Ok(Self::InverseVariance),
                 value => Err(Box::new(ModelTestError::Invalid(value))),
             }
         }
@@ -0,0 +1,154 @@
+use derive_more::Display;
+use ordered_float::OrderedFloat;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use std::{fmt, str::FromStr};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::ValidError;
+
+#[typeshare::typeshare]
+#[derive(Debug, Display, Clone, Copy, Eq, PartialEq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Double))]
+pub struct SmoothingFactor(OrderedFloat<f64>);
+
+impl TryFrom<f64> for SmoothingFactor {
+    type Error = ValidError;
+
+    fn try_from(smoothing_factor: f64) -> Result<Self, Self::Error> {
+        is_valid_smoothing_factor(smoothing_factor)
+            .then(|| Self(smoothing_factor.into()))
+            .ok_or(ValidError::SmoothingFactor(smoothing_factor))
+    }
+}
+
+impl From<SmoothingFactor> for f64 {
+    fn from(smoothing_factor: SmoothingFactor) -> Self {
+        smoothing_factor.0.into()
+    }
+}
+
+impl SmoothingFactor {
+    pub const DEFAULT: Self = Self(OrderedFloat(0.1));
+    pub const MAX: Self = Self(OrderedFloat(1.0));
+}
+
+impl FromStr for SmoothingFactor {
+    type Err = ValidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(f64::from_str(s).map_err(ValidError::SmoothingFactorStr)?)
+    }
+}
+
+impl<'de> Deserialize<'de> for SmoothingFactor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_f64(SmoothingFactorVisitor)
+    }
+}
+
+struct SmoothingFactorVisitor;
+
+impl Visitor<'_> for SmoothingFactorVisitor {
+    type Value = SmoothingFactor;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a model smoothing factor greater than 0 and less than or equal to 1")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        #[allow(clippy::cast_precision_loss)]
+        (value as f64).try_into().map_err(E::custom)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        value.try_into().map_err(E::custom)
+    }
+}
+
+#[cfg(feature = "db")]
+mod db {
+    use super::SmoothingFactor;
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Double, DB> for SmoothingFactor
+    where
+        DB: diesel::backend::Backend,
+        for<'a> f64: diesel::serialize::ToSql<diesel::sql_types::Double, DB>
+            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            out.set_value(f64::from(*self));
+            Ok(diesel::serialize::IsNull::No)
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Double, DB> for SmoothingFactor
+    where
+        DB: diesel::backend::Backend,
+        f64: diesel::deserialize::FromSql<diesel::sql_types::Double, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            f64::from_sql(bytes)?.try_into().map_err(Into::into)
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_smoothing_factor(smoothing_factor: f64) -> bool {
+    smoothing_factor > 0.0 && smoothing_factor <= 1.0
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{is_valid_smoothing_factor, SmoothingFactor};
+
+    #[test]
+    fn test_smoothing_factor() {
+        assert_eq!(true, is_valid_smoothing_factor(0.1));
+        assert_eq!(true, is_valid_smoothing_factor(0.5));
+        assert_eq!(true, is_valid_smoothing_factor(1.0));
+        assert_eq!(true, is_valid_smoothing_factor(f64::MIN_POSITIVE));
+
+        assert_eq!(false, is_valid_smoothing_factor(0.0));
+        assert_eq!(false, is_valid_smoothing_factor(-0.1));
+        assert_eq!(false, is_valid_smoothing_factor(1.1));
+        assert_eq!(false, is_valid_smoothing_factor(f64::INFINITY));
+        assert_eq!(false, is_valid_smoothing_factor(f64::NAN));
+    }
+
+    #[test]
+    fn test_smoothing_factor_serde() {
+        let smoothing_factor: SmoothingFactor = serde_json::from_str("0.1").unwrap();
+        assert_eq!(SmoothingFactor::DEFAULT, smoothing_factor);
+        let smoothing_factor: SmoothingFactor = serde_json::from_str("1").unwrap();
+        assert_eq!(SmoothingFactor::MAX, smoothing_factor);
+
+        let smoothing_factor = serde_json::from_str::<SmoothingFactor>("0.0");
+        assert!(smoothing_factor.is_err());
+        let smoothing_factor = serde_json::from_str::<SmoothingFactor>("1.5");
+        assert!(smoothing_factor.is_err());
+    }
+}
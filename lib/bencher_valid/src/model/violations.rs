@@ -0,0 +1,154 @@
+use derive_more::Display;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use std::{fmt, str::FromStr};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::ValidError;
+
+#[typeshare::typeshare]
+#[derive(Debug, Display, Clone, Copy, Eq, PartialEq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::BigInt))]
+pub struct Violations(u32);
+
+impl TryFrom<u32> for Violations {
+    type Error = ValidError;
+
+    fn try_from(violations: u32) -> Result<Self, Self::Error> {
+        is_valid_violations(violations)
+            .then_some(Self(violations))
+            .ok_or(ValidError::Violations(violations))
+    }
+}
+
+impl From<Violations> for i64 {
+    fn from(violations: Violations) -> Self {
+        i64::from(violations.0)
+    }
+}
+
+impl From<Violations> for u32 {
+    fn from(violations: Violations) -> Self {
+        violations.0
+    }
+}
+
+impl From<Violations> for usize {
+    fn from(violations: Violations) -> Self {
+        violations.0 as usize
+    }
+}
+
+impl Violations {
+    pub const MIN: Self = Self(1);
+    // The violation window is used to limit the boundary history query for every detection.
+    pub const MAX: Self = Self(u8::MAX as u32);
+}
+
+impl FromStr for Violations {
+    type Err = ValidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(u32::from_str(s).map_err(ValidError::ViolationsStr)?)
+    }
+}
+
+impl<'de> Deserialize<'de> for Violations {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_u32(ViolationsVisitor)
+    }
+}
+
+struct ViolationsVisitor;
+
+impl Visitor<'_> for ViolationsVisitor {
+    type Value = Violations;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a model violations count between 1 and 255")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_u32(u32::try_from(value).map_err(E::custom)?)
+    }
+
+    fn visit_u32<E>(self, value: u32) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        value.try_into().map_err(E::custom)
+    }
+}
+
+#[cfg(feature = "db")]
+mod db {
+    use super::Violations;
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::BigInt, DB> for Violations
+    where
+        DB: diesel::backend::Backend,
+        for<'a> i64: diesel::serialize::ToSql<diesel::sql_types::BigInt, DB>
+            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            out.set_value(i64::from(*self));
+            Ok(diesel::serialize::IsNull::No)
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::BigInt, DB> for Violations
+    where
+        DB: diesel::backend::Backend,
+        i64: diesel::deserialize::FromSql<diesel::sql_types::BigInt, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            u32::try_from(i64::from_sql(bytes)?)?
+                .try_into()
+                .map_err(Into::into)
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_violations(violations: u32) -> bool {
+    (u32::from(Violations::MIN)..=u32::from(Violations::MAX)).contains(&violations)
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{is_valid_violations, Violations};
+
+    #[test]
+    fn test_violations() {
+        assert_eq!(true, is_valid_violations(Violations::MIN.into()));
+        assert_eq!(true, is_valid_violations(1));
+        assert_eq!(true, is_valid_violations(2));
+        assert_eq!(true, is_valid_violations(3));
+        assert_eq!(true, is_valid_violations(254));
+        assert_eq!(true, is_valid_violations(255));
+        assert_eq!(true, is_valid_violations(Violations::MAX.into()));
+
+        assert_eq!(false, is_valid_violations(0));
+        assert_eq!(false, is_valid_violations(256));
+        assert_eq!(false, is_valid_violations(u32::MAX));
+    }
+}
@@ -0,0 +1,47 @@
+PRAGMA foreign_keys = off;
+CREATE TABLE down_model (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    threshold_id INTEGER NOT NULL,
+    test INTEGER NOT NULL,
+    min_sample_size BIGINT,
+    max_sample_size BIGINT,
+    window BIGINT,
+    smoothing_factor DOUBLE,
+    lower_boundary DOUBLE,
+    upper_boundary DOUBLE,
+    created BIGINT NOT NULL,
+    replaced BIGINT,
+    FOREIGN KEY (threshold_id) REFERENCES threshold (id) ON DELETE CASCADE
+);
+INSERT INTO down_model(
+        id,
+        uuid,
+        threshold_id,
+        test,
+        min_sample_size,
+        max_sample_size,
+        window,
+        smoothing_factor,
+        lower_boundary,
+        upper_boundary,
+        created,
+        replaced
+    )
+SELECT id,
+    uuid,
+    threshold_id,
+    test,
+    min_sample_size,
+    max_sample_size,
+    window,
+    smoothing_factor,
+    lower_boundary,
+    upper_boundary,
+    created,
+    replaced
+FROM model;
+DROP TABLE model;
+ALTER TABLE down_model
+    RENAME TO model;
+PRAGMA foreign_keys = on;
\ No newline at end of file
@@ -0,0 +1,36 @@
+PRAGMA foreign_keys = off;
+DROP INDEX IF EXISTS index_alert_boundary;
+CREATE TABLE up_alert (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    boundary_id INTEGER NOT NULL,
+    boundary_limit BOOLEAN NOT NULL,
+    status INTEGER NOT NULL,
+    resolved_report_id INTEGER,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (boundary_id) REFERENCES boundary (id) ON DELETE CASCADE,
+    FOREIGN KEY (resolved_report_id) REFERENCES report (id) ON DELETE
+    SET NULL
+);
+INSERT INTO up_alert(
+        id,
+        uuid,
+        boundary_id,
+        boundary_limit,
+        status,
+        resolved_report_id,
+        modified
+    )
+SELECT id,
+    uuid,
+    boundary_id,
+    boundary_limit,
+    status,
+    NULL,
+    modified
+FROM alert;
+DROP TABLE alert;
+ALTER TABLE up_alert
+    RENAME TO alert;
+CREATE INDEX index_alert_boundary ON alert(boundary_id);
+PRAGMA foreign_keys = on;
@@ -200,12 +200,14 @@ impl Api {
         if http_options {
             api.register(project::thresholds::proj_thresholds_options)?;
             api.register(project::thresholds::proj_threshold_options)?;
+            api.register(project::thresholds::proj_threshold_simulate_options)?;
         }
         api.register(project::thresholds::proj_thresholds_get)?;
         api.register(project::thresholds::proj_threshold_post)?;
         api.register(project::thresholds::proj_threshold_get)?;
         api.register(project::thresholds::proj_threshold_put)?;
         api.register(project::thresholds::proj_threshold_delete)?;
+        api.register(project::thresholds::proj_threshold_simulate_post)?;
 
         // Threshold Alerts
         if http_options {
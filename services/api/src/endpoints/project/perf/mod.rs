@@ -311,6 +311,10 @@ async fn perf_query(
                     schema::model::min_sample_size,
                     schema::model::max_sample_size,
                     schema::model::window,
+                    schema::model::smoothing_factor,
+                    schema::model::violations,
+                    schema::model::violation_window,
+                    schema::model::start_point_baseline,
                     schema::model::lower_boundary,
                     schema::model::upper_boundary,
                     schema::model::created,
@@ -322,6 +326,7 @@ async fn perf_query(
                     schema::alert::boundary_id,
                     schema::alert::boundary_limit,
                     schema::alert::status,
+                    schema::alert::resolved_report_id,
                     schema::alert::modified,
                 ).nullable(),
             ).nullable(),
@@ -15,7 +15,7 @@ use crate::{
         endpoint::{CorsResponse, Get, Patch, ResponseOk},
         Endpoint,
     },
-    error::{resource_conflict_err, resource_not_found_err},
+    error::{bad_request_error, resource_conflict_err, resource_not_found_err},
     model::user::auth::{AuthUser, PubBearerToken},
     model::{
         project::{
@@ -233,7 +233,8 @@ async fn get_one_inner(
 ///
 /// Update an alert for a project.
 /// The user must have `edit` permissions for the project.
-/// Use this endpoint to dismiss an alert.
+/// Use this endpoint to dismiss or reactivate an alert.
+/// An alert can only be resolved by a later report, not manually.
 #[endpoint {
     method = PATCH,
     path =  "/v0/projects/{project}/alerts/{alert}",
@@ -271,6 +272,13 @@ async fn patch_inner(
         Permission::Edit,
     )?;
 
+    // A resolved alert must be linked to the report that resolved it.
+    if let Some(AlertStatus::Resolved) = json_alert.status {
+        return Err(bad_request_error(
+            "An alert can only be resolved by a later report, not manually",
+        ));
+    }
+
     let query_alert =
         QueryAlert::from_uuid(conn_lock!(context), query_project.id, path_params.alert)?;
     let update_alert = UpdateAlert::from(json_alert.clone());
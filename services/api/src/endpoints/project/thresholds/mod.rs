@@ -1,15 +1,17 @@
 use bencher_json::{
     project::threshold::{
-        JsonNewThreshold, JsonThreshold, JsonThresholdQuery, JsonThresholdQueryParams,
-        JsonUpdateThreshold,
+        JsonNewThreshold, JsonSimulateThreshold, JsonThreshold, JsonThresholdQuery,
+        JsonThresholdQueryParams, JsonThresholdSimulation, JsonUpdateThreshold,
     },
     JsonDirection, JsonPagination, JsonThresholds, ModelUuid, ResourceId, ThresholdUuid,
 };
 use bencher_rbac::project::Permission;
 use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
 use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use http::StatusCode;
 use schemars::JsonSchema;
 use serde::Deserialize;
+use slog::Logger;
 
 use crate::{
     conn_lock,
@@ -21,15 +23,17 @@ use crate::{
         Endpoint,
     },
     error::{
-        bad_request_error, resource_conflict_err, resource_not_found_err, resource_not_found_error,
-        BencherResource,
+        bad_request_error, issue_error, resource_conflict_err, resource_not_found_err,
+        resource_not_found_error, BencherResource,
     },
     model::{
         project::{
             branch::QueryBranch,
             measure::QueryMeasure,
             testbed::QueryTestbed,
-            threshold::{model::QueryModel, InsertThreshold, QueryThreshold},
+            threshold::{
+                model::QueryModel, simulation::SimulationHistory, InsertThreshold, QueryThreshold,
+            },
             QueryProject,
         },
         user::auth::{AuthUser, BearerToken, PubBearerToken},
@@ -436,3 +440,94 @@ async fn delete_inner(
 
     Ok(())
 }
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/thresholds/{threshold}/simulate",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_threshold_simulate_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjThresholdParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Post.into()]))
+}
+
+/// Simulate a threshold model
+///
+/// Simulate a proposed threshold model for a threshold using its historical metrics.
+/// The most recent stored metrics for the threshold branch, testbed, and measure are replayed through the proposed model,
+/// returning the boundary and any alert that would have been generated for each metric.
+/// If the proposed model does not set a maximum sample size, then each metric is compared against at most 255 preceding metrics.
+/// Nothing is saved, and the current threshold model is left unchanged.
+/// The user must have `view` permissions for the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/thresholds/{threshold}/simulate",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_threshold_simulate_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjThresholdParams>,
+    body: TypedBody<JsonSimulateThreshold>,
+) -> Result<ResponseOk<JsonThresholdSimulation>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = simulate_inner(
+        &rqctx.log,
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_ok(json))
+}
+
+async fn simulate_inner(
+    log: &Logger,
+    context: &ApiContext,
+    path_params: ProjThresholdParams,
+    json_simulate: JsonSimulateThreshold,
+    auth_user: &AuthUser,
+) -> Result<JsonThresholdSimulation, HttpError> {
+    // Validate the proposed model
+    json_simulate.model.validate().map_err(bad_request_error)?;
+
+    // Simulating a model replays the threshold history,
+    // so it is limited to authenticated users with access to the project.
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    let query_threshold =
+        QueryThreshold::get_with_uuid(conn_lock!(context), &query_project, path_params.threshold)?;
+
+    // Only hold the database connection while loading the history,
+    // not while replaying it through the proposed model.
+    let history =
+        SimulationHistory::load(conn_lock!(context), &query_threshold, json_simulate.model)?;
+
+    // Replaying the history is CPU bound,
+    // so it is run on a blocking thread instead of tying up the async executor.
+    let log = log.clone();
+    let model = json_simulate.model;
+    let threshold_uuid = query_threshold.uuid;
+    tokio::task::spawn_blocking(move || {
+        history.simulate(&log, &query_project, &query_threshold, model)
+    })
+    .await
+    .map_err(|e| {
+        issue_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to simulate threshold model",
+            &format!("Failed to simulate threshold model ({model:?}) for threshold ({threshold_uuid}) on Bencher."),
+            e,
+        )
+    })?
+}
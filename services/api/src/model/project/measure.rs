@@ -1,11 +1,16 @@
 use bencher_json::{
     project::measure::{
-        JsonUpdateMeasure, MeasureUuid, ESTIMATED_CYCLES_NAME_STR, ESTIMATED_CYCLES_SLUG_STR,
-        FILE_SIZE_NAME_STR, FILE_SIZE_SLUG_STR, INSTRUCTIONS_NAME_STR, INSTRUCTIONS_SLUG_STR,
-        L1_ACCESSES_NAME_STR, L1_ACCESSES_SLUG_STR, L2_ACCESSES_NAME_STR, L2_ACCESSES_SLUG_STR,
-        LATENCY_NAME_STR, LATENCY_SLUG_STR, MEASURE_UNITS, RAM_ACCESSES_NAME_STR,
-        RAM_ACCESSES_SLUG_STR, THROUGHPUT_NAME_STR, THROUGHPUT_SLUG_STR, TOTAL_ACCESSES_NAME_STR,
-        TOTAL_ACCESSES_SLUG_STR,
+        JsonUpdateMeasure, MeasureUuid, ALLOCATED_BYTES_NAME_STR, ALLOCATED_BYTES_SLUG_STR,
+        ALLOCATIONS_NAME_STR, ALLOCATIONS_SLUG_STR, BRANCH_MISSES_NAME_STR, BRANCH_MISSES_SLUG_STR,
+        CACHE_MISSES_NAME_STR, CACHE_MISSES_SLUG_STR, CYCLES_NAME_STR, CYCLES_SLUG_STR,
+        ERROR_RATE_NAME_STR, ERROR_RATE_SLUG_STR, ESTIMATED_CYCLES_NAME_STR,
+        ESTIMATED_CYCLES_SLUG_STR, FILE_SIZE_NAME_STR, FILE_SIZE_SLUG_STR, INSTRUCTIONS_NAME_STR,
+        INSTRUCTIONS_SLUG_STR, L1_ACCESSES_NAME_STR, L1_ACCESSES_SLUG_STR, L2_ACCESSES_NAME_STR,
+        L2_ACCESSES_SLUG_STR, LATENCY_NAME_STR, LATENCY_P50_NAME_STR, LATENCY_P50_SLUG_STR,
+        LATENCY_P90_NAME_STR, LATENCY_P90_SLUG_STR, LATENCY_P99_NAME_STR, LATENCY_P99_SLUG_STR,
+        LATENCY_SLUG_STR, MEASURE_UNITS, RAM_ACCESSES_NAME_STR, RAM_ACCESSES_SLUG_STR,
+        SYSTEM_TIME_NAME_STR, SYSTEM_TIME_SLUG_STR, THROUGHPUT_NAME_STR, THROUGHPUT_SLUG_STR,
+        TOTAL_ACCESSES_NAME_STR, TOTAL_ACCESSES_SLUG_STR, USER_TIME_NAME_STR, USER_TIME_SLUG_STR,
     },
     DateTime, JsonMeasure, JsonNewMeasure, MeasureNameId, NameIdKind, ResourceName, Slug,
 };
@@ -97,6 +102,39 @@ impl QueryMeasure {
             ESTIMATED_CYCLES_NAME_STR | ESTIMATED_CYCLES_SLUG_STR => {
                 InsertMeasure::estimated_cycles(conn, project_id)
             },
+            // Go bench
+            ALLOCATED_BYTES_NAME_STR | ALLOCATED_BYTES_SLUG_STR => {
+                InsertMeasure::allocated_bytes(conn, project_id)
+            },
+            ALLOCATIONS_NAME_STR | ALLOCATIONS_SLUG_STR => {
+                InsertMeasure::allocations(conn, project_id)
+            },
+            // perf stat
+            CYCLES_NAME_STR | CYCLES_SLUG_STR => InsertMeasure::cycles(conn, project_id),
+            BRANCH_MISSES_NAME_STR | BRANCH_MISSES_SLUG_STR => {
+                InsertMeasure::branch_misses(conn, project_id)
+            },
+            CACHE_MISSES_NAME_STR | CACHE_MISSES_SLUG_STR => {
+                InsertMeasure::cache_misses(conn, project_id)
+            },
+            // HTTP load test
+            LATENCY_P50_NAME_STR | LATENCY_P50_SLUG_STR => {
+                InsertMeasure::latency_p50(conn, project_id)
+            },
+            LATENCY_P90_NAME_STR | LATENCY_P90_SLUG_STR => {
+                InsertMeasure::latency_p90(conn, project_id)
+            },
+            LATENCY_P99_NAME_STR | LATENCY_P99_SLUG_STR => {
+                InsertMeasure::latency_p99(conn, project_id)
+            },
+            ERROR_RATE_NAME_STR | ERROR_RATE_SLUG_STR => {
+                InsertMeasure::error_rate(conn, project_id)
+            },
+            // CPU time
+            USER_TIME_NAME_STR | USER_TIME_SLUG_STR => InsertMeasure::user_time(conn, project_id),
+            SYSTEM_TIME_NAME_STR | SYSTEM_TIME_SLUG_STR => {
+                InsertMeasure::system_time(conn, project_id)
+            },
             // File size
             FILE_SIZE_NAME_STR | FILE_SIZE_SLUG_STR => InsertMeasure::file_size(conn, project_id),
             _ => {
@@ -231,6 +269,56 @@ impl InsertMeasure {
         Self::from_json(conn, project_id, JsonNewMeasure::estimated_cycles())
     }
 
+    pub fn allocated_bytes(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+    ) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::allocated_bytes())
+    }
+
+    pub fn allocations(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::allocations())
+    }
+
+    pub fn cycles(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::cycles())
+    }
+
+    pub fn branch_misses(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+    ) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::branch_misses())
+    }
+
+    pub fn cache_misses(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::cache_misses())
+    }
+
+    pub fn latency_p50(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::latency_p50())
+    }
+
+    pub fn latency_p90(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::latency_p90())
+    }
+
+    pub fn latency_p99(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::latency_p99())
+    }
+
+    pub fn error_rate(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::error_rate())
+    }
+
+    pub fn user_time(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::user_time())
+    }
+
+    pub fn system_time(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::system_time())
+    }
+
     pub fn file_size(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
         Self::from_json(conn, project_id, JsonNewMeasure::file_size())
     }
@@ -1,4 +1,4 @@
-use bencher_json::{BoundaryUuid, MetricUuid};
+use bencher_json::{Boundary, BoundaryUuid, MetricUuid};
 
 use crate::{model::project::metric::MetricId, view::metric_boundary as metric_boundary_table};
 
@@ -37,6 +37,8 @@ pub struct QueryMetricBoundary {
     pub baseline: Option<f64>,
     pub lower_limit: Option<f64>,
     pub upper_limit: Option<f64>,
+    pub adjusted_lower_boundary: Option<Boundary>,
+    pub adjusted_upper_boundary: Option<Boundary>,
 }
 
 impl QueryMetricBoundary {
@@ -56,6 +58,8 @@ impl QueryMetricBoundary {
             baseline,
             lower_limit,
             upper_limit,
+            adjusted_lower_boundary,
+            adjusted_upper_boundary,
         } = self;
         let query_metric = QueryMetric {
             id: metric_id,
@@ -78,6 +82,8 @@ impl QueryMetricBoundary {
                 baseline,
                 lower_limit,
                 upper_limit,
+                adjusted_lower_boundary,
+                adjusted_upper_boundary,
             })
         } else {
             None
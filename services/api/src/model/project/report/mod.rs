@@ -144,6 +144,10 @@ fn get_report_results(
                 schema::model::min_sample_size,
                 schema::model::max_sample_size,
                 schema::model::window,
+                schema::model::smoothing_factor,
+                schema::model::violations,
+                schema::model::violation_window,
+                schema::model::start_point_baseline,
                 schema::model::lower_boundary,
                 schema::model::upper_boundary,
                 schema::model::created,
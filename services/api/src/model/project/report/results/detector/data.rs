@@ -1,6 +1,10 @@
-use bencher_boundary::MetricsData;
+use bencher_boundary::{BoundaryCheck, MetricsData};
+use bencher_json::project::branch::VersionNumber;
 use chrono::offset::Utc;
-use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl};
+use diesel::{
+    ExpressionMethods, JoinOnDsl, NullableExpressionMethods, OptionalExtension, QueryDsl,
+    RunQueryDsl,
+};
 use dropshot::HttpError;
 use slog::{warn, Logger};
 
@@ -8,13 +12,15 @@ use crate::{
     context::DbConnection,
     error::not_found_error,
     model::project::{
-        benchmark::BenchmarkId, branch::BranchId, measure::MeasureId, testbed::TestbedId,
+        benchmark::BenchmarkId, branch::BranchId, measure::MeasureId, metric::MetricId,
+        testbed::TestbedId, threshold::model::ModelId,
     },
     schema,
 };
 
 use super::threshold::ThresholdModel;
 
+#[allow(clippy::too_many_arguments)]
 pub fn metrics_data(
     log: &Logger,
     conn: &mut DbConnection,
@@ -22,8 +28,12 @@ pub fn metrics_data(
     testbed_id: TestbedId,
     benchmark_id: BenchmarkId,
     measure_id: MeasureId,
+    exclude: &[MetricId],
     model: &ThresholdModel,
 ) -> Result<MetricsData, HttpError> {
+    let (branch_id, start_point_number) =
+        baseline_branch(conn, branch_id, model.start_point_baseline)?;
+
     let mut query =
         schema::metric::table
             .inner_join(
@@ -47,6 +57,14 @@ pub fn metrics_data(
             .filter(schema::metric::measure_id.eq(measure_id))
             .into_boxed();
 
+    if let Some(start_point_number) = start_point_number {
+        query = query.filter(schema::version::number.le(start_point_number));
+    }
+
+    if !exclude.is_empty() {
+        query = query.filter(schema::metric::id.ne_all(exclude.to_vec()));
+    }
+
     if let Some(window) = model.window {
         let now = Utc::now().timestamp();
         if let Some(start_time) = now.checked_sub(window.into()) {
@@ -66,16 +84,99 @@ pub fn metrics_data(
         schema::report_benchmark::iteration.desc(),
     ));
 
-    if let Some(max_sample_size) = model.max_sample_size {
+    if let Some(max_sample_size) = model
+        .max_sample_size
+        .or_else(|| model.test.default_max_sample_size())
+    {
         query = query.limit(max_sample_size.into());
     }
 
-    let data = query
-        .select(schema::metric::value)
-        .load::<f64>(conn)
-        .map_err(not_found_error)?
-        .into_iter()
-        .collect();
+    let metrics = query
+        .select((
+            schema::metric::value,
+            schema::metric::lower_value,
+            schema::metric::upper_value,
+        ))
+        .load::<(f64, Option<f64>, Option<f64>)>(conn)
+        .map_err(not_found_error)?;
+
+    Ok(MetricsData::new(metrics))
+}
+
+// The branch to use for the historical data, and the max version number for that branch, if any.
+// If the model uses the start point as its baseline and the branch has a start point,
+// then only the start point branch history at or before the start point version is used.
+// Otherwise, the branch's own history is used.
+pub fn baseline_branch(
+    conn: &mut DbConnection,
+    branch_id: BranchId,
+    start_point_baseline: Option<bool>,
+) -> Result<(BranchId, Option<VersionNumber>), HttpError> {
+    if !start_point_baseline.unwrap_or_default() {
+        return Ok((branch_id, None));
+    }
+
+    let start_point = schema::branch::table
+        .inner_join(
+            schema::branch_version::table
+                .on(schema::branch::start_point_id.eq(schema::branch_version::id.nullable())),
+        )
+        .inner_join(
+            schema::version::table.on(schema::branch_version::version_id.eq(schema::version::id)),
+        )
+        .filter(schema::branch::id.eq(branch_id))
+        .select((schema::branch_version::branch_id, schema::version::number))
+        .first::<(BranchId, VersionNumber)>(conn)
+        .optional()
+        .map_err(not_found_error)?;
 
-    Ok(MetricsData { data })
+    Ok(
+        start_point.map_or((branch_id, None), |(start_point_branch_id, number)| {
+            (start_point_branch_id, Some(number))
+        }),
+    )
+}
+
+// The most recent boundary checks for the benchmark with the given threshold model,
+// ordered from most recent to least recent.
+pub fn boundary_history(
+    conn: &mut DbConnection,
+    model_id: ModelId,
+    benchmark_id: BenchmarkId,
+    exclude: &[MetricId],
+    limit: usize,
+) -> Result<Vec<BoundaryCheck>, HttpError> {
+    schema::boundary::table
+        .inner_join(
+            schema::metric::table.inner_join(
+                schema::report_benchmark::table
+                    .inner_join(schema::report::table.inner_join(schema::version::table)),
+            ),
+        )
+        .filter(schema::boundary::model_id.eq(model_id))
+        .filter(schema::report_benchmark::benchmark_id.eq(benchmark_id))
+        .filter(schema::metric::id.ne_all(exclude.to_vec()))
+        .order((
+            schema::version::number.desc(),
+            schema::report::start_time.desc(),
+            schema::report_benchmark::iteration.desc(),
+        ))
+        .limit(i64::try_from(limit).unwrap_or(i64::MAX))
+        .select((
+            schema::metric::value,
+            schema::boundary::lower_limit,
+            schema::boundary::upper_limit,
+        ))
+        .load::<(f64, Option<f64>, Option<f64>)>(conn)
+        .map(|history| {
+            history
+                .into_iter()
+                .map(|(value, lower_limit, upper_limit)| BoundaryCheck {
+                    value,
+                    lower_limit,
+                    upper_limit,
+                })
+                .collect()
+        })
+        .map_err(not_found_error)
 }
@@ -0,0 +1,265 @@
+use std::collections::HashSet;
+
+use bencher_boundary::{
+    correction::{adjust_boundary, correction_factor},
+    Hysteresis, MetricsBoundary, MetricsData,
+};
+use bencher_json::{
+    project::{boundary::BoundaryLimit, report::JsonCorrection},
+    Boundary, BoundaryUuid, ModelTest,
+};
+use diesel::RunQueryDsl;
+use dropshot::HttpError;
+use slog::Logger;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    error::{bad_request_error, resource_conflict_err},
+    model::project::{
+        benchmark::BenchmarkId,
+        metric::MetricId,
+        report::ReportId,
+        threshold::{
+            alert::{InsertAlert, UpdateAlert},
+            boundary::InsertBoundary,
+            ThresholdId,
+        },
+    },
+    schema,
+};
+
+use super::{data::boundary_history, Detector};
+
+/// `Detection` is a boundary check that has not yet been inserted.
+/// This allows a correction for multiple comparisons to be applied across the entire report.
+pub struct Detection {
+    detector: Detector,
+    benchmark_id: BenchmarkId,
+    /// Every metric that shares the boundary.
+    /// Only the last metric can have an alert.
+    metric_ids: Vec<MetricId>,
+    sample: Vec<f64>,
+    datum_spread: Option<f64>,
+    metrics_data: MetricsData,
+    boundary: MetricsBoundary,
+    adjusted_lower_boundary: Option<Boundary>,
+    adjusted_upper_boundary: Option<Boundary>,
+}
+
+impl Detection {
+    pub fn new(
+        log: &Logger,
+        detector: Detector,
+        benchmark_id: BenchmarkId,
+        metric_ids: Vec<MetricId>,
+        sample: Vec<f64>,
+        datum_spread: Option<f64>,
+        metrics_data: MetricsData,
+    ) -> Result<Self, HttpError> {
+        let model = &detector.threshold.model;
+        let boundary = new_boundary(
+            log,
+            &detector,
+            &sample,
+            datum_spread,
+            &metrics_data,
+            model.lower_boundary,
+            model.upper_boundary,
+        )?;
+        Ok(Self {
+            detector,
+            benchmark_id,
+            metric_ids,
+            sample,
+            datum_spread,
+            metrics_data,
+            boundary,
+            adjusted_lower_boundary: None,
+            adjusted_upper_boundary: None,
+        })
+    }
+
+    fn significance_ratio(&self) -> Option<f64> {
+        let model = &self.detector.threshold.model;
+        self.boundary
+            .significance_ratio(model.lower_boundary, model.upper_boundary)
+    }
+
+    // Correct every detection that has a p-value for multiple comparisons.
+    // Detections for model tests without a p-value are left as is.
+    pub fn correct(
+        log: &Logger,
+        detections: &mut [Self],
+        correction: JsonCorrection,
+    ) -> Result<(), HttpError> {
+        let significance_ratios = detections
+            .iter()
+            .filter_map(Self::significance_ratio)
+            .collect::<Vec<_>>();
+        let factor = correction_factor(correction, &significance_ratios);
+        slog::debug!(
+            log,
+            "Correction ({correction:?}) for {} tests: factor={factor}",
+            significance_ratios.len()
+        );
+
+        for detection in detections {
+            if detection.significance_ratio().is_none() {
+                continue;
+            }
+            let model = &detection.detector.threshold.model;
+            let adjusted_lower_boundary = model
+                .lower_boundary
+                .and_then(|boundary| adjust_boundary(boundary, factor));
+            let adjusted_upper_boundary = model
+                .upper_boundary
+                .and_then(|boundary| adjust_boundary(boundary, factor));
+            detection.boundary = new_boundary(
+                log,
+                &detection.detector,
+                &detection.sample,
+                detection.datum_spread,
+                &detection.metrics_data,
+                adjusted_lower_boundary,
+                adjusted_upper_boundary,
+            )?;
+            detection.adjusted_lower_boundary = adjusted_lower_boundary;
+            detection.adjusted_upper_boundary = adjusted_upper_boundary;
+        }
+
+        Ok(())
+    }
+
+    // Alerts for each threshold and benchmark are resolved at most once per report,
+    // so the resolved set is shared across all of the detections for the report.
+    pub async fn insert(
+        self,
+        context: &ApiContext,
+        report_id: ReportId,
+        resolved: &mut HashSet<(ThresholdId, BenchmarkId)>,
+    ) -> Result<(), HttpError> {
+        let Some((last_metric_id, metric_ids)) = self.metric_ids.split_last() else {
+            return Ok(());
+        };
+
+        // Every metric in the sample shares the same boundary,
+        // but only a single alert is created for the entire sample.
+        for metric_id in metric_ids {
+            self.insert_boundary(context, *metric_id).await?;
+        }
+        let boundary_uuid = self.insert_boundary(context, *last_metric_id).await?;
+
+        // If the boundary check detects an outlier then create an alert for it on the given side,
+        // as long as the outlier has persisted for long enough.
+        // Otherwise, if the benchmark is back within its limits then resolve any active alerts.
+        // Change point detection only flags the metric that begins a new segment,
+        // so a metric without an outlier does not mean that the benchmark is back within its limits.
+        let Some(boundary_limit) = self.boundary.outlier else {
+            let threshold_id = self.detector.threshold.id;
+            if self.detector.threshold.model.test != ModelTest::ChangePoint
+                && (self.boundary.limits.lower.is_some() || self.boundary.limits.upper.is_some())
+                && resolved.insert((threshold_id, self.benchmark_id))
+            {
+                UpdateAlert::resolve(
+                    conn_lock!(context),
+                    threshold_id,
+                    self.benchmark_id,
+                    report_id,
+                )?;
+            }
+            return Ok(());
+        };
+        if !self.persists(context, boundary_limit).await? {
+            return Ok(());
+        }
+        InsertAlert::from_boundary(conn_lock!(context), boundary_uuid, boundary_limit)
+    }
+
+    async fn persists(
+        &self,
+        context: &ApiContext,
+        boundary_limit: BoundaryLimit,
+    ) -> Result<bool, HttpError> {
+        let model = &self.detector.threshold.model;
+        let Some(hysteresis) =
+            Hysteresis::new(model.test, model.violations, model.violation_window)
+        else {
+            return Ok(true);
+        };
+        let history = boundary_history(
+            conn_lock!(context),
+            model.id,
+            self.benchmark_id,
+            &self.metric_ids,
+            hysteresis.history_len(),
+        )?;
+        Ok(hysteresis.persists(boundary_limit, history))
+    }
+
+    async fn insert_boundary(
+        &self,
+        context: &ApiContext,
+        metric_id: MetricId,
+    ) -> Result<BoundaryUuid, HttpError> {
+        let boundary_uuid = BoundaryUuid::new();
+        let insert_boundary = InsertBoundary {
+            uuid: boundary_uuid,
+            threshold_id: self.detector.threshold.id,
+            model_id: self.detector.threshold.model.id,
+            metric_id,
+            baseline: self.boundary.limits.baseline,
+            lower_limit: self.boundary.limits.lower.as_ref().map(|limit| limit.value),
+            upper_limit: self.boundary.limits.upper.as_ref().map(|limit| limit.value),
+            adjusted_lower_boundary: self.adjusted_lower_boundary,
+            adjusted_upper_boundary: self.adjusted_upper_boundary,
+        };
+
+        diesel::insert_into(schema::boundary::table)
+            .values(&insert_boundary)
+            .execute(conn_lock!(context))
+            .map_err(resource_conflict_err!(Boundary, insert_boundary))?;
+
+        Ok(boundary_uuid)
+    }
+}
+
+fn new_boundary(
+    log: &Logger,
+    detector: &Detector,
+    sample: &[f64],
+    datum_spread: Option<f64>,
+    metrics_data: &MetricsData,
+    lower_boundary: Option<Boundary>,
+    upper_boundary: Option<Boundary>,
+) -> Result<MetricsBoundary, HttpError> {
+    let model = &detector.threshold.model;
+    let boundary = if model.test.is_two_sample() {
+        MetricsBoundary::new_sample(
+            log,
+            sample,
+            metrics_data,
+            model.test,
+            model.min_sample_size,
+            model.smoothing_factor,
+            lower_boundary,
+            upper_boundary,
+        )
+    } else {
+        let Some(&datum) = sample.last() else {
+            return Ok(MetricsBoundary::default());
+        };
+        MetricsBoundary::new(
+            log,
+            datum,
+            datum_spread,
+            metrics_data,
+            model.test,
+            model.min_sample_size,
+            model.smoothing_factor,
+            lower_boundary,
+            upper_boundary,
+        )
+    };
+    boundary.map_err(bad_request_error)
+}
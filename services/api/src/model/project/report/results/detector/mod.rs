@@ -1,28 +1,23 @@
-use bencher_boundary::MetricsBoundary;
-use bencher_json::BoundaryUuid;
-use diesel::RunQueryDsl;
+use bencher_boundary::metric_spread;
+use bencher_json::ModelTest;
 use dropshot::HttpError;
 use slog::Logger;
 
 use crate::{
     conn_lock,
     context::{ApiContext, DbConnection},
-    error::{bad_request_error, resource_conflict_err},
     model::project::{
-        benchmark::BenchmarkId,
-        branch::BranchId,
-        measure::MeasureId,
-        metric::QueryMetric,
+        benchmark::BenchmarkId, branch::BranchId, measure::MeasureId, metric::QueryMetric,
         testbed::TestbedId,
-        threshold::{alert::InsertAlert, boundary::InsertBoundary},
     },
-    schema,
 };
 
 pub mod data;
+pub mod detection;
 pub mod threshold;
 
 use data::metrics_data;
+pub use detection::Detection;
 use threshold::Threshold;
 
 #[derive(Debug, Clone)]
@@ -56,7 +51,17 @@ impl Detector {
         context: &ApiContext,
         benchmark_id: BenchmarkId,
         query_metric: &QueryMetric,
-    ) -> Result<(), HttpError> {
+    ) -> Result<Detection, HttpError> {
+        // The change point and EWMA models compare the new metric against the series that precedes it,
+        // so the new metric must not be included in its own history.
+        let exclude = if matches!(
+            self.threshold.model.test,
+            ModelTest::ChangePoint | ModelTest::Ewma
+        ) {
+            vec![query_metric.id]
+        } else {
+            Vec::new()
+        };
         // Query the historical population/sample data for the benchmark
         let metrics_data = metrics_data(
             log,
@@ -65,42 +70,69 @@ impl Detector {
             self.testbed_id,
             benchmark_id,
             self.measure_id,
+            &exclude,
             &self.threshold.model,
         )?;
 
         // Check to see if the metric has a boundary check for the given threshold model.
-        let boundary = MetricsBoundary::new(
+        // The boundary is not inserted until any correction for the entire report has been applied.
+        Detection::new(
             log,
-            query_metric.value,
-            &metrics_data,
-            self.threshold.model.test,
-            self.threshold.model.min_sample_size,
-            self.threshold.model.lower_boundary,
-            self.threshold.model.upper_boundary,
+            self.clone(),
+            benchmark_id,
+            vec![query_metric.id],
+            vec![query_metric.value],
+            metric_spread(
+                query_metric.value,
+                query_metric.lower_value,
+                query_metric.upper_value,
+            ),
+            metrics_data,
         )
-        .map_err(bad_request_error)?;
+    }
 
-        let boundary_uuid = BoundaryUuid::new();
-        let insert_boundary = InsertBoundary {
-            uuid: boundary_uuid,
-            threshold_id: self.threshold.id,
-            model_id: self.threshold.model.id,
-            metric_id: query_metric.id,
-            baseline: boundary.limits.baseline,
-            lower_limit: boundary.limits.lower.map(Into::into),
-            upper_limit: boundary.limits.upper.map(Into::into),
-        };
+    pub async fn detect_sample(
+        &self,
+        log: &Logger,
+        context: &ApiContext,
+        benchmark_id: BenchmarkId,
+        sample: &[QueryMetric],
+    ) -> Result<Option<Detection>, HttpError> {
+        if sample.is_empty() {
+            return Ok(None);
+        }
 
-        diesel::insert_into(schema::boundary::table)
-            .values(&insert_boundary)
-            .execute(conn_lock!(context))
-            .map_err(resource_conflict_err!(Boundary, insert_boundary))?;
+        // Query the historical population/sample data for the benchmark
+        // The sample itself must not be included in its own history.
+        let exclude = sample
+            .iter()
+            .map(|query_metric| query_metric.id)
+            .collect::<Vec<_>>();
+        let metrics_data = metrics_data(
+            log,
+            conn_lock!(context),
+            self.branch_id,
+            self.testbed_id,
+            benchmark_id,
+            self.measure_id,
+            &exclude,
+            &self.threshold.model,
+        )?;
 
-        // If the boundary check detects an outlier then create an alert for it on the given side.
-        if let Some(boundary_limit) = boundary.outlier {
-            InsertAlert::from_boundary(conn_lock!(context), boundary_uuid, boundary_limit)
-        } else {
-            Ok(())
-        }
+        // Check to see if the sample has a boundary check for the given threshold model.
+        let values = sample
+            .iter()
+            .map(|query_metric| query_metric.value)
+            .collect::<Vec<_>>();
+        Detection::new(
+            log,
+            self.clone(),
+            benchmark_id,
+            exclude,
+            values,
+            None,
+            metrics_data,
+        )
+        .map(Some)
     }
 }
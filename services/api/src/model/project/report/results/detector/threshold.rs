@@ -1,4 +1,4 @@
-use bencher_json::{Boundary, ModelTest, SampleSize, Window};
+use bencher_json::{Boundary, ModelTest, SampleSize, SmoothingFactor, Violations, Window};
 use diesel::{
     ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl, RunQueryDsl,
     SelectableHelper,
@@ -31,6 +31,10 @@ pub struct ThresholdModel {
     pub min_sample_size: Option<SampleSize>,
     pub max_sample_size: Option<SampleSize>,
     pub window: Option<Window>,
+    pub smoothing_factor: Option<SmoothingFactor>,
+    pub violations: Option<Violations>,
+    pub violation_window: Option<Violations>,
+    pub start_point_baseline: Option<bool>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
 }
@@ -59,6 +63,10 @@ impl Threshold {
                     min_sample_size,
                     max_sample_size,
                     window,
+                    smoothing_factor,
+                    violations,
+                    violation_window,
+                    start_point_baseline,
                     lower_boundary,
                     upper_boundary,
                     ..
@@ -69,6 +77,10 @@ impl Threshold {
                     min_sample_size,
                     max_sample_size,
                     window,
+                    smoothing_factor,
+                    violations,
+                    violation_window,
+                    start_point_baseline,
                     lower_boundary,
                     upper_boundary,
                 };
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use bencher_adapter::{
     results::adapter_metrics::AdapterMetrics, AdapterResults, AdapterResultsArray,
@@ -31,7 +31,7 @@ use crate::{
 
 pub mod detector;
 
-use detector::Detector;
+use detector::{Detection, Detector};
 
 use super::ReportId;
 
@@ -44,6 +44,8 @@ pub struct ReportResults {
     pub benchmark_cache: HashMap<BenchmarkName, BenchmarkId>,
     pub measure_cache: HashMap<MeasureNameId, MeasureId>,
     pub detector_cache: HashMap<MeasureId, Option<Detector>>,
+    pub sample_cache: BTreeMap<(BenchmarkId, MeasureId), Vec<QueryMetric>>,
+    pub detections: Vec<Detection>,
 }
 
 impl ReportResults {
@@ -61,6 +63,8 @@ impl ReportResults {
             benchmark_cache: HashMap::new(),
             measure_cache: HashMap::new(),
             detector_cache: HashMap::new(),
+            sample_cache: BTreeMap::new(),
+            detections: Vec::new(),
         }
     }
 
@@ -106,7 +110,8 @@ impl ReportResults {
             }
         };
 
-        Ok(())
+        self.detect_samples(log, context).await?;
+        self.insert_detections(log, context, settings).await
     }
 
     async fn results(
@@ -185,12 +190,63 @@ impl ReportResults {
                         e,
                     )
                 })?;
-                detector
-                    .detect(log, context, benchmark_id, &query_metric)
-                    .await?;
+                // Two-sample tests are run once all of the iterations have been processed
+                if detector.threshold.model.test.is_two_sample() {
+                    self.sample_cache
+                        .entry((benchmark_id, measure_id))
+                        .or_default()
+                        .push(query_metric);
+                } else {
+                    let detection = detector
+                        .detect(log, context, benchmark_id, &query_metric)
+                        .await?;
+                    self.detections.push(detection);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn detect_samples(
+        &mut self,
+        log: &Logger,
+        context: &ApiContext,
+    ) -> Result<(), HttpError> {
+        // The samples are ordered by benchmark and measure,
+        // so the detections for the report are always created in the same order.
+        for ((benchmark_id, measure_id), sample) in std::mem::take(&mut self.sample_cache) {
+            let Some(detector) = self.detector(context, measure_id).await else {
+                continue;
+            };
+            if let Some(detection) = detector
+                .detect_sample(log, context, benchmark_id, &sample)
+                .await?
+            {
+                self.detections.push(detection);
             }
         }
+        Ok(())
+    }
 
+    // Any correction for multiple comparisons must be applied across the entire report
+    // before the boundaries and alerts are inserted.
+    async fn insert_detections(
+        &mut self,
+        log: &Logger,
+        context: &ApiContext,
+        settings: JsonReportSettings,
+    ) -> Result<(), HttpError> {
+        let mut detections = std::mem::take(&mut self.detections);
+        if let Some(correction) = settings.correction {
+            Detection::correct(log, &mut detections, correction)?;
+        }
+        let mut resolved = HashSet::new();
+        for detection in detections {
+            detection
+                .insert(context, self.report_id, &mut resolved)
+                .await?;
+        }
         Ok(())
     }
 
@@ -11,12 +11,17 @@ use dropshot::HttpError;
 
 use super::{
     boundary::{BoundaryId, QueryBoundary},
-    QueryThreshold,
+    QueryThreshold, ThresholdId,
 };
 use crate::{
     context::DbConnection,
     error::{resource_conflict_err, resource_not_found_err},
-    model::project::{benchmark::QueryBenchmark, metric::QueryMetric, ProjectId, QueryProject},
+    model::project::{
+        benchmark::{BenchmarkId, QueryBenchmark},
+        metric::QueryMetric,
+        report::{QueryReport, ReportId},
+        ProjectId, QueryProject,
+    },
     schema::alert as alert_table,
     schema::{self},
     util::fn_get::{fn_get, fn_get_id, fn_get_uuid},
@@ -32,6 +37,7 @@ pub struct QueryAlert {
     pub boundary_id: BoundaryId,
     pub boundary_limit: BoundaryLimit,
     pub status: AlertStatus,
+    pub resolved_report_id: Option<ReportId>,
     pub modified: DateTime,
 }
 
@@ -117,9 +123,13 @@ impl QueryAlert {
             uuid,
             boundary_limit,
             status,
+            resolved_report_id,
             modified,
             ..
         } = self;
+        let resolved_report = resolved_report_id
+            .map(|report_id| QueryReport::get_uuid(conn, report_id))
+            .transpose()?;
         let threshold_id = query_boundary.threshold_id;
         let model_id = query_boundary.model_id;
         let benchmark =
@@ -132,6 +142,7 @@ impl QueryAlert {
             benchmark,
             limit: boundary_limit,
             status,
+            resolved_report,
             created,
             modified,
         })
@@ -161,6 +172,7 @@ pub struct InsertAlert {
     pub boundary_id: BoundaryId,
     pub boundary_limit: BoundaryLimit,
     pub status: AlertStatus,
+    pub resolved_report_id: Option<ReportId>,
     pub modified: DateTime,
 }
 
@@ -175,6 +187,7 @@ impl InsertAlert {
             boundary_id: QueryBoundary::get_id(conn, boundary_uuid)?,
             boundary_limit,
             status: AlertStatus::default(),
+            resolved_report_id: None,
             modified: DateTime::now(),
         };
 
@@ -191,15 +204,56 @@ impl InsertAlert {
 #[diesel(table_name = alert_table)]
 pub struct UpdateAlert {
     pub status: Option<AlertStatus>,
+    pub resolved_report_id: Option<Option<ReportId>>,
     pub modified: DateTime,
 }
 
 impl From<JsonUpdateAlert> for UpdateAlert {
     fn from(update: JsonUpdateAlert) -> Self {
         let JsonUpdateAlert { status } = update;
+        // Manually reactivating or dismissing an alert unlinks any resolving report
+        let resolved_report_id = match status {
+            Some(AlertStatus::Active | AlertStatus::Dismissed) => Some(None),
+            Some(AlertStatus::Resolved) | None => None,
+        };
         Self {
             status,
+            resolved_report_id,
             modified: DateTime::now(),
         }
     }
 }
+
+impl UpdateAlert {
+    // Resolve any active alerts for the threshold and benchmark that were raised by earlier reports.
+    // Alerts raised by the given report are left as is,
+    // so that the iterations of a single report do not resolve each other.
+    pub fn resolve(
+        conn: &mut DbConnection,
+        threshold_id: ThresholdId,
+        benchmark_id: BenchmarkId,
+        report_id: ReportId,
+    ) -> Result<(), HttpError> {
+        let boundary_ids = schema::boundary::table
+            .inner_join(schema::metric::table.inner_join(schema::report_benchmark::table))
+            .filter(schema::boundary::threshold_id.eq(threshold_id))
+            .filter(schema::report_benchmark::benchmark_id.eq(benchmark_id))
+            .filter(schema::report_benchmark::report_id.ne(report_id))
+            .select(schema::boundary::id);
+        let update_alert = Self {
+            status: Some(AlertStatus::Resolved),
+            resolved_report_id: Some(Some(report_id)),
+            modified: DateTime::now(),
+        };
+        diesel::update(
+            schema::alert::table
+                .filter(schema::alert::boundary_id.eq_any(boundary_ids))
+                .filter(schema::alert::status.eq(AlertStatus::Active)),
+        )
+        .set(&update_alert)
+        .execute(conn)
+        .map_err(resource_conflict_err!(Alert, update_alert))?;
+
+        Ok(())
+    }
+}
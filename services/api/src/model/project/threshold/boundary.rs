@@ -1,4 +1,4 @@
-use bencher_json::{project::boundary::JsonBoundary, BoundaryUuid};
+use bencher_json::{project::boundary::JsonBoundary, Boundary, BoundaryUuid};
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
 
@@ -26,6 +26,8 @@ pub struct QueryBoundary {
     pub baseline: Option<f64>,
     pub lower_limit: Option<f64>,
     pub upper_limit: Option<f64>,
+    pub adjusted_lower_boundary: Option<Boundary>,
+    pub adjusted_upper_boundary: Option<Boundary>,
 }
 
 impl QueryBoundary {
@@ -45,6 +47,8 @@ impl QueryBoundary {
             baseline: self.baseline.map(Into::into),
             lower_limit: self.lower_limit.map(Into::into),
             upper_limit: self.upper_limit.map(Into::into),
+            adjusted_lower_boundary: self.adjusted_lower_boundary,
+            adjusted_upper_boundary: self.adjusted_upper_boundary,
         }
     }
 }
@@ -59,4 +63,6 @@ pub struct InsertBoundary {
     pub baseline: Option<f64>,
     pub lower_limit: Option<f64>,
     pub upper_limit: Option<f64>,
+    pub adjusted_lower_boundary: Option<Boundary>,
+    pub adjusted_upper_boundary: Option<Boundary>,
 }
@@ -27,6 +27,7 @@ use crate::{
 pub mod alert;
 pub mod boundary;
 pub mod model;
+pub mod simulation;
 
 crate::util::typed_id::typed_id!(ThresholdId);
 
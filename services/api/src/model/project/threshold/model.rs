@@ -1,5 +1,6 @@
 use bencher_json::{
-    Boundary, DateTime, JsonModel, Model, ModelTest, ModelUuid, SampleSize, Window,
+    Boundary, DateTime, JsonModel, Model, ModelTest, ModelUuid, SampleSize, SmoothingFactor,
+    Violations, Window,
 };
 use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl, SelectableHelper};
 use dropshot::HttpError;
@@ -26,6 +27,10 @@ pub struct QueryModel {
     pub min_sample_size: Option<SampleSize>,
     pub max_sample_size: Option<SampleSize>,
     pub window: Option<Window>,
+    pub smoothing_factor: Option<SmoothingFactor>,
+    pub violations: Option<Violations>,
+    pub violation_window: Option<Violations>,
+    pub start_point_baseline: Option<bool>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
     pub created: DateTime,
@@ -66,6 +71,10 @@ impl QueryModel {
             min_sample_size,
             max_sample_size,
             window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
             lower_boundary,
             upper_boundary,
             created,
@@ -85,6 +94,10 @@ impl QueryModel {
             min_sample_size,
             max_sample_size,
             window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
             lower_boundary,
             upper_boundary,
             created,
@@ -102,6 +115,10 @@ pub struct InsertModel {
     pub min_sample_size: Option<SampleSize>,
     pub max_sample_size: Option<SampleSize>,
     pub window: Option<Window>,
+    pub smoothing_factor: Option<SmoothingFactor>,
+    pub violations: Option<Violations>,
+    pub violation_window: Option<Violations>,
+    pub start_point_baseline: Option<bool>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
     pub created: DateTime,
@@ -116,6 +133,10 @@ impl From<QueryModel> for InsertModel {
             min_sample_size,
             max_sample_size,
             window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
             lower_boundary,
             upper_boundary,
             created,
@@ -129,6 +150,10 @@ impl From<QueryModel> for InsertModel {
             min_sample_size,
             max_sample_size,
             window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
             lower_boundary,
             upper_boundary,
             created,
@@ -144,6 +169,10 @@ impl InsertModel {
             min_sample_size,
             max_sample_size,
             window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
             lower_boundary,
             upper_boundary,
         } = model;
@@ -154,6 +183,10 @@ impl InsertModel {
             min_sample_size,
             max_sample_size,
             window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
             lower_boundary,
             upper_boundary,
             created: DateTime::now(),
@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+
+use bencher_boundary::simulation::{simulate, SimulatedBoundary, SimulationMetric};
+use bencher_json::{
+    project::{
+        boundary::JsonBoundary,
+        branch::VersionNumber,
+        report::Iteration,
+        threshold::{JsonSimulatedBoundary, JsonThresholdSimulation},
+    },
+    DateTime, Model, ModelTest, ReportUuid,
+};
+use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl, SelectableHelper};
+use dropshot::HttpError;
+use slog::Logger;
+
+use super::QueryThreshold;
+use crate::{
+    context::DbConnection,
+    error::{bad_request_error, resource_not_found_err},
+    model::project::{
+        benchmark::{BenchmarkId, QueryBenchmark},
+        branch::BranchId,
+        metric::QueryMetric,
+        report::{results::detector::data::baseline_branch, ReportId},
+        QueryProject,
+    },
+    schema,
+};
+
+type SimulationQuery = (
+    ReportId,
+    ReportUuid,
+    DateTime,
+    Iteration,
+    QueryBenchmark,
+    QueryMetric,
+);
+
+/// The maximum number of the most recent metrics that are replayed for a simulation.
+/// The baseline history, if any, is limited to the same number of metrics.
+const MAX_SIMULATION_METRICS: i64 = 10_000;
+/// Change point detection and bootstrapping are much more expensive for each replayed metric,
+/// so far fewer metrics are replayed for them.
+const MAX_EXPENSIVE_SIMULATION_METRICS: i64 = 1_000;
+
+/// The stored metrics for a threshold that are replayed through a proposed model.
+pub struct SimulationHistory {
+    history: Vec<SimulationQuery>,
+    baselines: Option<HashMap<BenchmarkId, Vec<SimulationMetric>>>,
+}
+
+impl SimulationHistory {
+    // If the model uses the start point as its baseline,
+    // then only the metrics added to the branch after its start point are replayed,
+    // and they are all compared against the start point branch history.
+    pub fn load(
+        conn: &mut DbConnection,
+        query_threshold: &QueryThreshold,
+        model: Model,
+    ) -> Result<Self, HttpError> {
+        let (baseline_branch_id, start_point_number) =
+            baseline_branch(conn, query_threshold.branch_id, model.start_point_baseline)?;
+        let limit = max_simulation_metrics(model.test);
+        let history = load_history(
+            conn,
+            query_threshold,
+            query_threshold.branch_id,
+            start_point_number,
+            None,
+            limit,
+        )?;
+        let baselines = if start_point_number.is_some() {
+            let baseline_history = load_history(
+                conn,
+                query_threshold,
+                baseline_branch_id,
+                None,
+                start_point_number,
+                limit,
+            )?;
+            Some(
+                baseline_history
+                    .chunk_by(same_benchmark)
+                    .filter_map(|benchmark_history| {
+                        let (_, _, _, _, query_benchmark, _) = benchmark_history.first()?;
+                        Some((query_benchmark.id, simulation_metrics(benchmark_history)))
+                    })
+                    .collect(),
+            )
+        } else {
+            None
+        };
+        Ok(Self { history, baselines })
+    }
+
+    // Replay the stored metrics for the threshold through the proposed model.
+    // Nothing is written to the database, so no database connection is needed.
+    pub fn simulate(
+        self,
+        log: &Logger,
+        project: &QueryProject,
+        query_threshold: &QueryThreshold,
+        model: Model,
+    ) -> Result<JsonThresholdSimulation, HttpError> {
+        let Self { history, baselines } = self;
+
+        // Each benchmark is replayed separately, as they each have their own history.
+        let mut simulated = Vec::with_capacity(history.len());
+        for benchmark_history in history.chunk_by(same_benchmark) {
+            let Some((_, _, _, _, query_benchmark, _)) = benchmark_history.first() else {
+                continue;
+            };
+            let baseline = baselines.as_ref().map(|baselines| {
+                baselines
+                    .get(&query_benchmark.id)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+            });
+            let metrics = simulation_metrics(benchmark_history);
+            simulated.extend(simulate(log, model, baseline, &metrics).map_err(bad_request_error)?);
+        }
+
+        let mut alerts = 0u64;
+        let mut boundaries = Vec::with_capacity(history.len());
+        for ((_, report_uuid, _, iteration, query_benchmark, query_metric), boundary) in
+            history.into_iter().zip(simulated)
+        {
+            let SimulatedBoundary {
+                baseline,
+                lower_limit,
+                upper_limit,
+                alert,
+                ..
+            } = boundary;
+            if alert.is_some() {
+                alerts += 1;
+            }
+            let mut benchmark =
+                query_benchmark.into_benchmark_metric_json(project, query_metric, None);
+            benchmark.boundary = Some(JsonBoundary {
+                baseline: baseline.map(Into::into),
+                lower_limit: lower_limit.map(Into::into),
+                upper_limit: upper_limit.map(Into::into),
+                adjusted_lower_boundary: None,
+                adjusted_upper_boundary: None,
+            });
+            boundaries.push(JsonSimulatedBoundary {
+                report: report_uuid,
+                iteration,
+                benchmark,
+                alert,
+            });
+        }
+
+        Ok(JsonThresholdSimulation {
+            threshold: query_threshold.uuid,
+            alerts: alerts.into(),
+            boundaries,
+        })
+    }
+}
+
+fn max_simulation_metrics(test: ModelTest) -> i64 {
+    match test {
+        ModelTest::ChangePoint | ModelTest::BootstrapMean | ModelTest::BootstrapMedian => {
+            MAX_EXPENSIVE_SIMULATION_METRICS
+        },
+        ModelTest::Static
+        | ModelTest::Percentage
+        | ModelTest::ZScore
+        | ModelTest::TTest
+        | ModelTest::LogNormal
+        | ModelTest::Iqr
+        | ModelTest::DeltaIqr
+        | ModelTest::WelchTTest
+        | ModelTest::MannWhitneyU
+        | ModelTest::Ewma
+        | ModelTest::InverseVariance => MAX_SIMULATION_METRICS,
+    }
+}
+
+// The most recent metrics for the threshold on the given branch,
+// after and through the given version numbers, if any, up to the given limit.
+// The metrics are grouped by benchmark, and ordered from least recent to most recent.
+fn load_history(
+    conn: &mut DbConnection,
+    query_threshold: &QueryThreshold,
+    branch_id: BranchId,
+    after_number: Option<VersionNumber>,
+    through_number: Option<VersionNumber>,
+    limit: i64,
+) -> Result<Vec<SimulationQuery>, HttpError> {
+    let mut query = schema::metric::table
+        .inner_join(
+            schema::report_benchmark::table
+                .inner_join(
+                    schema::report::table.inner_join(
+                        schema::version::table.inner_join(
+                            schema::branch_version::table.inner_join(
+                                schema::branch::table
+                                    .on(schema::branch_version::branch_id.eq(schema::branch::id)),
+                            ),
+                        ),
+                    ),
+                )
+                .inner_join(schema::benchmark::table),
+        )
+        .filter(schema::branch::id.eq(branch_id))
+        .filter(schema::report::testbed_id.eq(query_threshold.testbed_id))
+        .filter(schema::metric::measure_id.eq(query_threshold.measure_id))
+        .into_boxed();
+
+    if let Some(after_number) = after_number {
+        query = query.filter(schema::version::number.gt(after_number));
+    }
+    if let Some(through_number) = through_number {
+        query = query.filter(schema::version::number.le(through_number));
+    }
+
+    let mut history = query
+        .order((
+            schema::version::number.desc(),
+            schema::report::start_time.desc(),
+            schema::report::id.desc(),
+            schema::report_benchmark::iteration.desc(),
+        ))
+        .limit(limit)
+        .select((
+            schema::report::id,
+            schema::report::uuid,
+            schema::report::start_time,
+            schema::report_benchmark::iteration,
+            QueryBenchmark::as_select(),
+            QueryMetric::as_select(),
+        ))
+        .load::<SimulationQuery>(conn)
+        .map_err(resource_not_found_err!(Metric, query_threshold))?;
+    // The sort is stable, so each benchmark stays ordered from least recent to most recent.
+    history.reverse();
+    history.sort_by_key(|(_, _, _, _, query_benchmark, _)| i32::from(query_benchmark.id));
+    Ok(history)
+}
+
+fn same_benchmark(a: &SimulationQuery, b: &SimulationQuery) -> bool {
+    let (_, _, _, _, a, _) = a;
+    let (_, _, _, _, b, _) = b;
+    a.id == b.id
+}
+
+// Metrics from the same report share a sample.
+fn simulation_metrics(benchmark_history: &[SimulationQuery]) -> Vec<SimulationMetric> {
+    let mut sample = 0;
+    let mut prev_report_id = None;
+    benchmark_history
+        .iter()
+        .map(|(report_id, _, start_time, _, _, query_metric)| {
+            if prev_report_id.is_some_and(|prev_report_id| prev_report_id != *report_id) {
+                sample += 1;
+            }
+            prev_report_id = Some(*report_id);
+            SimulationMetric {
+                sample,
+                start_time: start_time.timestamp(),
+                value: query_metric.value,
+                lower_value: query_metric.lower_value,
+                upper_value: query_metric.upper_value,
+            }
+        })
+        .collect()
+}
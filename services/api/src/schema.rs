@@ -7,6 +7,7 @@ diesel::table! {
         boundary_id -> Integer,
         boundary_limit -> Bool,
         status -> Integer,
+        resolved_report_id -> Nullable<Integer>,
         modified -> BigInt,
     }
 }
@@ -33,6 +34,8 @@ diesel::table! {
         baseline -> Nullable<Double>,
         lower_limit -> Nullable<Double>,
         upper_limit -> Nullable<Double>,
+        adjusted_lower_boundary -> Nullable<Double>,
+        adjusted_upper_boundary -> Nullable<Double>,
     }
 }
 
@@ -91,6 +94,10 @@ diesel::table! {
         min_sample_size -> Nullable<BigInt>,
         max_sample_size -> Nullable<BigInt>,
         window -> Nullable<BigInt>,
+        smoothing_factor -> Nullable<Double>,
+        violations -> Nullable<BigInt>,
+        violation_window -> Nullable<BigInt>,
+        start_point_baseline -> Nullable<Bool>,
         lower_boundary -> Nullable<Double>,
         upper_boundary -> Nullable<Double>,
         created -> BigInt,
@@ -8,6 +8,8 @@ macro_rules! typed_id {
             Default,
             PartialEq,
             Eq,
+            PartialOrd,
+            Ord,
             Hash,
             derive_more::Display,
             diesel::FromSqlRow,
@@ -19,6 +19,8 @@ diesel::table! {
         baseline -> Nullable<Double>,
         lower_limit -> Nullable<Double>,
         upper_limit -> Nullable<Double>,
+        adjusted_lower_boundary -> Nullable<Double>,
+        adjusted_upper_boundary -> Nullable<Double>,
     }
 }
 
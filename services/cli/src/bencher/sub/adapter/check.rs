@@ -0,0 +1,93 @@
+use bencher_adapter::{diagnose, Adaptable, AdapterCustom, AdapterDiagnostics, Settings};
+use bencher_json::project::report::{Adapter, JsonAverage};
+use camino::Utf8PathBuf;
+
+use crate::{bencher::sub::SubCmd, cli_println, parser::adapter::CliAdapterCheck, CliError};
+
+use super::custom::{read_custom_adapter, CustomAdapterError};
+
+#[derive(Debug)]
+pub struct Check {
+    pub file: Utf8PathBuf,
+    pub adapter: Adapter,
+    pub average: Option<JsonAverage>,
+    pub custom_adapter: Option<AdapterCustom>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CheckError {
+    #[error("Failed to read benchmark harness output file ({path}): {err}")]
+    ReadFile {
+        path: Utf8PathBuf,
+        err: std::io::Error,
+    },
+    #[error("Failed to parse benchmark harness output with the {adapter} adapter:\n{diagnostics}")]
+    Diagnostics {
+        adapter: Adapter,
+        diagnostics: AdapterDiagnostics,
+    },
+    #[error("{0}")]
+    CustomAdapter(#[from] CustomAdapterError),
+    #[error("Failed to parse benchmark harness output with the custom adapter: No lines matched its patterns")]
+    CustomAdapterResults,
+    #[error("Failed to serialize benchmark results: {0}")]
+    SerializeResults(serde_json::Error),
+}
+
+impl TryFrom<CliAdapterCheck> for Check {
+    type Error = CheckError;
+
+    fn try_from(check: CliAdapterCheck) -> Result<Self, Self::Error> {
+        let CliAdapterCheck {
+            file,
+            adapter,
+            adapter_config,
+            average,
+        } = check;
+        Ok(Self {
+            file,
+            adapter: adapter.map(Into::into).unwrap_or_default(),
+            average: average.map(Into::into),
+            custom_adapter: adapter_config
+                .as_deref()
+                .map(read_custom_adapter)
+                .transpose()?,
+        })
+    }
+}
+
+impl SubCmd for Check {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.exec_inner().map_err(Into::into)
+    }
+}
+
+impl Check {
+    fn exec_inner(&self) -> Result<(), CheckError> {
+        let input = std::fs::read_to_string(&self.file).map_err(|err| CheckError::ReadFile {
+            path: self.file.clone(),
+            err,
+        })?;
+
+        let settings = Settings::new(self.average);
+        let results = if let Some(custom_adapter) = &self.custom_adapter {
+            custom_adapter
+                .convert(&input, settings)
+                .ok_or(CheckError::CustomAdapterResults)?
+        } else {
+            diagnose(self.adapter, &input, settings).map_err(|diagnostics| {
+                CheckError::Diagnostics {
+                    adapter: self.adapter,
+                    diagnostics,
+                }
+            })?
+        };
+
+        cli_println!(
+            "{}",
+            serde_json::to_string_pretty(&results).map_err(CheckError::SerializeResults)?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use bencher_adapter::{AdapterCustom, AdapterError, CustomAdapterConfig};
+use camino::{Utf8Path, Utf8PathBuf};
+
+#[derive(thiserror::Error, Debug)]
+pub enum CustomAdapterError {
+    #[error("Failed to read custom adapter config file ({path}): {err}")]
+    Read {
+        path: Utf8PathBuf,
+        err: std::io::Error,
+    },
+    #[error("Failed to parse custom adapter config file ({path}): {err}")]
+    Parse {
+        path: Utf8PathBuf,
+        err: serde_json::Error,
+    },
+    #[error("Invalid custom adapter config file ({path}): {err}")]
+    Invalid {
+        path: Utf8PathBuf,
+        err: AdapterError,
+    },
+}
+
+pub fn read_custom_adapter(path: &Utf8Path) -> Result<AdapterCustom, CustomAdapterError> {
+    let config = std::fs::read_to_string(path).map_err(|err| CustomAdapterError::Read {
+        path: path.to_owned(),
+        err,
+    })?;
+    let config = serde_json::from_str::<CustomAdapterConfig>(&config).map_err(|err| {
+        CustomAdapterError::Parse {
+            path: path.to_owned(),
+            err,
+        }
+    })?;
+    config
+        .try_into()
+        .map_err(|err| CustomAdapterError::Invalid {
+            path: path.to_owned(),
+            err,
+        })
+}
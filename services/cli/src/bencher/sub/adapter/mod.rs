@@ -0,0 +1,29 @@
+use crate::{bencher::sub::SubCmd, parser::adapter::CliAdapter, CliError};
+
+mod check;
+pub mod custom;
+
+pub use check::CheckError;
+
+#[derive(Debug)]
+pub enum Adapter {
+    Check(check::Check),
+}
+
+impl TryFrom<CliAdapter> for Adapter {
+    type Error = CliError;
+
+    fn try_from(adapter: CliAdapter) -> Result<Self, Self::Error> {
+        Ok(match adapter {
+            CliAdapter::Check(check) => Self::Check(check.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Adapter {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::Check(check) => check.exec().await,
+        }
+    }
+}
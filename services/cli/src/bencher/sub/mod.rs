@@ -1,5 +1,6 @@
 use crate::{parser::CliSub, CliError};
 
+mod adapter;
 mod docker;
 mod mock;
 mod organization;
@@ -8,6 +9,8 @@ mod sub_cmd;
 mod system;
 mod user;
 
+use adapter::Adapter;
+pub use adapter::{custom::CustomAdapterError, CheckError};
 pub use docker::DockerError;
 use docker::{down::Down, logs::Logs, up::Up};
 use mock::Mock;
@@ -45,6 +48,7 @@ pub enum Sub {
     User(User),
     Token(Token),
     Server(Server),
+    Adapter(Adapter),
     Mock(Mock),
     Up(Up),
     Down(Down),
@@ -74,6 +78,7 @@ impl TryFrom<CliSub> for Sub {
             CliSub::User(user) => Self::User(user.try_into()?),
             CliSub::Token(token) => Self::Token(token.try_into()?),
             CliSub::Server(server) => Self::Server(server.try_into()?),
+            CliSub::Adapter(adapter) => Self::Adapter(adapter.try_into()?),
             CliSub::Mock(mock) => Self::Mock(mock.into()),
             CliSub::Up(up) => Self::Up(up.into()),
             CliSub::Down(down) => Self::Down(down.into()),
@@ -103,6 +108,7 @@ impl SubCmd for Sub {
             Self::User(user) => user.exec().await,
             Self::Token(token) => token.exec().await,
             Self::Server(server) => server.exec().await,
+            Self::Adapter(adapter) => adapter.exec().await,
             Self::Mock(mock) => mock.exec().await,
             Self::Up(up) => up.exec().await,
             Self::Down(down) => down.exec().await,
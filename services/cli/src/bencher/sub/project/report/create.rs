@@ -1,5 +1,6 @@
 use bencher_client::types::{
-    Adapter, DateTime, GitHash, JsonAverage, JsonFold, JsonNewReport, JsonReportSettings, NameId,
+    Adapter, DateTime, GitHash, JsonAverage, JsonCorrection, JsonFold, JsonNewReport,
+    JsonReportSettings, NameId,
 };
 use bencher_json::ResourceId;
 
@@ -21,6 +22,7 @@ pub struct Create {
     pub adapter: Option<Adapter>,
     pub average: Option<JsonAverage>,
     pub fold: Option<JsonFold>,
+    pub correction: Option<JsonCorrection>,
     pub backend: AuthBackend,
 }
 
@@ -39,6 +41,7 @@ impl TryFrom<CliReportCreate> for Create {
             adapter,
             average,
             fold,
+            correction,
             backend,
         } = create;
         Ok(Self {
@@ -52,6 +55,7 @@ impl TryFrom<CliReportCreate> for Create {
             adapter: adapter.map(Into::into),
             average: average.map(Into::into),
             fold: fold.map(Into::into),
+            correction: correction.map(Into::into),
             backend: backend.try_into()?,
         })
     }
@@ -69,6 +73,7 @@ impl From<Create> for JsonNewReport {
             adapter,
             average,
             fold,
+            correction,
             ..
         } = create;
         Self {
@@ -81,6 +86,7 @@ impl From<Create> for JsonNewReport {
             settings: Some(JsonReportSettings {
                 adapter,
                 average,
+                correction,
                 fold,
             }),
         }
@@ -12,16 +12,24 @@ impl From<CliRunAdapter> for Adapter {
             CliRunAdapter::Cpp => Self::Cpp,
             CliRunAdapter::CppCatch2 => Self::CppCatch2,
             CliRunAdapter::CppGoogle => Self::CppGoogle,
+            CliRunAdapter::Elixir => Self::Elixir,
+            CliRunAdapter::ElixirBenchee => Self::ElixirBenchee,
             CliRunAdapter::Go => Self::Go,
             CliRunAdapter::GoBench => Self::GoBench,
+            CliRunAdapter::Http => Self::Http,
+            CliRunAdapter::HttpK6 => Self::HttpK6,
+            CliRunAdapter::HttpWrk => Self::HttpWrk,
+            CliRunAdapter::HttpOha => Self::HttpOha,
             CliRunAdapter::Java => Self::Java,
             CliRunAdapter::JavaJmh => Self::JavaJmh,
             CliRunAdapter::Js => Self::Js,
             CliRunAdapter::JsBenchmark => Self::JsBenchmark,
             CliRunAdapter::JsTime => Self::JsTime,
+            CliRunAdapter::JsVitest => Self::JsVitest,
             CliRunAdapter::Python => Self::Python,
             CliRunAdapter::PythonAsv => Self::PythonAsv,
             CliRunAdapter::PythonPytest => Self::PythonPytest,
+            CliRunAdapter::PythonPyperf => Self::PythonPyperf,
             CliRunAdapter::Ruby => Self::Ruby,
             CliRunAdapter::RubyBenchmark => Self::RubyBenchmark,
             CliRunAdapter::Rust => Self::Rust,
@@ -29,8 +37,61 @@ impl From<CliRunAdapter> for Adapter {
             CliRunAdapter::RustCriterion => Self::RustCriterion,
             CliRunAdapter::RustIai => Self::RustIai,
             CliRunAdapter::RustIaiCallgrind => Self::RustIaiCallgrind,
+            CliRunAdapter::RustDivan => Self::RustDivan,
             CliRunAdapter::Shell => Self::Shell,
             CliRunAdapter::ShellHyperfine => Self::ShellHyperfine,
+            CliRunAdapter::ShellPerfStat => Self::ShellPerfStat,
+            CliRunAdapter::Swift => Self::Swift,
+            CliRunAdapter::SwiftBenchmark => Self::SwiftBenchmark,
+            CliRunAdapter::Zig => Self::Zig,
+            CliRunAdapter::ZigZbench => Self::ZigZbench,
+        }
+    }
+}
+
+impl From<CliRunAdapter> for bencher_json::project::report::Adapter {
+    fn from(adapter: CliRunAdapter) -> Self {
+        match adapter {
+            CliRunAdapter::Magic => Self::Magic,
+            CliRunAdapter::Json => Self::Json,
+            CliRunAdapter::CSharp => Self::CSharp,
+            CliRunAdapter::CSharpDotNet => Self::CSharpDotNet,
+            CliRunAdapter::Cpp => Self::Cpp,
+            CliRunAdapter::CppCatch2 => Self::CppCatch2,
+            CliRunAdapter::CppGoogle => Self::CppGoogle,
+            CliRunAdapter::Elixir => Self::Elixir,
+            CliRunAdapter::ElixirBenchee => Self::ElixirBenchee,
+            CliRunAdapter::Go => Self::Go,
+            CliRunAdapter::GoBench => Self::GoBench,
+            CliRunAdapter::Http => Self::Http,
+            CliRunAdapter::HttpK6 => Self::HttpK6,
+            CliRunAdapter::HttpWrk => Self::HttpWrk,
+            CliRunAdapter::HttpOha => Self::HttpOha,
+            CliRunAdapter::Java => Self::Java,
+            CliRunAdapter::JavaJmh => Self::JavaJmh,
+            CliRunAdapter::Js => Self::Js,
+            CliRunAdapter::JsBenchmark => Self::JsBenchmark,
+            CliRunAdapter::JsTime => Self::JsTime,
+            CliRunAdapter::JsVitest => Self::JsVitest,
+            CliRunAdapter::Python => Self::Python,
+            CliRunAdapter::PythonAsv => Self::PythonAsv,
+            CliRunAdapter::PythonPytest => Self::PythonPytest,
+            CliRunAdapter::PythonPyperf => Self::PythonPyperf,
+            CliRunAdapter::Ruby => Self::Ruby,
+            CliRunAdapter::RubyBenchmark => Self::RubyBenchmark,
+            CliRunAdapter::Rust => Self::Rust,
+            CliRunAdapter::RustBench => Self::RustBench,
+            CliRunAdapter::RustCriterion => Self::RustCriterion,
+            CliRunAdapter::RustIai => Self::RustIai,
+            CliRunAdapter::RustIaiCallgrind => Self::RustIaiCallgrind,
+            CliRunAdapter::RustDivan => Self::RustDivan,
+            CliRunAdapter::Shell => Self::Shell,
+            CliRunAdapter::ShellHyperfine => Self::ShellHyperfine,
+            CliRunAdapter::ShellPerfStat => Self::ShellPerfStat,
+            CliRunAdapter::Swift => Self::Swift,
+            CliRunAdapter::SwiftBenchmark => Self::SwiftBenchmark,
+            CliRunAdapter::Zig => Self::Zig,
+            CliRunAdapter::ZigZbench => Self::ZigZbench,
         }
     }
 }
@@ -10,3 +10,12 @@ impl From<CliRunAverage> for JsonAverage {
         }
     }
 }
+
+impl From<CliRunAverage> for bencher_json::project::report::JsonAverage {
+    fn from(average: CliRunAverage) -> Self {
+        match average {
+            CliRunAverage::Mean => Self::Mean,
+            CliRunAverage::Median => Self::Median,
+        }
+    }
+}
@@ -0,0 +1,442 @@
+use bencher_comment::ReportComment;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::cli_println_quietable;
+
+const BITBUCKET_BUILD_NUMBER: &str = "BITBUCKET_BUILD_NUMBER";
+const BITBUCKET_PR_ID: &str = "BITBUCKET_PR_ID";
+const BITBUCKET_WORKSPACE: &str = "BITBUCKET_WORKSPACE";
+const BITBUCKET_REPO_SLUG: &str = "BITBUCKET_REPO_SLUG";
+// Not set by Bitbucket Pipelines, only used to override the Bitbucket Cloud API URL.
+const BENCHER_BITBUCKET_API_URL: &str = "BENCHER_BITBUCKET_API_URL";
+const BITBUCKET_API_URL: &str = "https://api.bitbucket.org/2.0";
+
+#[derive(Debug)]
+pub struct BitbucketPipelines {
+    pub token: String,
+    pub ci_only_thresholds: bool,
+    pub ci_only_on_alert: bool,
+    pub ci_id: Option<String>,
+    pub ci_number: Option<u64>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum BitbucketError {
+    #[error("Failed to parse Bitbucket API URL ({0}): {1}")]
+    BadApiUrl(String, url::ParseError),
+    #[error(
+        "Failed to get Bitbucket Pipelines workspace\n{}",
+        docker_env(BITBUCKET_WORKSPACE)
+    )]
+    NoWorkspace,
+    #[error(
+        "Failed to get Bitbucket Pipelines repository slug\n{}",
+        docker_env(BITBUCKET_REPO_SLUG)
+    )]
+    NoRepoSlug,
+    #[error("Bitbucket Pipelines pull request ID is invalid: {0}")]
+    BadPullRequestId(String),
+    #[error("Bitbucket Pipelines token is not a valid header value: {0}")]
+    BadToken(reqwest::header::InvalidHeaderValue),
+    #[error("Failed to create Bitbucket API client: {0}")]
+    Client(reqwest::Error),
+    #[error("Failed to list Bitbucket PR comments: {0}")]
+    Comments(reqwest::Error),
+    #[error("Failed to create Bitbucket PR comment: {0}")]
+    CreateComment(reqwest::Error),
+    #[error("Failed to update Bitbucket PR comment: {0}")]
+    UpdateComment(reqwest::Error),
+    #[error("Bitbucket Pipelines token does not have the `pullrequest:write` scope.\n{help}\nError: {0}", help = SCOPE_HELP)]
+    BadPermissions(reqwest::Error),
+}
+
+// https://support.atlassian.com/bitbucket-cloud/docs/repository-access-token-permissions/
+const SCOPE_HELP: &str = "To fix, use a repository access token with the `pullrequest:write` scope.\nSee: https://bencher.dev/docs/explanation/bencher-run/#--bitbucket-pipelines-bitbucket_token";
+
+fn docker_env(env_var: &str) -> String {
+    format!(
+        "If you are running in a Docker container, then you need to pass in the `{env_var}` environment variable. See https://bencher.dev/docs/explanation/bencher-run/#--bitbucket-pipelines-bitbucket_token",
+    )
+}
+
+// https://developer.atlassian.com/cloud/bitbucket/rest/api-group-pullrequests/#api-repositories-workspace-repo-slug-pullrequests-pull-request-id-comments-get
+#[derive(Debug, Deserialize)]
+struct BitbucketComments {
+    values: Vec<BitbucketComment>,
+    next: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketComment {
+    id: u64,
+    content: BitbucketContent,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BitbucketContent {
+    raw: String,
+}
+
+#[derive(Debug, Serialize)]
+struct BitbucketCommentBody {
+    content: BitbucketContent,
+}
+
+impl BitbucketPipelines {
+    pub async fn run(
+        &self,
+        report_comment: &ReportComment,
+        log: bool,
+    ) -> Result<(), BitbucketError> {
+        // Only post to CI if there are thresholds set
+        if self.ci_only_thresholds && !report_comment.has_threshold() {
+            cli_println_quietable!(log, "No thresholds set. Skipping CI integration.");
+            return Ok(());
+        }
+
+        if !is_bitbucket_pipelines() {
+            cli_println_quietable!(
+                log,
+                "Not running in Bitbucket Pipelines. Skipping CI integration.\n{}",
+                docker_env(BITBUCKET_BUILD_NUMBER)
+            );
+            return Ok(());
+        }
+
+        // The pull request ID is only set for pull request pipelines.
+        // https://support.atlassian.com/bitbucket-cloud/docs/variables-and-secrets/
+        let pull_request_id = if let Some(pull_request_id) = self.ci_number {
+            pull_request_id
+        } else if let Ok(pull_request_id) = std::env::var(BITBUCKET_PR_ID) {
+            pull_request_id
+                .parse()
+                .map_err(|_e| BitbucketError::BadPullRequestId(pull_request_id))?
+        } else {
+            cli_println_quietable!(
+                log,
+                "Not running in a Bitbucket pull request pipeline. Skipping CI integration.\n{}",
+                docker_env(BITBUCKET_PR_ID)
+            );
+            return Ok(());
+        };
+
+        let workspace =
+            std::env::var(BITBUCKET_WORKSPACE).map_err(|_e| BitbucketError::NoWorkspace)?;
+        let repo_slug =
+            std::env::var(BITBUCKET_REPO_SLUG).map_err(|_e| BitbucketError::NoRepoSlug)?;
+        let api_url =
+            std::env::var(BENCHER_BITBUCKET_API_URL).unwrap_or_else(|_e| BITBUCKET_API_URL.into());
+        // The trailing slash is needed so the API URL path is not replaced when joining.
+        let comments_url = Url::parse(&format!("{}/", api_url.trim_end_matches('/')))
+            .and_then(|url| {
+                url.join(&format!(
+                    "repositories/{workspace}/{repo_slug}/pullrequests/{pull_request_id}/comments"
+                ))
+            })
+            .map_err(|e| BitbucketError::BadApiUrl(api_url, e))?;
+
+        let bitbucket_client = BitbucketClient::new(&self.token, comments_url)?;
+        self.post_comment(&bitbucket_client, report_comment, log)
+            .await
+    }
+
+    async fn post_comment(
+        &self,
+        bitbucket_client: &BitbucketClient,
+        report_comment: &ReportComment,
+        log: bool,
+    ) -> Result<(), BitbucketError> {
+        // Get the comment ID if it exists
+        let bencher_tag = bitbucket_tag(&report_comment.bencher_tag(self.ci_id.as_deref()));
+        let comment_id = bitbucket_client.get_comment(&bencher_tag).await?;
+
+        // Update or create the comment
+        // Bitbucket does not render HTML in comments, so the results are posted as Markdown,
+        // and the Bencher tag is hidden in a Markdown link reference definition instead.
+        let body = format!(
+            "{}\n\n{bencher_tag}",
+            report_comment.markdown(self.ci_only_thresholds)
+        );
+        // Always update the comment if it exists
+        let comment = if let Some(comment_id) = comment_id {
+            bitbucket_client.update_comment(comment_id, body).await
+        } else {
+            if self.ci_only_on_alert && !report_comment.has_alert() {
+                cli_println_quietable!(log, "No alerts found. Skipping CI integration.");
+                return Ok(());
+            }
+            bitbucket_client.create_comment(body).await
+        };
+        if let Err(e) = comment {
+            return Err(
+                if matches!(
+                    e.status(),
+                    Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN)
+                ) {
+                    BitbucketError::BadPermissions(e)
+                } else if comment_id.is_some() {
+                    BitbucketError::UpdateComment(e)
+                } else {
+                    BitbucketError::CreateComment(e)
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
+
+// https://support.atlassian.com/bitbucket-cloud/docs/variables-and-secrets/
+// The unique identifier for a build. It increments with each build and can be used to create unique artifact names.
+fn is_bitbucket_pipelines() -> bool {
+    std::env::var(BITBUCKET_BUILD_NUMBER).is_ok()
+}
+
+fn bitbucket_tag(bencher_tag: &str) -> String {
+    format!("[//]: # ({bencher_tag})")
+}
+
+struct BitbucketClient {
+    client: reqwest::Client,
+    comments_url: Url,
+}
+
+impl BitbucketClient {
+    fn new(token: &str, comments_url: Url) -> Result<Self, BitbucketError> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        let mut authorization = reqwest::header::HeaderValue::from_str(&format!("Bearer {token}"))
+            .map_err(BitbucketError::BadToken)?;
+        authorization.set_sensitive(true);
+        headers.insert(reqwest::header::AUTHORIZATION, authorization);
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(BitbucketError::Client)?;
+        Ok(Self {
+            client,
+            comments_url,
+        })
+    }
+
+    async fn get_comment(&self, bencher_tag: &str) -> Result<Option<u64>, BitbucketError> {
+        const PAGE_LEN: u8 = 100;
+
+        let mut page_url = self.comments_url.clone();
+        page_url
+            .query_pairs_mut()
+            .append_pair("pagelen", &PAGE_LEN.to_string());
+        let mut next = Some(page_url.to_string());
+        while let Some(page_url) = next {
+            let comments: BitbucketComments = self
+                .client
+                .get(page_url)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(BitbucketError::Comments)?
+                .json()
+                .await
+                .map_err(BitbucketError::Comments)?;
+
+            for comment in comments.values {
+                if comment.content.raw.ends_with(bencher_tag) {
+                    return Ok(Some(comment.id));
+                }
+            }
+
+            next = comments.next;
+        }
+
+        Ok(None)
+    }
+
+    async fn create_comment(&self, raw: String) -> Result<(), reqwest::Error> {
+        self.client
+            .post(self.comments_url.clone())
+            .json(&BitbucketCommentBody {
+                content: BitbucketContent { raw },
+            })
+            .send()
+            .await?
+            .error_for_status()
+            .map(drop)
+    }
+
+    async fn update_comment(&self, comment_id: u64, raw: String) -> Result<(), reqwest::Error> {
+        self.client
+            .put(format!("{}/{comment_id}", self.comments_url))
+            .json(&BitbucketCommentBody {
+                content: BitbucketContent { raw },
+            })
+            .send()
+            .await?
+            .error_for_status()
+            .map(drop)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use url::Url;
+
+    use super::{bitbucket_tag, BitbucketClient, BitbucketError, BitbucketPipelines};
+    use crate::bencher::sub::project::run::ci::mock::{report_comment, MockServer};
+
+    const COMMENTS_PATH: &str = "/2.0/repositories/workspace/repo/pullrequests/7/comments";
+
+    fn bitbucket_pipelines(ci_only_on_alert: bool) -> BitbucketPipelines {
+        BitbucketPipelines {
+            token: "bitbucket-token".into(),
+            ci_only_thresholds: false,
+            ci_only_on_alert,
+            ci_id: None,
+            ci_number: None,
+        }
+    }
+
+    fn comments(comments: impl IntoIterator<Item = (u64, String)>, next: Option<&str>) -> String {
+        let values = comments
+            .into_iter()
+            .map(|(id, raw)| serde_json::json!({ "id": id, "content": { "raw": raw } }))
+            .collect::<Vec<_>>();
+        serde_json::json!({ "values": values, "next": next }).to_string()
+    }
+
+    fn previous_results() -> String {
+        format!(
+            "Previous results\n\n{}",
+            bitbucket_tag(&report_comment().bencher_tag(None))
+        )
+    }
+
+    async fn post_comment(
+        server: &MockServer,
+        bitbucket_pipelines: &BitbucketPipelines,
+    ) -> Result<(), BitbucketError> {
+        let comments_url = Url::parse(&format!("{}{COMMENTS_PATH}", server.url())).unwrap();
+        let bitbucket_client =
+            BitbucketClient::new(&bitbucket_pipelines.token, comments_url).unwrap();
+        bitbucket_pipelines
+            .post_comment(&bitbucket_client, &report_comment(), false)
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_bitbucket_create_comment() {
+        let server = MockServer::start(|request| match request.method.as_str() {
+            "GET" => (200, comments([(1, "Looks good to me".into())], None)),
+            _ => (201, "{}".into()),
+        })
+        .await;
+
+        post_comment(&server, &bitbucket_pipelines(false))
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        let (get, post) = (&requests[0], &requests[1]);
+        assert_eq!(get.method, "GET");
+        assert_eq!(get.path, format!("{COMMENTS_PATH}?pagelen=100"));
+        assert_eq!(get.header("authorization"), Some("Bearer bitbucket-token"));
+        assert_eq!(post.method, "POST");
+        assert_eq!(post.path, COMMENTS_PATH);
+        // The comment is Markdown with the Bencher tag hidden at the end.
+        let body: serde_json::Value = serde_json::from_str(&post.body).unwrap();
+        let raw = body["content"]["raw"].as_str().unwrap();
+        assert!(raw.starts_with("## [🐰 Bencher]"));
+        assert!(raw.ends_with(&bitbucket_tag(&report_comment().bencher_tag(None))));
+    }
+
+    #[tokio::test]
+    async fn test_bitbucket_update_comment() {
+        let server = MockServer::start(|request| match request.method.as_str() {
+            "GET" => (
+                200,
+                comments(
+                    [(1, "Looks good to me".into()), (42, previous_results())],
+                    None,
+                ),
+            ),
+            _ => (200, "{}".into()),
+        })
+        .await;
+
+        // An existing comment is always updated, even if there are no alerts.
+        post_comment(&server, &bitbucket_pipelines(true))
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "PUT");
+        assert_eq!(requests[1].path, format!("{COMMENTS_PATH}/42"));
+    }
+
+    #[tokio::test]
+    async fn test_bitbucket_get_comment_next() {
+        let server = MockServer::start(|request| {
+            if request.method != "GET" {
+                (200, "{}".into())
+            } else if request.path.ends_with("page=2") {
+                (200, comments([(142, previous_results())], None))
+            } else {
+                // The next page is a full URL.
+                let next = format!(
+                    "http://{}{COMMENTS_PATH}?pagelen=100&page=2",
+                    request.header("host").unwrap()
+                );
+                (200, comments([(1, "Other".into())], Some(&next)))
+            }
+        })
+        .await;
+
+        post_comment(&server, &bitbucket_pipelines(false))
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests[1].path,
+            format!("{COMMENTS_PATH}?pagelen=100&page=2")
+        );
+        assert_eq!(requests[2].method, "PUT");
+        assert_eq!(requests[2].path, format!("{COMMENTS_PATH}/142"));
+    }
+
+    #[tokio::test]
+    async fn test_bitbucket_only_on_alert() {
+        let server = MockServer::start(|_| (200, comments([], None))).await;
+
+        post_comment(&server, &bitbucket_pipelines(true))
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+    }
+
+    #[tokio::test]
+    async fn test_bitbucket_bad_permissions() {
+        for status in [401, 403] {
+            let server = MockServer::start(move |request| match request.method.as_str() {
+                "GET" => (200, comments([], None)),
+                _ => (status, "{}".into()),
+            })
+            .await;
+
+            let error = post_comment(&server, &bitbucket_pipelines(false))
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(error, BitbucketError::BadPermissions(_)),
+                "{status}: {error}"
+            );
+        }
+    }
+}
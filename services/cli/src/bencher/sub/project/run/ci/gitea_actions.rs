@@ -0,0 +1,406 @@
+use bencher_comment::ReportComment;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::cli_println_quietable;
+
+// Gitea and Forgejo Actions also set the GitHub Actions default environment variables.
+const GITEA_ACTIONS: &str = "GITEA_ACTIONS";
+const FORGEJO_ACTIONS: &str = "FORGEJO_ACTIONS";
+const GITHUB_SERVER_URL: &str = "GITHUB_SERVER_URL";
+const GITHUB_REPOSITORY: &str = "GITHUB_REPOSITORY";
+const GITHUB_EVENT_NAME: &str = "GITHUB_EVENT_NAME";
+const GITHUB_REF: &str = "GITHUB_REF";
+
+const PULL_REQUEST: &str = "pull_request";
+const PULL_REQUEST_TARGET: &str = "pull_request_target";
+const PULL_REF_PREFIX: &str = "refs/pull/";
+
+#[derive(Debug)]
+pub struct GiteaActions {
+    pub token: String,
+    pub ci_only_thresholds: bool,
+    pub ci_only_on_alert: bool,
+    pub ci_id: Option<String>,
+    pub ci_number: Option<u64>,
+    pub ci_i_am_vulnerable_to_pwn_requests: bool,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GiteaError {
+    #[error(
+        "Failed to get Gitea Actions server URL\n{}",
+        docker_env(GITHUB_SERVER_URL)
+    )]
+    NoServerUrl,
+    #[error("Failed to parse Gitea Actions server URL ({0}): {1}")]
+    BadServerUrl(String, url::ParseError),
+    #[error(
+        "Failed to get Gitea Actions repository\n{}",
+        docker_env(GITHUB_REPOSITORY)
+    )]
+    NoRepository,
+    #[error("Gitea Actions repository is not of the form `owner/repo`: ({0})")]
+    InvalidRepository(String),
+    #[error("Gitea Actions event ({1}) PR number is missing from the ref: {0}")]
+    NoPRNumber(String, String),
+    #[error("Gitea Actions token is not a valid header value: {0}")]
+    BadToken(reqwest::header::InvalidHeaderValue),
+    #[error("Failed to create Gitea API client: {0}")]
+    Client(reqwest::Error),
+    #[error("Failed to list Gitea PR comments: {0}")]
+    Comments(reqwest::Error),
+    #[error("Failed to create Gitea PR comment: {0}")]
+    CreateComment(reqwest::Error),
+    #[error("Failed to update Gitea PR comment: {0}")]
+    UpdateComment(reqwest::Error),
+    #[error("Gitea Actions token does not have `write` permissions for `issue`.\n{help}\nError: {0}", help = PERMISSIONS_HELP)]
+    BadPermissions(reqwest::Error),
+    #[error("{}", pwn_requests())]
+    PwnRequest,
+}
+
+// https://docs.gitea.com/development/oauth2-provider#scopes
+const PERMISSIONS_HELP: &str = "To fix, use an access token with the `write:issue` scope.\nSee: https://bencher.dev/docs/explanation/bencher-run/#--gitea-actions-gitea_token";
+
+fn docker_env(env_var: &str) -> String {
+    format!(
+        "If you are running in a Docker container, then you need to pass in the `{env_var}` environment variable. See https://bencher.dev/docs/explanation/bencher-run/#--gitea-actions-gitea_token",
+    )
+}
+
+// A `pull_request_target` workflow runs with the secrets of the base repository,
+// even for pull requests from forks.
+fn pwn_requests() -> String {
+    format!(
+        "WARNING! Unsafe use of Gitea or Forgejo Actions `{PULL_REQUEST_TARGET}` event!\nThis workflow runs with the secrets of your repository, even for pull requests from forked repositories. This is a major security risk!\nFor more information on pwn requests, see: https://securitylab.github.com/research/github-actions-preventing-pwn-requests/\nTo accept this risk, use `--ci-i-am-vulnerable-to-pwn-requests`",
+    )
+}
+
+// https://docs.gitea.com/api/1.20/#tag/issue/operation/issueGetComments
+#[derive(Debug, Deserialize)]
+struct GiteaComment {
+    id: u64,
+    body: String,
+}
+
+#[derive(Debug, Serialize)]
+struct GiteaCommentBody<'b> {
+    body: &'b str,
+}
+
+impl GiteaActions {
+    pub fn safety_check(&self, log: bool) -> Result<(), GiteaError> {
+        if !is_gitea_actions() {
+            return Ok(());
+        }
+
+        if let Some(PULL_REQUEST_TARGET) = std::env::var(GITHUB_EVENT_NAME).ok().as_deref() {
+            if self.ci_i_am_vulnerable_to_pwn_requests {
+                cli_println_quietable!(log, "{}", pwn_requests());
+            } else {
+                return Err(GiteaError::PwnRequest);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn run(&self, report_comment: &ReportComment, log: bool) -> Result<(), GiteaError> {
+        // Only post to CI if there are thresholds set
+        if self.ci_only_thresholds && !report_comment.has_threshold() {
+            cli_println_quietable!(log, "No thresholds set. Skipping CI integration.");
+            return Ok(());
+        }
+
+        if !is_gitea_actions() {
+            cli_println_quietable!(
+                log,
+                "Not running as a Gitea or Forgejo Action. Skipping CI integration.\n{}",
+                docker_env(GITEA_ACTIONS)
+            );
+            return Ok(());
+        }
+
+        let issue_number = if let Some(issue_number) = self.ci_number {
+            issue_number
+        } else {
+            let event_name = std::env::var(GITHUB_EVENT_NAME).ok();
+            if let Some(event_name @ (PULL_REQUEST | PULL_REQUEST_TARGET)) = event_name.as_deref() {
+                // The pull request ref is of the form `refs/pull/123/head`
+                let git_ref = std::env::var(GITHUB_REF).unwrap_or_default();
+                git_ref
+                    .strip_prefix(PULL_REF_PREFIX)
+                    .and_then(|pull| pull.split('/').next())
+                    .and_then(|number| number.parse().ok())
+                    .ok_or_else(|| GiteaError::NoPRNumber(git_ref.clone(), event_name.into()))?
+            } else {
+                cli_println_quietable!(
+                    log,
+                    "Not running as an expected Gitea or Forgejo Action event (`pull_request` or `pull_request_target`). Skipping CI integration.\n{}",
+                    docker_env(GITHUB_EVENT_NAME)
+                );
+                return Ok(());
+            }
+        };
+
+        let server_url = std::env::var(GITHUB_SERVER_URL).map_err(|_e| GiteaError::NoServerUrl)?;
+        let repository = std::env::var(GITHUB_REPOSITORY).map_err(|_e| GiteaError::NoRepository)?;
+        let Some((owner, repo)) = repository.split_once('/') else {
+            return Err(GiteaError::InvalidRepository(repository));
+        };
+        // The trailing slash is needed so the server URL path is not replaced when joining.
+        let repo_url = Url::parse(&format!("{}/", server_url.trim_end_matches('/')))
+            .and_then(|url| url.join(&format!("api/v1/repos/{owner}/{repo}/")))
+            .map_err(|e| GiteaError::BadServerUrl(server_url, e))?;
+
+        let gitea_client = GiteaClient::new(&self.token, repo_url)?;
+        self.post_comment(&gitea_client, issue_number, report_comment, log)
+            .await
+    }
+
+    async fn post_comment(
+        &self,
+        gitea_client: &GiteaClient,
+        issue_number: u64,
+        report_comment: &ReportComment,
+        log: bool,
+    ) -> Result<(), GiteaError> {
+        // Get the comment ID if it exists
+        let comment_id = gitea_client
+            .get_comment(
+                issue_number,
+                &report_comment.bencher_tag(self.ci_id.as_deref()),
+            )
+            .await?;
+
+        // Update or create the comment
+        let body = report_comment.html(self.ci_only_thresholds, self.ci_id.as_deref());
+        // Always update the comment if it exists
+        let comment = if let Some(comment_id) = comment_id {
+            gitea_client.update_comment(comment_id, &body).await
+        } else {
+            if self.ci_only_on_alert && !report_comment.has_alert() {
+                cli_println_quietable!(log, "No alerts found. Skipping CI integration.");
+                return Ok(());
+            }
+            gitea_client.create_comment(issue_number, &body).await
+        };
+        if let Err(e) = comment {
+            return Err(
+                if matches!(
+                    e.status(),
+                    Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN)
+                ) {
+                    GiteaError::BadPermissions(e)
+                } else if comment_id.is_some() {
+                    GiteaError::UpdateComment(e)
+                } else {
+                    GiteaError::CreateComment(e)
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
+
+// https://docs.gitea.com/usage/actions/comparison#context-availability
+fn is_gitea_actions() -> bool {
+    [GITEA_ACTIONS, FORGEJO_ACTIONS]
+        .into_iter()
+        .any(|env_var| std::env::var(env_var).ok().as_deref() == Some("true"))
+}
+
+struct GiteaClient {
+    client: reqwest::Client,
+    repo_url: Url,
+}
+
+impl GiteaClient {
+    fn new(token: &str, repo_url: Url) -> Result<Self, GiteaError> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        let mut authorization = reqwest::header::HeaderValue::from_str(&format!("token {token}"))
+            .map_err(GiteaError::BadToken)?;
+        authorization.set_sensitive(true);
+        headers.insert(reqwest::header::AUTHORIZATION, authorization);
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(GiteaError::Client)?;
+        Ok(Self { client, repo_url })
+    }
+
+    // All of the comments on an issue are returned at once.
+    async fn get_comment(
+        &self,
+        issue_number: u64,
+        bencher_tag: &str,
+    ) -> Result<Option<u64>, GiteaError> {
+        let comments: Vec<GiteaComment> = self
+            .client
+            .get(format!("{}issues/{issue_number}/comments", self.repo_url))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(GiteaError::Comments)?
+            .json()
+            .await
+            .map_err(GiteaError::Comments)?;
+
+        Ok(comments
+            .into_iter()
+            .find(|comment| comment.body.ends_with(bencher_tag))
+            .map(|comment| comment.id))
+    }
+
+    async fn create_comment(&self, issue_number: u64, body: &str) -> Result<(), reqwest::Error> {
+        self.client
+            .post(format!("{}issues/{issue_number}/comments", self.repo_url))
+            .json(&GiteaCommentBody { body })
+            .send()
+            .await?
+            .error_for_status()
+            .map(drop)
+    }
+
+    async fn update_comment(&self, comment_id: u64, body: &str) -> Result<(), reqwest::Error> {
+        self.client
+            .patch(format!("{}issues/comments/{comment_id}", self.repo_url))
+            .json(&GiteaCommentBody { body })
+            .send()
+            .await?
+            .error_for_status()
+            .map(drop)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use url::Url;
+
+    use super::{GiteaActions, GiteaClient, GiteaError};
+    use crate::bencher::sub::project::run::ci::mock::{report_comment, MockServer};
+
+    const REPO_PATH: &str = "/api/v1/repos/owner/repo/";
+    const ISSUE_NUMBER: u64 = 7;
+
+    fn gitea_actions(ci_only_on_alert: bool) -> GiteaActions {
+        GiteaActions {
+            token: "gitea-token".into(),
+            ci_only_thresholds: false,
+            ci_only_on_alert,
+            ci_id: None,
+            ci_number: None,
+            ci_i_am_vulnerable_to_pwn_requests: false,
+        }
+    }
+
+    fn comments(comments: impl IntoIterator<Item = (u64, String)>) -> String {
+        serde_json::Value::Array(
+            comments
+                .into_iter()
+                .map(|(id, body)| serde_json::json!({ "id": id, "body": body }))
+                .collect(),
+        )
+        .to_string()
+    }
+
+    async fn post_comment(
+        server: &MockServer,
+        gitea_actions: &GiteaActions,
+    ) -> Result<(), GiteaError> {
+        let repo_url = Url::parse(&format!("{}{REPO_PATH}", server.url())).unwrap();
+        let gitea_client = GiteaClient::new(&gitea_actions.token, repo_url).unwrap();
+        gitea_actions
+            .post_comment(&gitea_client, ISSUE_NUMBER, &report_comment(), false)
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_gitea_create_comment() {
+        let server = MockServer::start(|request| match request.method.as_str() {
+            "GET" => (200, comments([(1, "Looks good to me".into())])),
+            _ => (201, "{}".into()),
+        })
+        .await;
+
+        post_comment(&server, &gitea_actions(false)).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        let (get, post) = (&requests[0], &requests[1]);
+        assert_eq!(get.method, "GET");
+        assert_eq!(
+            get.path,
+            format!("{REPO_PATH}issues/{ISSUE_NUMBER}/comments")
+        );
+        assert_eq!(get.header("authorization"), Some("token gitea-token"));
+        assert_eq!(post.method, "POST");
+        assert_eq!(
+            post.path,
+            format!("{REPO_PATH}issues/{ISSUE_NUMBER}/comments")
+        );
+        let body: serde_json::Value = serde_json::from_str(&post.body).unwrap();
+        assert!(body["body"]
+            .as_str()
+            .unwrap()
+            .ends_with(&report_comment().bencher_tag(None)));
+    }
+
+    #[tokio::test]
+    async fn test_gitea_update_comment() {
+        let bencher_tag = report_comment().bencher_tag(None);
+        let server = MockServer::start(move |request| match request.method.as_str() {
+            "GET" => (
+                200,
+                comments([
+                    (1, "Looks good to me".into()),
+                    (42, format!("Previous results{bencher_tag}")),
+                ]),
+            ),
+            _ => (200, "{}".into()),
+        })
+        .await;
+
+        // An existing comment is always updated, even if there are no alerts.
+        post_comment(&server, &gitea_actions(true)).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "PATCH");
+        assert_eq!(requests[1].path, format!("{REPO_PATH}issues/comments/42"));
+    }
+
+    #[tokio::test]
+    async fn test_gitea_only_on_alert() {
+        let server = MockServer::start(|_| (200, comments([]))).await;
+
+        post_comment(&server, &gitea_actions(true)).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+    }
+
+    #[tokio::test]
+    async fn test_gitea_bad_permissions() {
+        for status in [401, 403] {
+            let server = MockServer::start(move |request| match request.method.as_str() {
+                "GET" => (200, comments([])),
+                _ => (status, "{}".into()),
+            })
+            .await;
+
+            let error = post_comment(&server, &gitea_actions(false))
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(error, GiteaError::BadPermissions(_)),
+                "{status}: {error}"
+            );
+        }
+    }
+}
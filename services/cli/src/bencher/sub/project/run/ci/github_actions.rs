@@ -1,11 +1,16 @@
 use bencher_comment::ReportComment;
-use octocrab::{models::CommentId, Octocrab};
+use octocrab::{
+    models::{CommentId, StatusState},
+    params::checks::{CheckRunConclusion, CheckRunOutput, CheckRunStatus},
+    Octocrab,
+};
 
-use crate::cli_println_quietable;
+use crate::{cli_eprintln_quietable, cli_println_quietable};
 
 const GITHUB_ACTIONS: &str = "GITHUB_ACTIONS";
 const GITHUB_EVENT_PATH: &str = "GITHUB_EVENT_PATH";
 const GITHUB_EVENT_NAME: &str = "GITHUB_EVENT_NAME";
+const GITHUB_SHA: &str = "GITHUB_SHA";
 
 const PULL_REQUEST: &str = "pull_request";
 const PULL_REQUEST_TARGET: &str = "pull_request_target";
@@ -23,6 +28,13 @@ pub struct GitHubActions {
     pub ci_id: Option<String>,
     pub ci_number: Option<u64>,
     pub ci_i_am_vulnerable_to_pwn_requests: bool,
+    pub ci_status: Option<GitHubStatus>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum GitHubStatus {
+    CheckRun,
+    CommitStatus,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -77,12 +89,41 @@ pub enum GitHubError {
     CreateComment(octocrab::Error),
     #[error("Failed to update GitHub PR comment: {0}")]
     UpdateComment(octocrab::Error),
-    #[error("GitHub Actions token (`GITHUB_TOKEN`) does not have `write` permissions for `pull-requests`.\n{help}\nError: {0}", help = PERMISSIONS_HELP)]
+    #[error("GitHub Actions token (`GITHUB_TOKEN`) does not have `write` permissions for `pull-requests`.\n{help}\nError: {0}", help = permissions_help(PULL_REQUESTS))]
     BadPermissions(octocrab::Error),
+    #[error(
+        "Failed to get the commit hash for the GitHub status (ex: `--hash 1234567890abcdef1234567890abcdef12345678`)\n{}",
+        docker_env(GITHUB_SHA)
+    )]
+    NoHash,
+    #[error("Failed to create GitHub Check Run: {0}")]
+    CreateCheckRun(octocrab::Error),
+    #[error("Failed to create GitHub commit status: {0}")]
+    CreateCommitStatus(octocrab::Error),
+    #[error("GitHub Actions token (`GITHUB_TOKEN`) does not have `write` permissions for `{permission}`.\n{help}\nError: {error}", help = permissions_help(permission))]
+    BadStatusPermissions {
+        permission: &'static str,
+        error: octocrab::Error,
+    },
 }
 
+const PULL_REQUESTS: &str = "pull-requests";
+const CHECKS: &str = "checks";
+const STATUSES: &str = "statuses";
+
 // https://docs.github.com/en/actions/using-jobs/assigning-permissions-to-jobs#setting-the-github_token-permissions-for-a-specific-job
-const PERMISSIONS_HELP: &str = "To fix, add `write` permissions to the job: `job: {{ \"permissions\": {{ \"pull-requests\": \"write\" }} }}`\nSee: https://bencher.dev/docs/how-to/github-actions/#pull-requests";
+fn permissions_help(permission: &str) -> String {
+    format!(
+        "To fix, add `write` permissions to the job: `job: {{ \"permissions\": {{ \"{permission}\": \"write\" }} }}`\nSee: https://bencher.dev/docs/how-to/github-actions/#pull-requests",
+    )
+}
+
+// https://github.blog/changelog/2023-02-02-github-actions-updating-the-default-github_token-permissions-to-read-only/
+fn is_permissions_error(error: &octocrab::Error) -> bool {
+    error
+        .to_string()
+        .contains("Resource not accessible by integration")
+}
 
 fn docker_env(env_var: &str) -> String {
     format!(
@@ -142,7 +183,6 @@ impl GitHubActions {
         Ok(())
     }
 
-    #[allow(clippy::too_many_lines)]
     pub async fn run(&self, report_comment: &ReportComment, log: bool) -> Result<(), GitHubError> {
         // Only post to CI if there are thresholds set
         if self.ci_only_thresholds && !report_comment.has_threshold() {
@@ -161,6 +201,51 @@ impl GitHubActions {
 
         let (event_str, event) = github_event()?;
 
+        let full_name = repository_full_name(&event_str, &event)?;
+        let (owner, repo) = split_full_name(full_name)?;
+
+        let github_client = Octocrab::builder()
+            .user_access_token(self.token.clone())
+            .build()
+            .map_err(GitHubError::Auth)?;
+
+        let comment = self
+            .post_comment(
+                &github_client,
+                owner,
+                repo,
+                &event_str,
+                &event,
+                report_comment,
+                log,
+            )
+            .await;
+
+        // The status is published for every event, not just pull requests.
+        // It is published after the comment, and failing to publish it does not fail the run.
+        if let Some(status) = self.ci_status {
+            if let Err(e) = self
+                .publish_status(&github_client, owner, repo, &event, report_comment, status)
+                .await
+            {
+                cli_eprintln_quietable!(log, "{e}");
+            }
+        }
+
+        comment
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn post_comment(
+        &self,
+        github_client: &Octocrab,
+        owner: &str,
+        repo: &str,
+        event_str: &str,
+        event: &serde_json::Value,
+        report_comment: &ReportComment,
+        log: bool,
+    ) -> Result<(), GitHubError> {
         // The name of the event that triggered the workflow. For example, `workflow_dispatch`.
         let issue_number = match std::env::var(GITHUB_EVENT_NAME).ok().as_deref() {
             // https://docs.github.com/en/actions/using-workflows/events-that-trigger-workflows#pull_request
@@ -173,11 +258,11 @@ impl GitHubActions {
                     event
                         .get("number")
                         .ok_or_else(|| {
-                            GitHubError::NoPRNumber(event_str.clone(), event_name.into())
+                            GitHubError::NoPRNumber(event_str.to_owned(), event_name.into())
                         })?
                         .as_u64()
                         .ok_or_else(|| {
-                            GitHubError::BadPRNumber(event_str.clone(), event_name.into())
+                            GitHubError::BadPRNumber(event_str.to_owned(), event_name.into())
                         })?
                 }
             },
@@ -196,17 +281,9 @@ impl GitHubActions {
             },
         };
 
-        let full_name = repository_full_name(&event_str, &event)?;
-        let (owner, repo) = split_full_name(full_name)?;
-
-        let github_client = Octocrab::builder()
-            .user_access_token(self.token.clone())
-            .build()
-            .map_err(GitHubError::Auth)?;
-
         // Get the comment ID if it exists
         let comment_id = get_comment(
-            &github_client,
+            github_client,
             owner,
             repo,
             issue_number,
@@ -228,27 +305,128 @@ impl GitHubActions {
             issue_handler.create_comment(issue_number, body).await
         };
         if let Err(e) = comment {
-            return Err(
-                // https://github.blog/changelog/2023-02-02-github-actions-updating-the-default-github_token-permissions-to-read-only/
-                if e.to_string()
-                    .contains("Resource not accessible by integration")
-                {
-                    GitHubError::BadPermissions(e)
-                } else if comment_id.is_some() {
-                    GitHubError::UpdateComment(e)
-                } else {
-                    GitHubError::CreateComment(e)
-                },
-            );
+            return Err(if is_permissions_error(&e) {
+                GitHubError::BadPermissions(e)
+            } else if comment_id.is_some() {
+                GitHubError::UpdateComment(e)
+            } else {
+                GitHubError::CreateComment(e)
+            });
         }
 
         Ok(())
     }
+
+    async fn publish_status(
+        &self,
+        github_client: &Octocrab,
+        owner: &str,
+        repo: &str,
+        event: &serde_json::Value,
+        report_comment: &ReportComment,
+        status: GitHubStatus,
+    ) -> Result<(), GitHubError> {
+        let head_sha = head_sha(event, report_comment).ok_or(GitHubError::NoHash)?;
+        // The name is unique for each Bencher ID so multiple runs do not overwrite each other
+        let name = format!(
+            "Bencher ({})",
+            report_comment.bencher_id(self.ci_id.as_deref())
+        );
+        let title = status_title(report_comment);
+
+        match status {
+            // https://docs.github.com/en/rest/checks/runs#create-a-check-run
+            GitHubStatus::CheckRun => {
+                let conclusion = if report_comment.has_alert() {
+                    CheckRunConclusion::Failure
+                } else if report_comment.has_threshold() {
+                    CheckRunConclusion::Success
+                } else {
+                    CheckRunConclusion::Neutral
+                };
+                github_client
+                    .checks(owner, repo)
+                    .create_check_run(name, head_sha)
+                    .status(CheckRunStatus::Completed)
+                    .conclusion(conclusion)
+                    .output(CheckRunOutput {
+                        title,
+                        summary: report_comment.text(),
+                        text: None,
+                        annotations: Vec::new(),
+                        images: Vec::new(),
+                    })
+                    .send()
+                    .await
+                    .map(drop)
+                    .map_err(|e| {
+                        if is_permissions_error(&e) {
+                            GitHubError::BadStatusPermissions {
+                                permission: CHECKS,
+                                error: e,
+                            }
+                        } else {
+                            GitHubError::CreateCheckRun(e)
+                        }
+                    })
+            },
+            // https://docs.github.com/en/rest/commits/statuses#create-a-commit-status
+            // Commit statuses do not have a neutral state.
+            GitHubStatus::CommitStatus => {
+                let state = if report_comment.has_alert() {
+                    StatusState::Failure
+                } else {
+                    StatusState::Success
+                };
+                github_client
+                    .repos(owner, repo)
+                    .create_status(head_sha, state)
+                    .context(name)
+                    .description(title)
+                    .send()
+                    .await
+                    .map(drop)
+                    .map_err(|e| {
+                        if is_permissions_error(&e) {
+                            GitHubError::BadStatusPermissions {
+                                permission: STATUSES,
+                                error: e,
+                            }
+                        } else {
+                            GitHubError::CreateCommitStatus(e)
+                        }
+                    })
+            },
+        }
+    }
+}
+
+// Prefer the pull request head, then the benchmarked hash, and finally the commit that triggered the workflow.
+// For pull requests, `GITHUB_SHA` and usually the benchmarked hash are the merge commit,
+// which is not shown on the pull request.
+fn head_sha(event: &serde_json::Value, report_comment: &ReportComment) -> Option<String> {
+    event
+        .get("pull_request")
+        .and_then(|pull_request| pull_request.get("head"))
+        .and_then(|head| head.get("sha"))
+        .and_then(serde_json::Value::as_str)
+        .map(ToOwned::to_owned)
+        .or_else(|| report_comment.hash().map(|hash| hash.as_ref().to_owned()))
+        .or_else(|| std::env::var(GITHUB_SHA).ok())
+}
+
+fn status_title(report_comment: &ReportComment) -> String {
+    match report_comment.alerts_count() {
+        0 if report_comment.has_threshold() => "No alerts".to_owned(),
+        0 => "No thresholds".to_owned(),
+        1 => "1 alert".to_owned(),
+        alerts_count => format!("{alerts_count} alerts"),
+    }
 }
 
 // https://docs.github.com/en/actions/learn-github-actions/variables#default-environment-variables
 // Always set to `true` when GitHub Actions is running the workflow. You can use this variable to differentiate when tests are being run locally or by GitHub Actions.
-fn is_github_actions() -> bool {
+pub(super) fn is_github_actions() -> bool {
     std::env::var(GITHUB_ACTIONS).ok().as_deref() == Some("true")
 }
 
@@ -0,0 +1,96 @@
+use std::io::Write;
+
+use bencher_comment::ReportComment;
+
+use crate::cli_println_quietable;
+
+use super::github_actions::is_github_actions;
+
+const GITHUB_STEP_SUMMARY: &str = "GITHUB_STEP_SUMMARY";
+
+#[derive(thiserror::Error, Debug)]
+pub enum GitHubSummaryError {
+    #[error("Failed to open GitHub Actions job summary ({0}): {1}")]
+    OpenStepSummary(String, std::io::Error),
+    #[error("Failed to write GitHub Actions job summary ({0}): {1}")]
+    WriteStepSummary(String, std::io::Error),
+}
+
+// The job summary and annotations do not require a token,
+// so they are output whenever they are enabled and running as a GitHub Action.
+pub fn github_summary(
+    report_comment: &ReportComment,
+    err: bool,
+    log: bool,
+) -> Result<(), GitHubSummaryError> {
+    if !is_github_actions() {
+        return Ok(());
+    }
+
+    // https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-a-warning-message
+    // https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-an-error-message
+    // Alerts are only errors if they will cause `bencher run` to fail.
+    let command = if err { "error" } else { "warning" };
+    for annotation in report_comment.alert_annotations() {
+        cli_println_quietable!(
+            log,
+            "::{command} title={title}::{message}",
+            title = escape_property(&annotation.title),
+            message = escape_data(&annotation.message),
+        );
+    }
+
+    // https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#adding-a-job-summary
+    let Ok(step_summary) = std::env::var(GITHUB_STEP_SUMMARY) else {
+        return Ok(());
+    };
+    let require_threshold = false;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&step_summary)
+        .map_err(|e| GitHubSummaryError::OpenStepSummary(step_summary.clone(), e))?;
+    writeln!(file, "{}", report_comment.markdown(require_threshold))
+        .map_err(|e| GitHubSummaryError::WriteStepSummary(step_summary, e))
+}
+
+// https://github.com/actions/toolkit/blob/main/packages/core/src/command.ts
+fn escape_data(data: &str) -> String {
+    data.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(property: &str) -> String {
+    escape_data(property)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{escape_data, escape_property};
+
+    #[test]
+    fn test_escape_data() {
+        assert_eq!(escape_data("Bencher Alert"), "Bencher Alert");
+        assert_eq!(escape_data("100%"), "100%25");
+        assert_eq!(escape_data("a\r\nb\nc"), "a%0D%0Ab%0Ac");
+        assert_eq!(escape_data("%0A"), "%250A");
+        // Colons and commas are only escaped in properties.
+        assert_eq!(escape_data("a: b, c"), "a: b, c");
+    }
+
+    #[test]
+    fn test_escape_property() {
+        assert_eq!(escape_property("Bencher Alert"), "Bencher Alert");
+        assert_eq!(
+            escape_property("Bencher Alert: bench (latency)"),
+            "Bencher Alert%3A bench (latency)"
+        );
+        assert_eq!(escape_property("a, b"), "a%2C b");
+        assert_eq!(escape_property("100%\n:,"), "100%25%0A%3A%2C");
+    }
+}
@@ -0,0 +1,387 @@
+use bencher_comment::ReportComment;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::cli_println_quietable;
+
+const GITLAB_CI: &str = "GITLAB_CI";
+const CI_API_V4_URL: &str = "CI_API_V4_URL";
+const CI_PROJECT_ID: &str = "CI_PROJECT_ID";
+const CI_MERGE_REQUEST_IID: &str = "CI_MERGE_REQUEST_IID";
+
+// https://docs.gitlab.com/ee/api/rest/#personalprojectgroup-access-tokens
+const PRIVATE_TOKEN: &str = "PRIVATE-TOKEN";
+
+#[derive(Debug)]
+pub struct GitLabCi {
+    pub token: String,
+    pub ci_only_thresholds: bool,
+    pub ci_only_on_alert: bool,
+    pub ci_id: Option<String>,
+    pub ci_number: Option<u64>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GitLabError {
+    #[error("Failed to get GitLab CI API URL\n{}", docker_env(CI_API_V4_URL))]
+    NoApiUrl,
+    #[error("Failed to parse GitLab CI API URL ({0}): {1}")]
+    BadApiUrl(String, url::ParseError),
+    #[error("Failed to get GitLab CI project ID\n{}", docker_env(CI_PROJECT_ID))]
+    NoProjectId,
+    #[error("GitLab CI merge request IID is invalid: {0}")]
+    BadMergeRequestIid(String),
+    #[error("GitLab CI token is not a valid header value: {0}")]
+    BadToken(reqwest::header::InvalidHeaderValue),
+    #[error("Failed to create GitLab API client: {0}")]
+    Client(reqwest::Error),
+    #[error("Failed to list GitLab MR notes: {0}")]
+    Notes(reqwest::Error),
+    #[error("Failed to create GitLab MR note: {0}")]
+    CreateNote(reqwest::Error),
+    #[error("Failed to update GitLab MR note: {0}")]
+    UpdateNote(reqwest::Error),
+    #[error("GitLab CI token does not have the `api` scope.\n{help}\nError: {0}", help = SCOPE_HELP)]
+    BadPermissions(reqwest::Error),
+}
+
+// https://docs.gitlab.com/ee/user/project/settings/project_access_tokens.html#scopes-for-a-project-access-token
+const SCOPE_HELP: &str = "To fix, use a project access token with the `api` scope and at least the `Reporter` role.\nSee: https://bencher.dev/docs/explanation/bencher-run/#--gitlab-ci-gitlab_token";
+
+fn docker_env(env_var: &str) -> String {
+    format!(
+        "If you are running in a Docker container, then you need to pass in the `{env_var}` environment variable. See https://bencher.dev/docs/explanation/bencher-run/#--gitlab-ci-gitlab_token",
+    )
+}
+
+// https://docs.gitlab.com/ee/api/notes.html#merge-requests
+#[derive(Debug, Deserialize)]
+struct GitLabNote {
+    id: u64,
+    body: String,
+}
+
+#[derive(Debug, Serialize)]
+struct GitLabNoteBody<'b> {
+    body: &'b str,
+}
+
+impl GitLabCi {
+    pub async fn run(&self, report_comment: &ReportComment, log: bool) -> Result<(), GitLabError> {
+        // Only post to CI if there are thresholds set
+        if self.ci_only_thresholds && !report_comment.has_threshold() {
+            cli_println_quietable!(log, "No thresholds set. Skipping CI integration.");
+            return Ok(());
+        }
+
+        if !is_gitlab_ci() {
+            cli_println_quietable!(
+                log,
+                "Not running in GitLab CI. Skipping CI integration.\n{}",
+                docker_env(GITLAB_CI)
+            );
+            return Ok(());
+        }
+
+        // The merge request IID is only set for merge request pipelines.
+        // https://docs.gitlab.com/ee/ci/pipelines/merge_request_pipelines.html
+        let merge_request_iid = if let Some(merge_request_iid) = self.ci_number {
+            merge_request_iid
+        } else if let Ok(merge_request_iid) = std::env::var(CI_MERGE_REQUEST_IID) {
+            merge_request_iid
+                .parse()
+                .map_err(|_e| GitLabError::BadMergeRequestIid(merge_request_iid))?
+        } else {
+            cli_println_quietable!(
+                log,
+                "Not running in a GitLab CI merge request pipeline. Skipping CI integration.\n{}",
+                docker_env(CI_MERGE_REQUEST_IID)
+            );
+            return Ok(());
+        };
+
+        let api_url = std::env::var(CI_API_V4_URL).map_err(|_e| GitLabError::NoApiUrl)?;
+        let project_id = std::env::var(CI_PROJECT_ID).map_err(|_e| GitLabError::NoProjectId)?;
+        // The trailing slash is needed so the API URL path is not replaced when joining.
+        let notes_url = Url::parse(&format!("{}/", api_url.trim_end_matches('/')))
+            .and_then(|url| {
+                url.join(&format!(
+                    "projects/{project_id}/merge_requests/{merge_request_iid}/notes"
+                ))
+            })
+            .map_err(|e| GitLabError::BadApiUrl(api_url, e))?;
+
+        let gitlab_client = GitLabClient::new(&self.token, notes_url)?;
+        self.post_note(&gitlab_client, report_comment, log).await
+    }
+
+    async fn post_note(
+        &self,
+        gitlab_client: &GitLabClient,
+        report_comment: &ReportComment,
+        log: bool,
+    ) -> Result<(), GitLabError> {
+        // Get the note ID if it exists
+        let note_id = gitlab_client
+            .get_note(&report_comment.bencher_tag(self.ci_id.as_deref()))
+            .await?;
+
+        // Update or create the note
+        let body = report_comment.html(self.ci_only_thresholds, self.ci_id.as_deref());
+        // Always update the note if it exists
+        let note = if let Some(note_id) = note_id {
+            gitlab_client.update_note(note_id, &body).await
+        } else {
+            if self.ci_only_on_alert && !report_comment.has_alert() {
+                cli_println_quietable!(log, "No alerts found. Skipping CI integration.");
+                return Ok(());
+            }
+            gitlab_client.create_note(&body).await
+        };
+        if let Err(e) = note {
+            return Err(
+                if matches!(
+                    e.status(),
+                    Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN)
+                ) {
+                    GitLabError::BadPermissions(e)
+                } else if note_id.is_some() {
+                    GitLabError::UpdateNote(e)
+                } else {
+                    GitLabError::CreateNote(e)
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
+
+// https://docs.gitlab.com/ee/ci/variables/predefined_variables.html
+// Available for all jobs executed in CI/CD. `true` when available.
+fn is_gitlab_ci() -> bool {
+    std::env::var(GITLAB_CI).ok().as_deref() == Some("true")
+}
+
+struct GitLabClient {
+    client: reqwest::Client,
+    notes_url: Url,
+}
+
+impl GitLabClient {
+    fn new(token: &str, notes_url: Url) -> Result<Self, GitLabError> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        let mut private_token =
+            reqwest::header::HeaderValue::from_str(token).map_err(GitLabError::BadToken)?;
+        private_token.set_sensitive(true);
+        headers.insert(PRIVATE_TOKEN, private_token);
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(GitLabError::Client)?;
+        Ok(Self { client, notes_url })
+    }
+
+    async fn get_note(&self, bencher_tag: &str) -> Result<Option<u64>, GitLabError> {
+        const PER_PAGE: u8 = 100;
+
+        let mut page: u32 = 1;
+        loop {
+            let notes: Vec<GitLabNote> = self
+                .client
+                .get(self.notes_url.clone())
+                .query(&[("per_page", u32::from(PER_PAGE)), ("page", page)])
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(GitLabError::Notes)?
+                .json()
+                .await
+                .map_err(GitLabError::Notes)?;
+
+            let notes_len = notes.len();
+            if notes_len == 0 {
+                return Ok(None);
+            }
+
+            for note in notes {
+                if note.body.ends_with(bencher_tag) {
+                    return Ok(Some(note.id));
+                }
+            }
+
+            if notes_len < usize::from(PER_PAGE) {
+                return Ok(None);
+            }
+
+            page += 1;
+        }
+    }
+
+    async fn create_note(&self, body: &str) -> Result<(), reqwest::Error> {
+        self.client
+            .post(self.notes_url.clone())
+            .json(&GitLabNoteBody { body })
+            .send()
+            .await?
+            .error_for_status()
+            .map(drop)
+    }
+
+    async fn update_note(&self, note_id: u64, body: &str) -> Result<(), reqwest::Error> {
+        self.client
+            .put(format!("{}/{note_id}", self.notes_url))
+            .json(&GitLabNoteBody { body })
+            .send()
+            .await?
+            .error_for_status()
+            .map(drop)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use url::Url;
+
+    use super::{GitLabCi, GitLabClient, GitLabError};
+    use crate::bencher::sub::project::run::ci::mock::{report_comment, MockServer};
+
+    const NOTES_PATH: &str = "/api/v4/projects/1/merge_requests/2/notes";
+
+    fn gitlab_ci(ci_only_on_alert: bool) -> GitLabCi {
+        GitLabCi {
+            token: "glpat-token".into(),
+            ci_only_thresholds: false,
+            ci_only_on_alert,
+            ci_id: None,
+            ci_number: None,
+        }
+    }
+
+    fn bencher_tag() -> String {
+        report_comment().bencher_tag(None)
+    }
+
+    fn notes(notes: impl IntoIterator<Item = (u64, String)>) -> String {
+        serde_json::Value::Array(
+            notes
+                .into_iter()
+                .map(|(id, body)| serde_json::json!({ "id": id, "body": body }))
+                .collect(),
+        )
+        .to_string()
+    }
+
+    async fn post_note(server: &MockServer, gitlab_ci: &GitLabCi) -> Result<(), GitLabError> {
+        let notes_url = Url::parse(&format!("{}{NOTES_PATH}", server.url())).unwrap();
+        let gitlab_client = GitLabClient::new(&gitlab_ci.token, notes_url).unwrap();
+        gitlab_ci
+            .post_note(&gitlab_client, &report_comment(), false)
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_gitlab_create_note() {
+        let server = MockServer::start(|request| match request.method.as_str() {
+            "GET" => (200, notes([(1, "Looks good to me".into())])),
+            _ => (201, "{}".into()),
+        })
+        .await;
+
+        post_note(&server, &gitlab_ci(false)).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        let (get, post) = (&requests[0], &requests[1]);
+        assert_eq!(get.method, "GET");
+        assert_eq!(get.path, format!("{NOTES_PATH}?per_page=100&page=1"));
+        assert_eq!(get.header("private-token"), Some("glpat-token"));
+        assert_eq!(post.method, "POST");
+        assert_eq!(post.path, NOTES_PATH);
+        let body: serde_json::Value = serde_json::from_str(&post.body).unwrap();
+        assert!(body["body"].as_str().unwrap().ends_with(&bencher_tag()));
+    }
+
+    #[tokio::test]
+    async fn test_gitlab_update_note() {
+        let bencher_tag = bencher_tag();
+        let server = MockServer::start(move |request| match request.method.as_str() {
+            "GET" => (
+                200,
+                notes([
+                    (1, "Looks good to me".into()),
+                    (42, format!("Previous results{bencher_tag}")),
+                ]),
+            ),
+            _ => (200, "{}".into()),
+        })
+        .await;
+
+        // An existing note is always updated, even if there are no alerts.
+        post_note(&server, &gitlab_ci(true)).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "PUT");
+        assert_eq!(requests[1].path, format!("{NOTES_PATH}/42"));
+    }
+
+    #[tokio::test]
+    async fn test_gitlab_get_note_pages() {
+        let bencher_tag = bencher_tag();
+        let server = MockServer::start(move |request| {
+            if request.method != "GET" {
+                (200, "{}".into())
+            } else if request.path.ends_with("page=1") {
+                (200, notes((0..100).map(|id| (id, "Other".to_owned()))))
+            } else {
+                (
+                    200,
+                    notes([(142, format!("Previous results{bencher_tag}"))]),
+                )
+            }
+        })
+        .await;
+
+        post_note(&server, &gitlab_ci(false)).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests[1].path,
+            format!("{NOTES_PATH}?per_page=100&page=2")
+        );
+        assert_eq!(requests[2].method, "PUT");
+        assert_eq!(requests[2].path, format!("{NOTES_PATH}/142"));
+    }
+
+    #[tokio::test]
+    async fn test_gitlab_only_on_alert() {
+        let server = MockServer::start(|_| (200, notes([]))).await;
+
+        post_note(&server, &gitlab_ci(true)).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+    }
+
+    #[tokio::test]
+    async fn test_gitlab_bad_permissions() {
+        for status in [401, 403] {
+            let server = MockServer::start(move |request| match request.method.as_str() {
+                "GET" => (200, notes([])),
+                _ => (status, "{}".into()),
+            })
+            .await;
+
+            let error = post_note(&server, &gitlab_ci(false)).await.unwrap_err();
+            assert!(
+                matches!(error, GitLabError::BadPermissions(_)),
+                "{status}: {error}"
+            );
+        }
+    }
+}
@@ -0,0 +1,172 @@
+#![allow(clippy::unwrap_used)]
+
+use std::sync::{Arc, Mutex};
+
+use bencher_comment::ReportComment;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+};
+
+const REPORT: &str = r#"{
+    "uuid": "2b4a0e4c-2ab5-4a3f-9b43-0bd8d3ddc8cb",
+    "user": {
+        "uuid": "6c1b4a1e-bd9a-4c1e-a0f9-0f6e1a48b3a2",
+        "name": "Muriel Bagge",
+        "slug": "muriel-bagge",
+        "email": "muriel.bagge@nowhere.com",
+        "admin": false,
+        "locked": false
+    },
+    "project": {
+        "uuid": "f4c1b0a6-4c43-4b8b-9b1b-0e2f5b3f2d1e",
+        "organization": "a1f1d3c5-0e8b-4f4e-8c4f-3b5d9e2f1a7c",
+        "name": "The Computer",
+        "slug": "the-computer",
+        "url": null,
+        "visibility": "public",
+        "created": "2024-01-01T00:00:00Z",
+        "modified": "2024-01-01T00:00:00Z"
+    },
+    "branch": {
+        "uuid": "0a8e9c2b-7d5f-4e3a-b1c6-2f4d8e6a9b0c",
+        "project": "f4c1b0a6-4c43-4b8b-9b1b-0e2f5b3f2d1e",
+        "name": "main",
+        "slug": "main",
+        "version": {
+            "number": 1,
+            "hash": "1234567890abcdef1234567890abcdef12345678"
+        },
+        "start_point": null,
+        "created": "2024-01-01T00:00:00Z",
+        "modified": "2024-01-01T00:00:00Z"
+    },
+    "testbed": {
+        "uuid": "5e2d7b1a-9c4f-4a6e-8b3d-1f0c2e4a6b8d",
+        "project": "f4c1b0a6-4c43-4b8b-9b1b-0e2f5b3f2d1e",
+        "name": "localhost",
+        "slug": "localhost",
+        "created": "2024-01-01T00:00:00Z",
+        "modified": "2024-01-01T00:00:00Z"
+    },
+    "start_time": "2024-01-01T00:00:00Z",
+    "end_time": "2024-01-01T00:01:00Z",
+    "adapter": "magic",
+    "results": [],
+    "alerts": [],
+    "created": "2024-01-01T00:01:00Z"
+}"#;
+
+pub fn report_comment() -> ReportComment {
+    let console_url = "https://bencher.dev".parse().unwrap();
+    ReportComment::new(console_url, serde_json::from_str(REPORT).unwrap())
+}
+
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    pub method: String,
+    /// The path, including the query string.
+    pub path: String,
+    /// The header names are lowercase.
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find_map(|(key, value)| (key == name).then_some(value.as_str()))
+    }
+}
+
+/// A local HTTP server that responds to every request with the status and JSON body
+/// returned by its handler, and records every request that it receives.
+pub struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockServer {
+    pub async fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&MockRequest) -> (u16, String) + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let handler = Arc::new(handler);
+        let server_requests = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let handler = handler.clone();
+                let requests = server_requests.clone();
+                tokio::spawn(async move {
+                    let _result = serve(stream, handler.as_ref(), &requests).await;
+                });
+            }
+        });
+        Self { url, requests }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn serve<F>(
+    stream: TcpStream,
+    handler: &F,
+    requests: &Mutex<Vec<MockRequest>>,
+) -> std::io::Result<()>
+where
+    F: Fn(&MockRequest) -> (u16, String),
+{
+    let mut reader = BufReader::new(stream);
+    // Keep serving requests on the same connection until the client closes it.
+    loop {
+        let mut request_line = String::new();
+        if reader.read_line(&mut request_line).await? == 0 {
+            return Ok(());
+        }
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_owned();
+        let path = parts.next().unwrap_or_default().to_owned();
+
+        let mut headers = Vec::new();
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).await?;
+            let Some((name, value)) = header.trim_end().split_once(':') else {
+                break;
+            };
+            headers.push((name.to_lowercase(), value.trim().to_owned()));
+        }
+        let content_length = headers
+            .iter()
+            .find_map(|(name, value)| (name == "content-length").then(|| value.parse().ok()))
+            .flatten()
+            .unwrap_or_default();
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).await?;
+
+        let request = MockRequest {
+            method,
+            path,
+            headers,
+            body: String::from_utf8_lossy(&body).into_owned(),
+        };
+        let (status, response) = handler(&request);
+        requests.lock().unwrap().push(request);
+
+        let response = format!(
+            "HTTP/1.1 {status} Mock\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{response}",
+            response.len()
+        );
+        reader.get_mut().write_all(response.as_bytes()).await?;
+    }
+}
@@ -1,20 +1,42 @@
 use bencher_comment::ReportComment;
 
-use crate::parser::project::run::CliRunCi;
+use crate::parser::project::run::{CliRunCi, CliRunCiStatus};
 
+mod bitbucket_pipelines;
+mod gitea_actions;
 mod github_actions;
+mod github_summary;
+mod gitlab_ci;
+#[cfg(test)]
+mod mock;
 
-use github_actions::{GitHubActions, GitHubError};
+use bitbucket_pipelines::{BitbucketError, BitbucketPipelines};
+use gitea_actions::{GiteaActions, GiteaError};
+use github_actions::{GitHubActions, GitHubError, GitHubStatus};
+pub use github_summary::github_summary;
+use github_summary::GitHubSummaryError;
+use gitlab_ci::{GitLabCi, GitLabError};
 
 #[derive(Debug)]
 pub enum Ci {
     GitHubActions(GitHubActions),
+    GitLab(GitLabCi),
+    GiteaActions(GiteaActions),
+    BitbucketPipelines(BitbucketPipelines),
 }
 
 #[derive(thiserror::Error, Debug)]
 pub enum CiError {
     #[error("{0}")]
     GitHub(#[from] GitHubError),
+    #[error("{0}")]
+    GitHubSummary(#[from] GitHubSummaryError),
+    #[error("{0}")]
+    GitLab(#[from] GitLabError),
+    #[error("{0}")]
+    Gitea(#[from] GiteaError),
+    #[error("{0}")]
+    Bitbucket(#[from] BitbucketError),
 }
 
 impl TryFrom<CliRunCi> for Option<Ci> {
@@ -23,16 +45,21 @@ impl TryFrom<CliRunCi> for Option<Ci> {
     fn try_from(ci: CliRunCi) -> Result<Self, Self::Error> {
         let CliRunCi {
             github_actions,
+            gitlab_ci,
+            gitea_actions,
+            bitbucket_pipelines,
             ci_only_thresholds,
             ci_only_on_alert,
             ci_public_links,
             ci_id,
             ci_number,
             ci_i_am_vulnerable_to_pwn_requests,
+            ci_status,
+            ci_github_summary: _,
             ci_deprecated: _,
         } = ci;
-        Ok(github_actions.map(|token| {
-            Ci::GitHubActions(GitHubActions {
+        Ok(if let Some(token) = github_actions {
+            Some(Ci::GitHubActions(GitHubActions {
                 token,
                 ci_only_thresholds,
                 ci_only_on_alert,
@@ -40,8 +67,36 @@ impl TryFrom<CliRunCi> for Option<Ci> {
                 ci_id,
                 ci_number,
                 ci_i_am_vulnerable_to_pwn_requests,
+                ci_status: ci_status.map(Into::into),
+            }))
+        } else if let Some(token) = gitlab_ci {
+            Some(Ci::GitLab(GitLabCi {
+                token,
+                ci_only_thresholds,
+                ci_only_on_alert,
+                ci_id,
+                ci_number,
+            }))
+        } else if let Some(token) = gitea_actions {
+            Some(Ci::GiteaActions(GiteaActions {
+                token,
+                ci_only_thresholds,
+                ci_only_on_alert,
+                ci_id,
+                ci_number,
+                ci_i_am_vulnerable_to_pwn_requests,
+            }))
+        } else {
+            bitbucket_pipelines.map(|token| {
+                Ci::BitbucketPipelines(BitbucketPipelines {
+                    token,
+                    ci_only_thresholds,
+                    ci_only_on_alert,
+                    ci_id,
+                    ci_number,
+                })
             })
-        }))
+        })
     }
 }
 
@@ -51,6 +106,13 @@ impl Ci {
             Self::GitHubActions(github_actions) => {
                 github_actions.safety_check(log).map_err(Into::into)
             },
+            Self::GiteaActions(gitea_actions) => {
+                gitea_actions.safety_check(log).map_err(Into::into)
+            },
+            // GitLab CI merge request pipelines for forks run in the parent project
+            // only after they are explicitly approved by a maintainer.
+            // Bitbucket Pipelines does not share secrets with pull requests from forks.
+            Self::GitLab(_) | Self::BitbucketPipelines(_) => Ok(()),
         }
     }
 
@@ -60,6 +122,24 @@ impl Ci {
                 .run(report_comment, log)
                 .await
                 .map_err(Into::into),
+            Self::GitLab(gitlab_ci) => gitlab_ci.run(report_comment, log).await.map_err(Into::into),
+            Self::GiteaActions(gitea_actions) => gitea_actions
+                .run(report_comment, log)
+                .await
+                .map_err(Into::into),
+            Self::BitbucketPipelines(bitbucket_pipelines) => bitbucket_pipelines
+                .run(report_comment, log)
+                .await
+                .map_err(Into::into),
+        }
+    }
+}
+
+impl From<CliRunCiStatus> for GitHubStatus {
+    fn from(status: CliRunCiStatus) -> Self {
+        match status {
+            CliRunCiStatus::CheckRun => Self::CheckRun,
+            CliRunCiStatus::CommitStatus => Self::CommitStatus,
         }
     }
 }
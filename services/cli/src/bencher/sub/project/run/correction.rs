@@ -0,0 +1,12 @@
+use bencher_client::types::JsonCorrection;
+
+use crate::parser::project::run::CliRunCorrection;
+
+impl From<CliRunCorrection> for JsonCorrection {
+    fn from(correction: CliRunCorrection) -> Self {
+        match correction {
+            CliRunCorrection::Bonferroni => Self::Bonferroni,
+            CliRunCorrection::BenjaminiHochberg => Self::BenjaminiHochberg,
+        }
+    }
+}
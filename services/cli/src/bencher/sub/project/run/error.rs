@@ -64,6 +64,13 @@ pub enum RunError {
     #[error("Failed to serialize file size results: {0}")]
     SerializeFileSize(serde_json::Error),
 
+    #[error("{0}")]
+    CustomAdapter(crate::bencher::sub::CustomAdapterError),
+    #[error("Failed to parse benchmark harness results with the custom adapter: No lines matched its patterns")]
+    CustomAdapterResults,
+    #[error("Failed to serialize custom adapter results: {0}")]
+    SerializeCustomResults(serde_json::Error),
+
     #[error("Failed to serialize report JSON: {0}")]
     SerializeReport(serde_json::Error),
     #[error("Failed to create new report: {0}")]
@@ -74,6 +81,11 @@ pub enum RunError {
     BadEndpoint(bencher_json::ValidError),
     #[error("Alerts detected ({0})")]
     Alerts(usize),
+    #[error("Failed to write the Report to the output file ({path}): {err}")]
+    WriteOutput {
+        path: camino::Utf8PathBuf,
+        err: std::io::Error,
+    },
 
     #[error("{0}")]
     Ci(#[from] super::ci::CiError),
@@ -0,0 +1,67 @@
+use bencher_comment::ReportComment;
+
+use crate::parser::project::run::{CliRunFmt, CliRunFormat};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Format {
+    Text,
+    Json,
+    Html,
+    Markdown,
+    Junit,
+}
+
+impl From<CliRunFormat> for Format {
+    fn from(format: CliRunFormat) -> Self {
+        match format {
+            CliRunFormat::Text => Self::Text,
+            CliRunFormat::Json => Self::Json,
+            CliRunFormat::Html => Self::Html,
+            CliRunFormat::Markdown => Self::Markdown,
+            CliRunFormat::Junit => Self::Junit,
+        }
+    }
+}
+
+impl From<&CliRunFmt> for Format {
+    // The deprecated `--html` and `--quiet` flags are still supported
+    fn from(fmt: &CliRunFmt) -> Self {
+        if let Some(format) = fmt.format {
+            format.into()
+        } else if fmt.html {
+            Self::Html
+        } else if fmt.quiet {
+            Self::Json
+        } else {
+            Self::Text
+        }
+    }
+}
+
+impl Format {
+    // Only the final Report JSON is output, without any logging
+    pub fn is_json(self) -> bool {
+        matches!(self, Self::Json)
+    }
+
+    // Logging to stdout would corrupt any other machine readable format,
+    // unless the final Report is written to an output file instead.
+    pub fn log(self, output_file: bool) -> bool {
+        match self {
+            Self::Text => true,
+            Self::Json => false,
+            Self::Html | Self::Markdown | Self::Junit => output_file,
+        }
+    }
+
+    // The JSON format is output directly from the Report, not the Report comment
+    pub fn render(self, report_comment: &ReportComment) -> String {
+        let require_threshold = false;
+        match self {
+            Self::Text | Self::Json => report_comment.text(),
+            Self::Html => report_comment.html(require_threshold, None),
+            Self::Markdown => report_comment.markdown(require_threshold),
+            Self::Junit => report_comment.junit(),
+        }
+    }
+}
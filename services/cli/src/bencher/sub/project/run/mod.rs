@@ -1,15 +1,17 @@
 use std::{future::Future, pin::Pin};
 
-use bencher_client::types::{Adapter, JsonAverage, JsonFold, JsonNewReport, JsonReportSettings};
+use bencher_adapter::{diagnose, Adaptable, AdapterCustom, Settings as AdapterSettings};
+use bencher_client::types::{Adapter, JsonCorrection, JsonFold, JsonNewReport, JsonReportSettings};
 use bencher_comment::ReportComment;
 use bencher_json::{DateTime, JsonConsole, JsonReport, ResourceId};
+use camino::Utf8PathBuf;
 use clap::ValueEnum;
 use url::Url;
 
 use crate::{
-    bencher::backend::AuthBackend,
+    bencher::{backend::AuthBackend, sub::adapter::custom::read_custom_adapter},
     cli_eprintln_quietable, cli_println, cli_println_quietable,
-    parser::project::run::{CliRun, CliRunAdapter},
+    parser::project::run::{CliRun, CliRunAdapter, CliRunAverage},
     CliError,
 };
 
@@ -17,14 +19,17 @@ mod adapter;
 mod average;
 mod branch;
 mod ci;
+mod correction;
 mod error;
 mod fold;
+mod format;
 pub mod runner;
 mod testbed;
 
 use branch::Branch;
 use ci::Ci;
 pub use error::RunError;
+use format::Format;
 use runner::Runner;
 use testbed::Testbed;
 
@@ -42,16 +47,20 @@ pub struct Run {
     project: ResourceId,
     branch: Branch,
     testbed: Testbed,
-    adapter: Option<Adapter>,
-    average: Option<JsonAverage>,
+    adapter: Option<CliRunAdapter>,
+    custom_adapter: Option<AdapterCustom>,
+    average: Option<CliRunAverage>,
     iter: usize,
     fold: Option<JsonFold>,
+    correction: Option<JsonCorrection>,
     backdate: Option<DateTime>,
     allow_failure: bool,
     err: bool,
-    html: bool,
+    format: Format,
+    output: Option<Utf8PathBuf>,
     log: bool,
     ci: Option<Ci>,
+    github_summary: bool,
     runner: Runner,
     #[allow(clippy::struct_field_names)]
     dry_run: bool,
@@ -67,9 +76,11 @@ impl TryFrom<CliRun> for Run {
             branch,
             testbed,
             adapter,
+            adapter_config,
             average,
             iter,
             fold,
+            correction,
             backdate,
             allow_failure,
             err,
@@ -79,19 +90,28 @@ impl TryFrom<CliRun> for Run {
             dry_run,
             backend,
         } = run;
+        let format = Format::from(&fmt);
         Ok(Self {
             project: unwrap_project(project)?,
             branch: branch.try_into().map_err(RunError::Branch)?,
             testbed: testbed.try_into().map_err(RunError::Testbed)?,
             adapter: map_adapter(adapter),
-            average: average.map(Into::into),
+            custom_adapter: adapter_config
+                .as_deref()
+                .map(read_custom_adapter)
+                .transpose()
+                .map_err(RunError::CustomAdapter)?,
+            average,
             iter: iter.unwrap_or(1),
             fold: fold.map(Into::into),
+            correction: correction.map(Into::into),
             backdate,
             allow_failure,
             err,
-            html: fmt.html,
-            log: !fmt.quiet,
+            format,
+            log: format.log(fmt.output.is_some()),
+            output: fmt.output,
+            github_summary: ci.ci_github_summary,
             ci: ci.try_into().map_err(RunError::Ci)?,
             runner: cmd.try_into()?,
             dry_run,
@@ -110,18 +130,12 @@ fn unwrap_project(project: Option<ResourceId>) -> Result<ResourceId, RunError> {
     })
 }
 
-fn map_adapter(adapter: Option<CliRunAdapter>) -> Option<Adapter> {
-    if let Some(adapter) = adapter {
-        Some(adapter.into())
-    } else if let Ok(env_adapter) = std::env::var(BENCHER_ADAPTER) {
-        if let Ok(cli_adapter) = CliRunAdapter::from_str(&env_adapter, false) {
-            Some(cli_adapter.into())
-        } else {
-            None
-        }
-    } else {
-        None
-    }
+fn map_adapter(adapter: Option<CliRunAdapter>) -> Option<CliRunAdapter> {
+    adapter.or_else(|| {
+        std::env::var(BENCHER_ADAPTER)
+            .ok()
+            .and_then(|env_adapter| CliRunAdapter::from_str(&env_adapter, false).ok())
+    })
 }
 
 impl SubCmd for Run {
@@ -162,26 +176,26 @@ impl Run {
         }
 
         let sender = report_sender(self.project.clone(), json_new_report);
-        // If we are not doing complex output logging then we don't need to a strict deserialization.
-        if !self.log {
+        // If we are only outputting the Report JSON then we don't need to a strict deserialization.
+        if self.format.is_json() {
             let json_report = self
                 .backend
                 .send(sender)
                 .await
                 .map_err(RunError::SendReport)?;
-            return serde_json::to_string_pretty(&json_report)
-                .map(|json| cli_println!("{json}"))
-                .map_err(RunError::SerializeReport);
+            let json =
+                serde_json::to_string_pretty(&json_report).map_err(RunError::SerializeReport)?;
+            return self.write_output(&json);
         }
 
-        cli_println!("\nBencher Report:");
+        cli_println_quietable!(self.log, "\nBencher Report:");
         let json_report: JsonReport = self
             .backend
             .send_with(sender)
             .await
             .map_err(RunError::SendReport)?;
         if let Ok(json) = serde_json::to_string_pretty(&json_report) {
-            cli_println!("{json}");
+            cli_println_quietable!(self.log, "{json}");
         }
 
         let alerts_count = json_report.alerts.len();
@@ -224,6 +238,13 @@ impl Run {
         for result in &results {
             cli_println_quietable!(self.log, "{result}");
         }
+        let (results, adapter) = if let Some(custom_adapter) = &self.custom_adapter {
+            let results = self.convert_custom_results(custom_adapter, &results)?;
+            (results, Some(Adapter::Json))
+        } else {
+            self.diagnose_results(&results);
+            (results, self.adapter.map(Into::into))
+        };
 
         let end_time = DateTime::now();
         // If a backdate is set then use it as the start time and calculate the end time from there
@@ -242,13 +263,48 @@ impl Run {
             end_time: end_time.into(),
             results,
             settings: Some(JsonReportSettings {
-                adapter: self.adapter,
-                average: self.average,
+                adapter,
+                average: self.average.map(Into::into),
                 fold: self.fold,
+                correction: self.correction,
             }),
         }))
     }
 
+    // The results are parsed locally and then sent as Bencher Metric Format JSON.
+    fn convert_custom_results(
+        &self,
+        custom_adapter: &AdapterCustom,
+        results: &[String],
+    ) -> Result<Vec<String>, RunError> {
+        let settings = AdapterSettings::new(self.average.map(Into::into));
+        results
+            .iter()
+            .map(|result| {
+                let adapter_results = custom_adapter
+                    .convert(result, settings)
+                    .ok_or(RunError::CustomAdapterResults)?;
+                serde_json::to_string(&adapter_results).map_err(RunError::SerializeCustomResults)
+            })
+            .collect()
+    }
+
+    // The results are parsed by the API server,
+    // so any parse failures are only explained here as a warning.
+    fn diagnose_results(&self, results: &[String]) {
+        let adapter: bencher_json::project::report::Adapter =
+            self.adapter.map(Into::into).unwrap_or_default();
+        let settings = AdapterSettings::new(self.average.map(Into::into));
+        for result in results {
+            if let Err(diagnostics) = diagnose(adapter, result, settings) {
+                cli_eprintln_quietable!(
+                    self.log,
+                    "\nWarning: Failed to parse benchmark harness results with the {adapter} adapter:\n{diagnostics}"
+                );
+            }
+        }
+    }
+
     async fn display_results(&self, json_report: JsonReport) -> Result<(), RunError> {
         let json_console: JsonConsole = self
             .backend
@@ -258,11 +314,10 @@ impl Run {
         let console_url: Url = json_console.url.try_into().map_err(RunError::BadEndpoint)?;
         let report_comment = ReportComment::new(console_url, json_report);
 
-        if self.html {
-            let require_threshold = false;
-            cli_println!("{}", report_comment.html(require_threshold, None));
-        } else {
-            cli_println!("{}", report_comment.text());
+        self.write_output(&self.format.render(&report_comment))?;
+
+        if self.github_summary {
+            ci::github_summary(&report_comment, self.err, self.log).map_err(ci::CiError::from)?;
         }
 
         if let Some(ci) = &self.ci {
@@ -271,6 +326,18 @@ impl Run {
 
         Ok(())
     }
+
+    fn write_output(&self, output: &str) -> Result<(), RunError> {
+        if let Some(path) = &self.output {
+            std::fs::write(path, output).map_err(|err| RunError::WriteOutput {
+                path: path.clone(),
+                err,
+            })
+        } else {
+            cli_println!("{output}");
+            Ok(())
+        }
+    }
 }
 
 type ReportResult = Pin<
@@ -78,6 +78,10 @@ impl From<Create> for JsonNewThreshold {
             min_sample_size,
             max_sample_size,
             window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
             lower_boundary,
             upper_boundary,
         } = model;
@@ -89,6 +93,10 @@ impl From<Create> for JsonNewThreshold {
             min_sample_size,
             max_sample_size,
             window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
             lower_boundary,
             upper_boundary,
         }
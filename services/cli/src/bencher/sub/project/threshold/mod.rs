@@ -4,6 +4,7 @@ mod create;
 mod delete;
 mod list;
 mod model;
+mod simulate;
 mod update;
 mod view;
 
@@ -16,6 +17,7 @@ pub enum Threshold {
     View(view::View),
     Update(update::Update),
     Delete(delete::Delete),
+    Simulate(simulate::Simulate),
 }
 
 impl TryFrom<CliThreshold> for Threshold {
@@ -28,6 +30,7 @@ impl TryFrom<CliThreshold> for Threshold {
             CliThreshold::View(view) => Self::View(view.try_into()?),
             CliThreshold::Update(update) => Self::Update(update.try_into()?),
             CliThreshold::Delete(delete) => Self::Delete(delete.try_into()?),
+            CliThreshold::Simulate(simulate) => Self::Simulate(simulate.try_into()?),
         })
     }
 }
@@ -40,6 +43,7 @@ impl SubCmd for Threshold {
             Self::View(view) => view.exec().await,
             Self::Update(update) => update.exec().await,
             Self::Delete(delete) => delete.exec().await,
+            Self::Simulate(simulate) => simulate.exec().await,
         }
     }
 }
@@ -1,4 +1,4 @@
-use bencher_client::types::{Boundary, ModelTest, SampleSize, Window};
+use bencher_client::types::{Boundary, ModelTest, SampleSize, SmoothingFactor, Violations, Window};
 
 use crate::{
     parser::project::threshold::{CliModel, CliModelTest},
@@ -11,6 +11,10 @@ pub struct Model {
     pub min_sample_size: Option<SampleSize>,
     pub max_sample_size: Option<SampleSize>,
     pub window: Option<Window>,
+    pub smoothing_factor: Option<SmoothingFactor>,
+    pub violations: Option<Violations>,
+    pub violation_window: Option<Violations>,
+    pub start_point_baseline: Option<bool>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
 }
@@ -24,14 +28,23 @@ impl TryFrom<CliModel> for Model {
             min_sample_size,
             max_sample_size,
             window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
             lower_boundary,
             upper_boundary,
         } = model;
+        let start_point_baseline = start_point_baseline.then_some(true);
         bencher_json::Model {
             test: test.into(),
             min_sample_size,
             max_sample_size,
             window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
             lower_boundary,
             upper_boundary,
         }
@@ -42,6 +55,10 @@ impl TryFrom<CliModel> for Model {
             min_sample_size: min_sample_size.map(Into::into),
             max_sample_size: max_sample_size.map(Into::into),
             window: window.map(Into::into),
+            smoothing_factor: smoothing_factor.map(Into::into),
+            violations: violations.map(Into::into),
+            violation_window: violation_window.map(Into::into),
+            start_point_baseline,
             lower_boundary: lower_boundary.map(Into::into),
             upper_boundary: upper_boundary.map(Into::into),
         })
@@ -58,6 +75,13 @@ impl From<CliModelTest> for bencher_json::ModelTest {
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::ChangePoint => Self::ChangePoint,
+            CliModelTest::WelchTTest => Self::WelchTTest,
+            CliModelTest::MannWhitneyU => Self::MannWhitneyU,
+            CliModelTest::BootstrapMean => Self::BootstrapMean,
+            CliModelTest::BootstrapMedian => Self::BootstrapMedian,
+            CliModelTest::Ewma => Self::Ewma,
+            CliModelTest::InverseVariance => Self::InverseVariance,
         }
     }
 }
@@ -72,6 +96,13 @@ impl From<CliModelTest> for ModelTest {
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::ChangePoint => Self::ChangePoint,
+            CliModelTest::WelchTTest => Self::WelchTTest,
+            CliModelTest::MannWhitneyU => Self::MannWhitneyU,
+            CliModelTest::BootstrapMean => Self::BootstrapMean,
+            CliModelTest::BootstrapMedian => Self::BootstrapMedian,
+            CliModelTest::Ewma => Self::Ewma,
+            CliModelTest::InverseVariance => Self::InverseVariance,
         }
     }
 }
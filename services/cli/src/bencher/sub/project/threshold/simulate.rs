@@ -0,0 +1,85 @@
+use bencher_client::types::JsonSimulateThreshold;
+use bencher_json::{ResourceId, ThresholdUuid};
+
+use super::model::Model;
+use crate::{
+    bencher::{backend::PubBackend, sub::SubCmd},
+    parser::project::threshold::CliThresholdSimulate,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Simulate {
+    pub project: ResourceId,
+    pub threshold: ThresholdUuid,
+    pub model: Model,
+    pub backend: PubBackend,
+}
+
+impl TryFrom<CliThresholdSimulate> for Simulate {
+    type Error = CliError;
+
+    fn try_from(simulate: CliThresholdSimulate) -> Result<Self, Self::Error> {
+        let CliThresholdSimulate {
+            project,
+            threshold,
+            model,
+            backend,
+        } = simulate;
+        Ok(Self {
+            project,
+            threshold,
+            model: model.try_into()?,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Simulate> for JsonSimulateThreshold {
+    fn from(simulate: Simulate) -> Self {
+        let Simulate { model, .. } = simulate;
+        let Model {
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
+            lower_boundary,
+            upper_boundary,
+        } = model;
+        #[allow(clippy::inconsistent_struct_constructor)]
+        Self {
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
+            lower_boundary,
+            upper_boundary,
+        }
+    }
+}
+
+impl SubCmd for Simulate {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_threshold_simulate_post()
+                    .project(self.project.clone())
+                    .threshold(self.threshold)
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -43,6 +43,10 @@ impl From<Update> for JsonUpdateThreshold {
             min_sample_size,
             max_sample_size,
             window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
             lower_boundary,
             upper_boundary,
         } = model;
@@ -52,6 +56,10 @@ impl From<Update> for JsonUpdateThreshold {
             min_sample_size,
             max_sample_size,
             window,
+            smoothing_factor,
+            violations,
+            violation_window,
+            start_point_baseline,
             lower_boundary,
             upper_boundary,
         }
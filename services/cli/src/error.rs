@@ -7,6 +7,8 @@ pub enum CliError {
     #[error("{0}")]
     Threshold(#[from] crate::bencher::sub::ThresholdError),
     #[error("{0}")]
+    Check(#[from] crate::bencher::sub::CheckError),
+    #[error("{0}")]
     Mock(#[from] crate::bencher::sub::MockError),
     #[error("{0}")]
     Docker(#[from] crate::bencher::sub::DockerError),
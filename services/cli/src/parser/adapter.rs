@@ -0,0 +1,28 @@
+use camino::Utf8PathBuf;
+use clap::{Parser, Subcommand};
+
+use crate::parser::project::run::{CliRunAdapter, CliRunAverage};
+
+#[derive(Subcommand, Debug)]
+pub enum CliAdapter {
+    /// Check that benchmark harness output can be parsed by an adapter
+    Check(CliAdapterCheck),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliAdapterCheck {
+    /// Benchmark harness output file path
+    pub file: Utf8PathBuf,
+
+    /// Benchmark harness adapter (default is magic)
+    #[clap(value_enum, long)]
+    pub adapter: Option<CliRunAdapter>,
+
+    /// Custom adapter config file path
+    #[clap(long, conflicts_with = "adapter")]
+    pub adapter_config: Option<Utf8PathBuf>,
+
+    /// Benchmark harness suggested central tendency (ie average)
+    #[clap(value_enum, long)]
+    pub average: Option<CliRunAverage>,
+}
@@ -1,6 +1,7 @@
 use bencher_json::{Jwt, Url};
 use clap::{Args, Parser, Subcommand, ValueEnum};
 
+pub mod adapter;
 pub mod docker;
 pub mod mock;
 pub mod organization;
@@ -8,6 +9,7 @@ pub mod project;
 pub mod system;
 pub mod user;
 
+use adapter::CliAdapter;
 use docker::{CliDown, CliLogs, CliUp};
 use mock::CliMock;
 use organization::{member::CliMember, CliOrganization};
@@ -88,6 +90,9 @@ pub enum CliSub {
     #[clap(subcommand)]
     Server(CliServer),
 
+    /// Check benchmark harness output with an adapter
+    #[clap(subcommand)]
+    Adapter(CliAdapter),
     /// Generate mock benchmark data
     Mock(CliMock),
 
@@ -1,7 +1,7 @@
 use bencher_json::{DateTime, GitHash, NameId, ReportUuid, ResourceId};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use super::run::{CliRunAdapter, CliRunAverage, CliRunFold};
+use super::run::{CliRunAdapter, CliRunAverage, CliRunCorrection, CliRunFold};
 use crate::parser::{CliBackend, CliPagination};
 
 #[derive(Subcommand, Debug)]
@@ -96,6 +96,10 @@ pub struct CliReportCreate {
     #[clap(value_enum, long)]
     pub fold: Option<CliRunFold>,
 
+    /// Correct for multiple comparisons across all of the benchmarks in the report
+    #[clap(value_enum, long)]
+    pub correction: Option<CliRunCorrection>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -23,6 +23,11 @@ pub struct CliRun {
     #[clap(value_enum, long)]
     pub adapter: Option<CliRunAdapter>,
 
+    /// Custom adapter config file path.
+    /// The benchmark harness results are parsed locally and sent as Bencher Metric Format JSON
+    #[clap(long, conflicts_with = "adapter")]
+    pub adapter_config: Option<Utf8PathBuf>,
+
     /// Benchmark harness suggested central tendency (ie average)
     #[clap(value_enum, long)]
     pub average: Option<CliRunAverage>,
@@ -35,6 +40,10 @@ pub struct CliRun {
     #[clap(value_enum, long, requires = "iter")]
     pub fold: Option<CliRunFold>,
 
+    /// Correct for multiple comparisons across all of the benchmarks in the report
+    #[clap(value_enum, long)]
+    pub correction: Option<CliRunCorrection>,
+
     /// Backdate the report (seconds since epoch)
     /// NOTE: This will NOT effect the ordering of past reports
     #[clap(long)]
@@ -155,7 +164,7 @@ pub struct CliRunShell {
 }
 
 /// Supported Adapters
-#[derive(ValueEnum, Debug, Clone)]
+#[derive(ValueEnum, Debug, Clone, Copy)]
 #[clap(rename_all = "snake_case")]
 pub enum CliRunAdapter {
     /// 🪄 Magic (default)
@@ -172,10 +181,22 @@ pub enum CliRunAdapter {
     CppCatch2,
     /// ➕ C++ Google
     CppGoogle,
+    /// 💧 Elixir
+    Elixir,
+    /// 💧 Elixir Benchee
+    ElixirBenchee,
     /// 🕳 Go
     Go,
     /// 🕳 Go Bench
     GoBench,
+    /// 🌐 HTTP
+    Http,
+    /// 🌐 HTTP k6
+    HttpK6,
+    /// 🌐 HTTP wrk
+    HttpWrk,
+    /// 🌐 HTTP oha
+    HttpOha,
     /// ☕️ Java
     Java,
     /// ☕️ Java JMH
@@ -186,12 +207,16 @@ pub enum CliRunAdapter {
     JsBenchmark,
     /// 🕸 JavaScript Time
     JsTime,
+    /// 🕸 JavaScript Vitest
+    JsVitest,
     /// 🐍 Python
     Python,
     /// 🐍 Python ASV
     PythonAsv,
     /// 🐍 Python Pytest
     PythonPytest,
+    /// 🐍 Python pyperf
+    PythonPyperf,
     /// ♦️ Ruby
     Ruby,
     /// ♦️ Ruby Benchmark
@@ -206,14 +231,26 @@ pub enum CliRunAdapter {
     RustIai,
     /// 🦀 Rust Iai-Callgrind
     RustIaiCallgrind,
+    /// 🦀 Rust Divan
+    RustDivan,
     /// ❯_ Shell
     Shell,
     /// ❯_ Shell Hyperfine
     ShellHyperfine,
+    /// ❯_ Shell perf stat
+    ShellPerfStat,
+    /// 🐦 Swift
+    Swift,
+    /// 🐦 Swift Benchmark
+    SwiftBenchmark,
+    /// ⚡ Zig
+    Zig,
+    /// ⚡ Zig zBench
+    ZigZbench,
 }
 
 /// Suggested Central Tendency (Average)
-#[derive(ValueEnum, Debug, Clone)]
+#[derive(ValueEnum, Debug, Clone, Copy)]
 #[clap(rename_all = "snake_case")]
 pub enum CliRunAverage {
     /// Mean and standard deviation
@@ -236,51 +273,108 @@ pub enum CliRunFold {
     Median,
 }
 
+/// Supported Multiple Comparison Corrections
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliRunCorrection {
+    /// Bonferroni family-wise error rate correction
+    Bonferroni,
+    /// Benjamini-Hochberg false discovery rate correction
+    BenjaminiHochberg,
+}
+
 #[derive(Args, Debug)]
 #[clap(group(
     ArgGroup::new("run_fmt")
         .multiple(false)
-        .args(&["html", "quiet"]),
+        .args(&["format", "html", "quiet"]),
 ))]
 pub struct CliRunFmt {
-    /// Output results as HTML
+    /// Output format for the final Report (default: text)
+    #[clap(value_enum, long)]
+    pub format: Option<CliRunFormat>,
+    /// Output file path for the final Report instead of stdout
     #[clap(long)]
+    pub output: Option<Utf8PathBuf>,
+    /// Deprecated: Use `--format html` instead
+    #[clap(long, hide = true)]
     pub html: bool,
-    /// Quite mode, only output the final Report JSON
-    #[clap(short, long)]
+    /// Deprecated: Use `--format json` instead
+    #[clap(short, long, hide = true)]
     pub quiet: bool,
 }
 
+/// Supported Report Formats
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliRunFormat {
+    /// Text (default)
+    Text,
+    /// Quiet mode, only output the final Report JSON
+    Json,
+    /// HTML
+    Html,
+    /// Markdown
+    Markdown,
+    /// `JUnit` XML
+    Junit,
+}
+
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Args, Debug)]
 #[clap(group(
     ArgGroup::new("ci_cd")
         .multiple(false)
-        .args(&["github_actions"]),
+        .args(&["github_actions", "gitlab_ci", "gitea_actions", "bitbucket_pipelines"]),
 ))]
 pub struct CliRunCi {
     /// GitHub API authentication token for GitHub Actions to comment on PRs (ie `--github-actions ${{ secrets.GITHUB_TOKEN }}`)
     #[clap(long)]
     pub github_actions: Option<String>,
-    /// Only post results to CI if a Threshold exists for the Branch, Testbed, and Measure (requires: `--github-actions`)
+    /// GitLab API access token for GitLab CI to comment on MRs (ie `--gitlab-ci $GITLAB_TOKEN`)
+    #[clap(long)]
+    pub gitlab_ci: Option<String>,
+    /// Gitea or Forgejo API access token for Gitea or Forgejo Actions to comment on PRs (ie `--gitea-actions ${{ secrets.GITEA_TOKEN }}`)
+    #[clap(long)]
+    pub gitea_actions: Option<String>,
+    /// Bitbucket API access token for Bitbucket Pipelines to comment on PRs (ie `--bitbucket-pipelines $BITBUCKET_TOKEN`)
+    #[clap(long)]
+    pub bitbucket_pipelines: Option<String>,
+    /// Only post results to CI if a Threshold exists for the Branch, Testbed, and Measure (requires: a CI integration, ie `--github-actions`)
     #[clap(long, requires = "ci_cd")]
     pub ci_only_thresholds: bool,
-    /// Only start posting results to CI if an Alert is generated (requires: `--github-actions`)
+    /// Only start posting results to CI if an Alert is generated (requires: a CI integration, ie `--github-actions`)
     #[clap(long, requires = "ci_cd")]
     pub ci_only_on_alert: bool,
     /// All links should be to public URLs that do not require a login (requires: `--github-actions`)
     #[clap(long, requires = "ci_cd")]
     pub ci_public_links: bool,
-    /// Custom ID for posting results to CI (requires: `--github-actions`)
+    /// Custom ID for posting results to CI (requires: a CI integration, ie `--github-actions`)
     #[clap(long, requires = "ci_cd")]
     pub ci_id: Option<String>,
-    /// Issue number for posting results to CI (requires: `--github-actions`)
+    /// Issue number for posting results to CI (requires: a CI integration, ie `--github-actions`)
     #[clap(long, requires = "ci_cd")]
     pub ci_number: Option<u64>,
-    /// CAUTION: Override safety checks and accept that you are vulnerable to pwn requests (requires: `--github-actions`)
+    /// CAUTION: Override safety checks and accept that you are vulnerable to pwn requests (requires: `--github-actions` or `--gitea-actions`)
     #[clap(long, requires = "ci_cd")]
     pub ci_i_am_vulnerable_to_pwn_requests: bool,
+    /// Also publish a GitHub status for the benchmarked commit hash (requires: `--github-actions`)
+    #[clap(value_enum, long, requires = "github_actions")]
+    pub ci_status: Option<CliRunCiStatus>,
+    /// Add the results to the GitHub Actions job summary and output each Alert as an annotation
+    #[clap(long)]
+    pub ci_github_summary: bool,
     /// Deprecated: Do not use. This will soon be removed.
     #[clap(long, alias = "ci-no-metrics")]
     pub ci_deprecated: bool,
 }
+
+/// Supported GitHub Statuses
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliRunCiStatus {
+    /// Check Run with a success, neutral, or failure conclusion
+    CheckRun,
+    /// Commit status with a success or failure state
+    CommitStatus,
+}
@@ -1,4 +1,7 @@
-use bencher_json::{Boundary, ModelUuid, NameId, ResourceId, SampleSize, ThresholdUuid, Window};
+use bencher_json::{
+    Boundary, ModelUuid, NameId, ResourceId, SampleSize, SmoothingFactor, ThresholdUuid,
+    Violations, Window,
+};
 use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliBackend, CliPagination};
@@ -20,6 +23,8 @@ pub enum CliThreshold {
     /// Delete a threshold
     #[clap(alias = "rm")]
     Delete(CliThresholdDelete),
+    /// Simulate a threshold model using historical metrics
+    Simulate(CliThresholdSimulate),
 }
 
 #[derive(Parser, Debug)]
@@ -111,6 +116,24 @@ pub struct CliModel {
     #[clap(long)]
     pub window: Option<Window>,
 
+    /// EWMA smoothing factor (0.0, 1.0]
+    #[clap(long)]
+    pub smoothing_factor: Option<SmoothingFactor>,
+
+    /// Number of boundary violations required before an alert is generated.
+    /// Not supported by the `change_point` test or by two-sample tests.
+    #[clap(long)]
+    pub violations: Option<Violations>,
+
+    /// Number of most recent boundary checks in which the violations must occur (requires: `--violations`).
+    /// If not set, then the violations must be consecutive.
+    #[clap(long, requires = "violations")]
+    pub violation_window: Option<Violations>,
+
+    /// Only use the start point branch history, at or before the start point version, as the baseline
+    #[clap(long)]
+    pub start_point_baseline: bool,
+
     /// Lower boundary
     #[clap(long)]
     pub lower_boundary: Option<Boundary>,
@@ -140,6 +163,22 @@ pub enum CliModelTest {
     Iqr,
     /// Delta interquartile range (ΔIQR)
     DeltaIqr,
+    /// Change point detection (PELT)
+    ChangePoint,
+    /// Welch's t-test (two-sample)
+    #[clap(alias = "welch")]
+    WelchTTest,
+    /// Mann-Whitney U test (two-sample)
+    #[clap(alias = "mann_whitney")]
+    MannWhitneyU,
+    /// Bootstrap confidence interval of the mean (two-sample)
+    BootstrapMean,
+    /// Bootstrap confidence interval of the median (two-sample)
+    BootstrapMedian,
+    /// Exponentially weighted moving average (EWMA)
+    Ewma,
+    /// Inverse variance weighted z-score (uses lower and upper values)
+    InverseVariance,
 }
 
 #[derive(Parser, Debug)]
@@ -184,3 +223,18 @@ pub struct CliThresholdDelete {
     #[clap(flatten)]
     pub backend: CliBackend,
 }
+
+#[derive(Parser, Debug)]
+pub struct CliThresholdSimulate {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Threshold UUID
+    pub threshold: ThresholdUuid,
+
+    #[clap(flatten)]
+    pub model: CliModel,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -26,6 +26,7 @@ const StatusButton = (props: Props) => {
 			case AlertStatus.Active:
 				return { status: AlertStatus.Dismissed };
 			case AlertStatus.Dismissed:
+			case AlertStatus.Resolved:
 				return { status: AlertStatus.Active };
 			default:
 				console.error("Unknown status");
@@ -88,7 +89,12 @@ const StatusButton = (props: Props) => {
 					<span>Dismiss</span>
 				</button>
 			</Match>
-			<Match when={props.data()?.status === AlertStatus.Dismissed}>
+			<Match
+				when={
+					props.data()?.status === AlertStatus.Dismissed ||
+					props.data()?.status === AlertStatus.Resolved
+				}
+			>
 				<button
 					class="button is-fullwidth"
 					type="button"
@@ -6,7 +6,9 @@ import {
 	validIqrBoundary,
 	validPercentageBoundary,
 	validSampleSize,
+	validSmoothingFactor,
 	validU32,
+	validViolations,
 } from "../../../util/valid";
 import type { FieldConfig, FieldHandler, FieldValue } from "../Field";
 import FieldKind from "../kind";
@@ -113,6 +115,27 @@ const STATISTIC_FIELDS = {
 		help: "Must be an integer greater than zero",
 		validate: validU32,
 	},
+	smoothing_factor: {
+		type: "input",
+		placeholder: "0.1",
+		icon: "fas fa-wave-square",
+		help: "Must be greater than 0.0 and less than or equal to 1.0 (lower is smoother; higher favors recent Metrics)",
+		validate: validSmoothingFactor,
+	},
+	violations: {
+		type: "number",
+		placeholder: "3",
+		icon: "fas fa-redo",
+		help: "Must be an integer between 1 and 255",
+		validate: validViolations,
+	},
+	violation_window: {
+		type: "number",
+		placeholder: "5",
+		icon: "fas fa-history",
+		help: "Must be an integer greater than or equal to the number of violations and no more than 255",
+		validate: validViolations,
+	},
 };
 
 const testValue = (selected: ModelTest) => {
@@ -147,6 +170,34 @@ const testValue = (selected: ModelTest) => {
 				value: ModelTest.DeltaIqr,
 				option: "Delta Interquartile Range (ΔIQR)",
 			},
+			{
+				value: ModelTest.ChangePoint,
+				option: "Change Point",
+			},
+			{
+				value: ModelTest.WelchTTest,
+				option: "Welch's t-test",
+			},
+			{
+				value: ModelTest.MannWhitneyU,
+				option: "Mann-Whitney U",
+			},
+			{
+				value: ModelTest.BootstrapMean,
+				option: "Bootstrap Mean",
+			},
+			{
+				value: ModelTest.BootstrapMedian,
+				option: "Bootstrap Median",
+			},
+			{
+				value: ModelTest.Ewma,
+				option: "EWMA",
+			},
+			{
+				value: ModelTest.InverseVariance,
+				option: "Inverse Variance",
+			},
 		],
 	};
 };
@@ -197,6 +248,19 @@ const testFragment = (modelTest: ModelTest) => {
 			return "iqr-thresholds";
 		case ModelTest.DeltaIqr:
 			return "delta-iqr-thresholds";
+		case ModelTest.ChangePoint:
+			return "change-point-thresholds";
+		case ModelTest.WelchTTest:
+			return "welchs-t-test-thresholds";
+		case ModelTest.MannWhitneyU:
+			return "mann-whitney-u-thresholds";
+		case ModelTest.BootstrapMean:
+		case ModelTest.BootstrapMedian:
+			return "bootstrap-thresholds";
+		case ModelTest.Ewma:
+			return "ewma-thresholds";
+		case ModelTest.InverseVariance:
+			return "inverse-variance-thresholds";
 	}
 };
 
@@ -224,6 +288,8 @@ const cdfConfig = (modelTest: ModelTest) => {
 			config: STATISTIC_FIELDS.cdf_upper_boundary,
 		},
 		...SAMPLE_SIZE,
+		...START_POINT_BASELINE,
+		...VIOLATIONS,
 	];
 };
 
@@ -251,6 +317,8 @@ const iqrConfig = (modelTest: ModelTest) => {
 			config: STATISTIC_FIELDS.iqr_upper_boundary,
 		},
 		...SAMPLE_SIZE,
+		...START_POINT_BASELINE,
+		...VIOLATIONS,
 	];
 };
 
@@ -287,6 +355,45 @@ const SAMPLE_SIZE = [
 	},
 ];
 
+const START_POINT_BASELINE = [
+	{
+		kind: FieldKind.SWITCH,
+		label: "Start Point Baseline",
+		key: "start_point_baseline",
+		value: false,
+		valid: true,
+		validate: false,
+		nullable: true,
+		config: {
+			label: "Start Point Baseline",
+			help: "Only use the start point branch history as the baseline",
+		},
+	},
+];
+
+const VIOLATIONS = [
+	{
+		kind: FieldKind.NUMBER,
+		label: "Violations",
+		key: "violations",
+		value: "",
+		valid: true,
+		validate: true,
+		nullable: true,
+		config: STATISTIC_FIELDS.violations,
+	},
+	{
+		kind: FieldKind.NUMBER,
+		label: "Violation Window",
+		key: "violation_window",
+		value: "",
+		valid: true,
+		validate: true,
+		nullable: true,
+		config: STATISTIC_FIELDS.violation_window,
+	},
+];
+
 const FIELDS = {
 	[ModelTest.Static]: [
 		testSelectConfig(ModelTest.Static),
@@ -310,6 +417,7 @@ const FIELDS = {
 			nullable: true,
 			config: STATISTIC_FIELDS.static_upper_boundary,
 		},
+		...VIOLATIONS,
 	],
 	[ModelTest.Percentage]: [
 		testSelectConfig(ModelTest.Percentage),
@@ -334,12 +442,33 @@ const FIELDS = {
 			config: STATISTIC_FIELDS.percentage_upper_boundary,
 		},
 		...SAMPLE_SIZE,
+		...START_POINT_BASELINE,
+		...VIOLATIONS,
 	],
 	[ModelTest.ZScore]: cdfConfig(ModelTest.ZScore),
 	[ModelTest.TTest]: cdfConfig(ModelTest.TTest),
 	[ModelTest.LogNormal]: cdfConfig(ModelTest.LogNormal),
 	[ModelTest.Iqr]: iqrConfig(ModelTest.Iqr),
 	[ModelTest.DeltaIqr]: iqrConfig(ModelTest.DeltaIqr),
+	[ModelTest.ChangePoint]: cdfConfig(ModelTest.ChangePoint),
+	[ModelTest.WelchTTest]: cdfConfig(ModelTest.WelchTTest),
+	[ModelTest.MannWhitneyU]: cdfConfig(ModelTest.MannWhitneyU),
+	[ModelTest.BootstrapMean]: cdfConfig(ModelTest.BootstrapMean),
+	[ModelTest.BootstrapMedian]: cdfConfig(ModelTest.BootstrapMedian),
+	[ModelTest.InverseVariance]: cdfConfig(ModelTest.InverseVariance),
+	[ModelTest.Ewma]: [
+		...cdfConfig(ModelTest.Ewma),
+		{
+			kind: FieldKind.NUMBER,
+			label: "Smoothing Factor",
+			key: "smoothing_factor",
+			value: "",
+			valid: true,
+			validate: true,
+			nullable: true,
+			config: STATISTIC_FIELDS.smoothing_factor,
+		},
+	],
 };
 
 const initForm = (fields: object[]) => {
@@ -81,6 +81,12 @@ const alertsConfig = {
 					keys: ["status"],
 					display: Display.RAW,
 				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Resolved Report",
+					keys: ["resolved_report"],
+					display: Display.RAW,
+				},
 				{
 					kind: Card.NESTED_FIELD,
 					label: "Branch",
@@ -171,6 +177,30 @@ const alertsConfig = {
 					keys: ["threshold", "model", "window"],
 					display: Display.RAW,
 				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Smoothing Factor",
+					keys: ["threshold", "model", "smoothing_factor"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Violations",
+					keys: ["threshold", "model", "violations"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Violation Window",
+					keys: ["threshold", "model", "violation_window"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Start Point Baseline",
+					keys: ["threshold", "model", "start_point_baseline"],
+					display: Display.RAW,
+				},
 			],
 		},
 	},
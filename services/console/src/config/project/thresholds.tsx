@@ -245,6 +245,30 @@ const thresholdsConfig = {
 					keys: ["model", "window"],
 					display: Display.RAW,
 				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Smoothing Factor",
+					keys: ["model", "smoothing_factor"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Violations",
+					keys: ["model", "violations"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Violation Window",
+					keys: ["model", "violation_window"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Start Point Baseline",
+					keys: ["model", "start_point_baseline"],
+					display: Display.RAW,
+				},
 			],
 			buttons: [
 				{
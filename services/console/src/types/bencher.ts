@@ -46,12 +46,23 @@ export enum ModelTest {
 	LogNormal = "log_normal",
 	Iqr = "iqr",
 	DeltaIqr = "delta_iqr",
+	ChangePoint = "change_point",
+	WelchTTest = "welch_t_test",
+	MannWhitneyU = "mann_whitney_u",
+	BootstrapMean = "bootstrap_mean",
+	BootstrapMedian = "bootstrap_median",
+	Ewma = "ewma",
+	InverseVariance = "inverse_variance",
 }
 
 export type SampleSize = number;
 
 export type Window = number;
 
+export type SmoothingFactor = number;
+
+export type Violations = number;
+
 export type Boundary = number;
 
 export interface JsonModel {
@@ -61,6 +72,10 @@ export interface JsonModel {
 	min_sample_size?: SampleSize;
 	max_sample_size?: SampleSize;
 	window?: Window;
+	smoothing_factor?: SmoothingFactor;
+	violations?: Violations;
+	violation_window?: Violations;
+	start_point_baseline?: boolean;
 	lower_boundary?: Boundary;
 	upper_boundary?: Boundary;
 	created: string;
@@ -79,6 +94,8 @@ export interface JsonBoundary {
 	baseline?: number;
 	lower_limit?: number;
 	upper_limit?: number;
+	adjusted_lower_boundary?: Boundary;
+	adjusted_upper_boundary?: Boundary;
 }
 
 export interface JsonBenchmarkMetric {
@@ -136,6 +153,22 @@ export interface JsonThreshold {
 	modified: string;
 }
 
+export interface JsonSimulatedBoundary {
+	report: Uuid;
+	iteration: Iteration;
+	benchmark: JsonBenchmarkMetric;
+	/** The side of the boundary that would have generated an alert, if any. */
+	alert?: BoundaryLimit;
+}
+
+export interface JsonThresholdSimulation {
+	threshold: Uuid;
+	/** The number of alerts that the proposed threshold model would have generated. */
+	alerts: number;
+	/** The boundary for every historical metric, ordered from least recent to most recent for each benchmark. */
+	boundaries: JsonSimulatedBoundary[];
+}
+
 export enum BoundaryLimit {
 	Lower = "lower",
 	Upper = "upper",
@@ -144,6 +177,7 @@ export enum BoundaryLimit {
 export enum AlertStatus {
 	Active = "active",
 	Dismissed = "dismissed",
+	Resolved = "resolved",
 }
 
 export interface JsonAlert {
@@ -154,6 +188,8 @@ export interface JsonAlert {
 	benchmark: JsonBenchmarkMetric;
 	limit: BoundaryLimit;
 	status: AlertStatus;
+	/** The report in which the benchmark returned within its boundary, if resolved. */
+	resolved_report?: Uuid;
 	created: string;
 	modified: string;
 }
@@ -538,6 +574,7 @@ export enum Adapter {
 	RustCriterion = "rust_criterion",
 	RustIai = "rust_iai",
 	RustIaiCallgrind = "rust_iai_callgrind",
+	RustDivan = "rust_divan",
 	Cpp = "cpp",
 	CppGoogle = "cpp_google",
 	CppCatch2 = "cpp_catch2",
@@ -550,13 +587,26 @@ export enum Adapter {
 	Js = "js",
 	JsBenchmark = "js_benchmark",
 	JsTime = "js_time",
+	JsVitest = "js_vitest",
 	Python = "python",
 	PythonAsv = "python_asv",
 	PythonPytest = "python_pytest",
+	PythonPyperf = "python_pyperf",
 	Ruby = "ruby",
 	RubyBenchmark = "ruby_benchmark",
 	Shell = "shell",
 	ShellHyperfine = "shell_hyperfine",
+	ShellPerfStat = "shell_perf_stat",
+	Http = "http",
+	HttpK6 = "http_k6",
+	HttpWrk = "http_wrk",
+	HttpOha = "http_oha",
+	Swift = "swift",
+	SwiftBenchmark = "swift_benchmark",
+	Zig = "zig",
+	ZigZbench = "zig_zbench",
+	Elixir = "elixir",
+	ElixirBenchee = "elixir_benchee",
 }
 
 export interface JsonReport {
@@ -22,6 +22,8 @@ import {
 	is_valid_iqr_boundary,
 	is_valid_model,
 	is_valid_window,
+	is_valid_smoothing_factor,
+	is_valid_violations,
 } from "bencher_valid";
 import type { JsonAuthUser } from "../types/bencher";
 
@@ -138,9 +140,15 @@ export const validCdfBoundary = (boundary: string): boolean =>
 export const validIqrBoundary = (boundary: string): boolean =>
 	validateNumber(boundary, is_valid_iqr_boundary);
 
+export const validSmoothingFactor = (smoothing_factor: string): boolean =>
+	validateNumber(smoothing_factor, is_valid_smoothing_factor);
+
 export const validSampleSize = (sample_size: string) =>
 	validU32(sample_size) && validateNumber(sample_size, is_valid_sample_size);
 
+export const validViolations = (violations: string) =>
+	validU32(violations) && validateNumber(violations, is_valid_violations);
+
 export const validModel = (model: object) => {
 	if (!model || typeof model !== "object") {
 		return false;
@@ -700,7 +700,7 @@ impl SeedTest {
             let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
             let bencher_cmd = cmd.get_program().to_string_lossy().to_string();
             if i.rem_euclid(2) == 0 {
-                // cargo run -- run --host http://localhost:61016 --token $BENCHER_API_TOKEN --project the-computer --branch master --testbed base --quiet "bencher mock"
+                // cargo run -- run --host http://localhost:61016 --token $BENCHER_API_TOKEN --project the-computer --branch master --testbed base --format json "bencher mock"
                 cmd.args([
                     "run",
                     HOST_ARG,
@@ -713,11 +713,12 @@ impl SeedTest {
                     BRANCH_SLUG,
                     TESTBED_ARG,
                     TESTBED_SLUG,
-                    "--quiet",
+                    "--format",
+                    "json",
                     &format!("{bencher_cmd} mock"),
                 ])
             } else {
-                // cargo run -- run --host http://localhost:61016 --token $BENCHER_API_TOKEN --project the-computer --branch master --testbed base --quiet bencher mock
+                // cargo run -- run --host http://localhost:61016 --token $BENCHER_API_TOKEN --project the-computer --branch master --testbed base --format json bencher mock
                 cmd.args([
                     "run",
                     HOST_ARG,
@@ -730,7 +731,8 @@ impl SeedTest {
                     BRANCH_SLUG,
                     TESTBED_ARG,
                     TESTBED_SLUG,
-                    "--quiet",
+                    "--format",
+                    "json",
                     &bencher_cmd,
                     "mock",
                 ])
@@ -767,7 +769,7 @@ impl SeedTest {
             serde_json::from_slice(&assert.get_output().stdout).unwrap();
         assert_eq!(alert_stats.active.0, 0);
 
-        // cargo run -- run --host http://localhost:61016 --token $BENCHER_API_TOKEN --project the-computer --branch master --testbed base --quiet bencher mock --pow 10
+        // cargo run -- run --host http://localhost:61016 --token $BENCHER_API_TOKEN --project the-computer --branch master --testbed base --format json bencher mock --pow 10
         let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
         let bencher_cmd = cmd.get_program().to_string_lossy().to_string();
         cmd.args([
@@ -782,7 +784,8 @@ impl SeedTest {
             BRANCH_SLUG,
             TESTBED_ARG,
             TESTBED_SLUG,
-            "--quiet",
+            "--format",
+            "json",
             &bencher_cmd,
             "mock",
             "--pow",